@@ -1,11 +1,69 @@
-use crate::Color;
+use crate::{Color, Point};
 
 /// The background of some element.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Background {
     /// A solid color
     Color(Color),
-    // TODO: Add gradient and image variants
+
+    /// A linear gradient
+    LinearGradient(LinearGradient),
+    // TODO: Add image variant
+}
+
+impl Background {
+    /// Creates a linear gradient [`Background`] with the given stops.
+    ///
+    /// The resulting [`Background`] is [`normalize`]d.
+    ///
+    /// [`Background`]: enum.Background.html
+    /// [`normalize`]: #method.normalize
+    pub fn linear_gradient(
+        start: Point,
+        end: Point,
+        stops: impl IntoIterator<Item = (f32, Color)>,
+    ) -> Background {
+        Background::LinearGradient(LinearGradient::new(start, end, stops))
+            .normalize()
+    }
+
+    /// Normalizes a gradient [`Background`].
+    ///
+    /// A gradient with a single stop becomes a solid color, while one without
+    /// stops becomes transparent.
+    ///
+    /// [`Background`]: enum.Background.html
+    pub fn normalize(self) -> Background {
+        match self {
+            Background::Color(_) => self,
+            Background::LinearGradient(gradient) => {
+                let mut stops = gradient.stops();
+
+                match (stops.next(), stops.next()) {
+                    (None, _) => Background::Color(Color::TRANSPARENT),
+                    (Some((_, color)), None) => Background::Color(color),
+                    _ => self,
+                }
+            }
+        }
+    }
+
+    /// Returns the [`Background`] with the alpha of its colors multiplied by
+    /// the given opacity.
+    ///
+    /// The opacity is clamped to `[0.0, 1.0]`.
+    ///
+    /// [`Background`]: enum.Background.html
+    pub fn scale_alpha(self, opacity: f32) -> Background {
+        match self {
+            Background::Color(color) => {
+                Background::Color(color.scale_alpha(opacity))
+            }
+            Background::LinearGradient(gradient) => {
+                Background::LinearGradient(gradient.scale_alpha(opacity))
+            }
+        }
+    }
 }
 
 impl From<Color> for Background {
@@ -13,3 +71,157 @@ impl From<Color> for Background {
         Background::Color(color)
     }
 }
+
+/// A linear gradient.
+///
+/// The `start` and `end` points are relative to the bounds of the element,
+/// where `(0.0, 0.0)` is its top-left corner and `(1.0, 1.0)` its
+/// bottom-right one. Each stop has an offset along the line between them,
+/// from `0.0` at the `start` to `1.0` at the `end`.
+///
+/// A [`LinearGradient`] holds up to [`MAX_STOPS`] stops, so it stays `Copy`.
+///
+/// [`LinearGradient`]: struct.LinearGradient.html
+/// [`MAX_STOPS`]: #associatedconstant.MAX_STOPS
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearGradient {
+    /// The point where the gradient starts
+    pub start: Point,
+
+    /// The point where the gradient ends
+    pub end: Point,
+
+    stops: [Option<(f32, Color)>; LinearGradient::MAX_STOPS],
+}
+
+impl LinearGradient {
+    /// The maximum amount of stops of a [`LinearGradient`].
+    ///
+    /// [`LinearGradient`]: struct.LinearGradient.html
+    pub const MAX_STOPS: usize = 8;
+
+    /// Creates a new [`LinearGradient`] with the given stops.
+    ///
+    /// The offsets of the stops are clamped to `[0.0, 1.0]` and the stops are
+    /// sorted by them. Any stop past the first [`MAX_STOPS`] is ignored.
+    ///
+    /// [`LinearGradient`]: struct.LinearGradient.html
+    /// [`MAX_STOPS`]: #associatedconstant.MAX_STOPS
+    pub fn new(
+        start: Point,
+        end: Point,
+        stops: impl IntoIterator<Item = (f32, Color)>,
+    ) -> LinearGradient {
+        let mut sorted = [None; Self::MAX_STOPS];
+
+        for (slot, (offset, color)) in sorted.iter_mut().zip(stops) {
+            let offset = if offset.is_nan() {
+                0.0
+            } else {
+                offset.clamp(0.0, 1.0)
+            };
+
+            *slot = Some((offset, color));
+        }
+
+        let count = sorted.iter().take_while(|stop| stop.is_some()).count();
+
+        let offset =
+            |stop: &Option<(f32, Color)>| stop.map(|(offset, _)| offset);
+
+        sorted[..count].sort_by(|a, b| {
+            offset(a)
+                .partial_cmp(&offset(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        LinearGradient {
+            start,
+            end,
+            stops: sorted,
+        }
+    }
+
+    /// Returns the stops of the [`LinearGradient`], sorted by their offset.
+    ///
+    /// [`LinearGradient`]: struct.LinearGradient.html
+    pub fn stops(&self) -> impl Iterator<Item = (f32, Color)> + '_ {
+        self.stops.iter().flatten().copied()
+    }
+
+    /// Returns the [`LinearGradient`] with the alpha of its colors multiplied
+    /// by the given opacity.
+    ///
+    /// [`LinearGradient`]: struct.LinearGradient.html
+    pub fn scale_alpha(mut self, opacity: f32) -> LinearGradient {
+        for (_, color) in self.stops.iter_mut().flatten() {
+            *color = color.scale_alpha(opacity);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
+    const BLUE: Color = Color::from_rgb(0.0, 0.0, 1.0);
+
+    fn gradient(stops: Vec<(f32, Color)>) -> Background {
+        Background::linear_gradient(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            stops,
+        )
+    }
+
+    fn stops(background: Background) -> Vec<(f32, Color)> {
+        match background {
+            Background::LinearGradient(gradient) => gradient.stops().collect(),
+            Background::Color(_) => panic!("Expected a gradient"),
+        }
+    }
+
+    #[test]
+    fn stops_are_sorted_and_clamped() {
+        let background = gradient(vec![
+            (1.5, BLUE),
+            (0.5, Color::WHITE),
+            (-0.5, RED),
+            (f32::NAN, Color::BLACK),
+        ]);
+
+        assert_eq!(
+            stops(background),
+            vec![
+                (0.0, RED),
+                (0.0, Color::BLACK),
+                (0.5, Color::WHITE),
+                (1.0, BLUE)
+            ]
+        );
+    }
+
+    #[test]
+    fn single_stop_is_a_solid_color() {
+        assert_eq!(gradient(vec![(0.3, RED)]), Background::Color(RED));
+    }
+
+    #[test]
+    fn gradient_without_stops_is_transparent() {
+        assert_eq!(gradient(vec![]), Background::Color(Color::TRANSPARENT));
+    }
+
+    #[test]
+    fn extra_stops_are_ignored() {
+        let background = gradient(
+            (0..LinearGradient::MAX_STOPS + 2)
+                .map(|i| (i as f32 / 10.0, RED))
+                .collect(),
+        );
+
+        assert_eq!(stops(background).len(), LinearGradient::MAX_STOPS);
+    }
+}
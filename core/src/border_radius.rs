@@ -0,0 +1,146 @@
+/// The radius of each corner of a box.
+///
+/// You can create a [`BorderRadius`] from a single `u16` or `f32`, rounding
+/// every corner uniformly, or from an array of `f32` in
+/// `[top_left, top_right, bottom_right, bottom_left]` order, like in CSS.
+///
+/// [`BorderRadius`]: struct.BorderRadius.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BorderRadius {
+    /// Top-left radius
+    pub top_left: f32,
+
+    /// Top-right radius
+    pub top_right: f32,
+
+    /// Bottom-right radius
+    pub bottom_right: f32,
+
+    /// Bottom-left radius
+    pub bottom_left: f32,
+}
+
+impl BorderRadius {
+    /// Border radius of zero
+    pub const ZERO: BorderRadius = BorderRadius {
+        top_left: 0.0,
+        top_right: 0.0,
+        bottom_right: 0.0,
+        bottom_left: 0.0,
+    };
+
+    /// Creates a new [`BorderRadius`] with the same radius on every corner.
+    ///
+    /// [`BorderRadius`]: struct.BorderRadius.html
+    pub const fn new(radius: f32) -> BorderRadius {
+        BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+
+    /// Returns the radius of every corner of the [`BorderRadius`], in
+    /// `[top_left, top_right, bottom_right, bottom_left]` order.
+    ///
+    /// [`BorderRadius`]: struct.BorderRadius.html
+    pub fn corners(self) -> [f32; 4] {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+    }
+
+    /// Returns the radius shared by every corner of the [`BorderRadius`], if
+    /// it is uniform.
+    ///
+    /// [`BorderRadius`]: struct.BorderRadius.html
+    pub fn uniform(self) -> Option<f32> {
+        let [top_left, top_right, bottom_right, bottom_left] = self.corners();
+
+        let is_uniform = [top_right, bottom_right, bottom_left]
+            .iter()
+            .all(|radius| (radius - top_left).abs() < f32::EPSILON);
+
+        if is_uniform {
+            Some(top_left)
+        } else {
+            None
+        }
+    }
+
+    /// Grows every corner of the [`BorderRadius`] by the given amount.
+    ///
+    /// This is useful to round a box drawn around another one, like a focus
+    /// ring.
+    ///
+    /// [`BorderRadius`]: struct.BorderRadius.html
+    pub fn expand(self, amount: f32) -> BorderRadius {
+        BorderRadius {
+            top_left: (self.top_left + amount).max(0.0),
+            top_right: (self.top_right + amount).max(0.0),
+            bottom_right: (self.bottom_right + amount).max(0.0),
+            bottom_left: (self.bottom_left + amount).max(0.0),
+        }
+    }
+}
+
+impl From<u16> for BorderRadius {
+    fn from(radius: u16) -> Self {
+        BorderRadius::new(f32::from(radius))
+    }
+}
+
+impl From<f32> for BorderRadius {
+    fn from(radius: f32) -> Self {
+        BorderRadius::new(radius)
+    }
+}
+
+impl From<[f32; 4]> for BorderRadius {
+    fn from(
+        [top_left, top_right, bottom_right, bottom_left]: [f32; 4],
+    ) -> Self {
+        BorderRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn four_radii_follow_css_order() {
+        let radius = BorderRadius::from([1.0, 2.0, 3.0, 4.0]);
+
+        assert_eq!(radius.top_left, 1.0);
+        assert_eq!(radius.top_right, 2.0);
+        assert_eq!(radius.bottom_right, 3.0);
+        assert_eq!(radius.bottom_left, 4.0);
+        assert_eq!(radius.corners(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(radius.uniform(), None);
+    }
+
+    #[test]
+    fn single_radius_rounds_every_corner() {
+        assert_eq!(BorderRadius::from(5_u16), BorderRadius::new(5.0));
+        assert_eq!(BorderRadius::from(5.0).corners(), [5.0; 4]);
+        assert_eq!(BorderRadius::from(5_u16).uniform(), Some(5.0));
+    }
+
+    #[test]
+    fn expanding_never_produces_negative_radii() {
+        let radius = BorderRadius::from([0.0, 2.0, 4.0, 6.0]);
+
+        assert_eq!(radius.expand(3.0).corners(), [3.0, 5.0, 7.0, 9.0]);
+        assert_eq!(radius.expand(-3.0).corners(), [0.0, 0.0, 1.0, 3.0]);
+    }
+}
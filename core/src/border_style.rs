@@ -0,0 +1,33 @@
+/// The style of a border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderStyle {
+    /// A solid line
+    Solid,
+
+    /// A line of dashes
+    Dashed {
+        /// The length of every dash, in pixels
+        length: u16,
+
+        /// The gap between dashes, in pixels
+        gap: u16,
+    },
+
+    /// A line of round dots, as big as the width of the border
+    Dotted,
+}
+
+// `#[default]` on enum variants needs a newer compiler than we support
+#[allow(clippy::derivable_impls)]
+impl Default for BorderStyle {
+    fn default() -> BorderStyle {
+        BorderStyle::Solid
+    }
+}
+
+impl BorderStyle {
+    /// A dashed [`BorderStyle`] with sensible defaults.
+    ///
+    /// [`BorderStyle`]: enum.BorderStyle.html
+    pub const DASHED: BorderStyle = BorderStyle::Dashed { length: 6, gap: 4 };
+}
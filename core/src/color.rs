@@ -52,6 +52,18 @@ impl Color {
         }
     }
 
+    /// Returns the [`Color`] with its alpha multiplied by the given opacity.
+    ///
+    /// The opacity is clamped to `[0.0, 1.0]`.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn scale_alpha(self, opacity: f32) -> Color {
+        Color {
+            a: self.a * opacity.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
     /// Converts the [`Color`] into its linear values.
     ///
     /// [`Color`]: struct.Color.html
@@ -86,3 +98,21 @@ impl From<[f32; 4]> for Color {
         Color { r, g, b, a }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_opacities_multiply() {
+        let color = Color::BLACK.scale_alpha(0.5).scale_alpha(0.5);
+
+        assert_eq!(color.a, 0.25);
+    }
+
+    #[test]
+    fn opacity_is_clamped() {
+        assert_eq!(Color::BLACK.scale_alpha(2.0), Color::BLACK);
+        assert_eq!(Color::BLACK.scale_alpha(-1.0).a, 0.0);
+    }
+}
@@ -17,18 +17,24 @@
 
 mod align;
 mod background;
+mod border_radius;
+mod border_style;
 mod color;
 mod font;
 mod length;
+mod padding;
 mod point;
 mod rectangle;
 mod vector;
 
 pub use align::{Align, HorizontalAlignment, VerticalAlignment};
-pub use background::Background;
+pub use background::{Background, LinearGradient};
+pub use border_radius::BorderRadius;
+pub use border_style::BorderStyle;
 pub use color::Color;
 pub use font::Font;
 pub use length::Length;
+pub use padding::Padding;
 pub use point::Point;
 pub use rectangle::Rectangle;
 pub use vector::Vector;
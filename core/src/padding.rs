@@ -0,0 +1,115 @@
+/// An amount of space to pad for each side of a box.
+///
+/// You can create a [`Padding`] from a single `u16`, padding every side
+/// uniformly, or from a 4-tuple of `u16` in `(top, right, bottom, left)`
+/// order, like in CSS.
+///
+/// [`Padding`]: struct.Padding.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Padding {
+    /// Top padding
+    pub top: u16,
+
+    /// Right padding
+    pub right: u16,
+
+    /// Bottom padding
+    pub bottom: u16,
+
+    /// Left padding
+    pub left: u16,
+}
+
+impl Padding {
+    /// Padding of zero
+    pub const ZERO: Padding = Padding {
+        top: 0,
+        right: 0,
+        bottom: 0,
+        left: 0,
+    };
+
+    /// Creates a new [`Padding`] with the same amount of units on every side.
+    ///
+    /// [`Padding`]: struct.Padding.html
+    pub const fn new(units: u16) -> Padding {
+        Padding {
+            top: units,
+            right: units,
+            bottom: units,
+            left: units,
+        }
+    }
+
+    /// Returns the total amount of vertical padding of the [`Padding`].
+    ///
+    /// [`Padding`]: struct.Padding.html
+    pub fn vertical(self) -> u16 {
+        self.top + self.bottom
+    }
+
+    /// Returns the total amount of horizontal padding of the [`Padding`].
+    ///
+    /// [`Padding`]: struct.Padding.html
+    pub fn horizontal(self) -> u16 {
+        self.left + self.right
+    }
+}
+
+impl From<u16> for Padding {
+    fn from(units: u16) -> Self {
+        Padding::new(units)
+    }
+}
+
+impl From<(u16, u16, u16, u16)> for Padding {
+    fn from((top, right, bottom, left): (u16, u16, u16, u16)) -> Self {
+        Padding {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+impl From<[u16; 4]> for Padding {
+    fn from([top, right, bottom, left]: [u16; 4]) -> Self {
+        Padding {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_padding_pads_every_side() {
+        assert_eq!(Padding::from(10), Padding::new(10));
+        assert_eq!(Padding::new(10).horizontal(), 20);
+        assert_eq!(Padding::new(10).vertical(), 20);
+    }
+
+    #[test]
+    fn tuple_padding_follows_css_order() {
+        let padding = Padding::from((5, 10, 15, 20));
+
+        assert_eq!(
+            padding,
+            Padding {
+                top: 5,
+                right: 10,
+                bottom: 15,
+                left: 20,
+            }
+        );
+        assert_eq!(padding, Padding::from([5, 10, 15, 20]));
+        assert_eq!(padding.horizontal(), 30);
+        assert_eq!(padding.vertical(), 20);
+    }
+}
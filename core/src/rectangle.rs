@@ -27,6 +27,22 @@ impl Rectangle<f32> {
             && self.y <= point.y
             && point.y <= self.y + self.height
     }
+
+    /// Returns a [`Rectangle`] with the same center and, at least, the given
+    /// dimensions.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn expand_to(&self, min_width: f32, min_height: f32) -> Rectangle {
+        let width = self.width.max(min_width);
+        let height = self.height.max(min_height);
+
+        Rectangle {
+            x: self.x - (width - self.width) / 2.0,
+            y: self.y - (height - self.height) / 2.0,
+            width,
+            height,
+        }
+    }
 }
 
 impl std::ops::Mul<f32> for Rectangle<u32> {
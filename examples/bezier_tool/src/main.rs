@@ -209,6 +209,7 @@ mod bezier {
                                     "Click to create bezier curves!",
                                 ),
                                 font: Font::Default,
+                                letter_spacing: 0.0,
                                 size: 30.0,
                                 horizontal_alignment:
                                     HorizontalAlignment::Center,
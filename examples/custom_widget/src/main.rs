@@ -62,7 +62,7 @@ mod circle {
                 Primitive::Quad {
                     bounds: layout.bounds(),
                     background: Background::Color(Color::BLACK),
-                    border_radius: self.radius,
+                    border_radius: self.radius.into(),
                     border_width: 0,
                     border_color: Color::TRANSPARENT,
                 },
@@ -10,7 +10,7 @@ use iced_wgpu::{
 use iced_winit::{winit, Cache, Clipboard, MouseCursor, Size, UserInterface};
 
 use winit::{
-    event::{DeviceEvent, Event, ModifiersState, WindowEvent},
+    event::{Event, ModifiersState, WindowEvent},
     event_loop::{ControlFlow, EventLoop},
 };
 
@@ -65,14 +65,11 @@ pub fn main() {
         *control_flow = ControlFlow::Wait;
 
         match event {
-            Event::DeviceEvent {
-                event: DeviceEvent::ModifiersChanged(new_modifiers),
-                ..
-            } => {
-                modifiers = new_modifiers;
-            }
             Event::WindowEvent { event, .. } => {
                 match event {
+                    WindowEvent::ModifiersChanged(new_modifiers) => {
+                        modifiers = new_modifiers;
+                    }
                     WindowEvent::Resized(new_size) => {
                         logical_size =
                             new_size.to_logical(window.scale_factor());
@@ -119,7 +116,7 @@ pub fn main() {
                     &renderer,
                 );
 
-                let user_interface = if messages.is_empty() {
+                let mut user_interface = if messages.is_empty() {
                     // If there are no messages, no interactions we care about have
                     // happened. We can simply leave our user interface as it is.
                     user_interface
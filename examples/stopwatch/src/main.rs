@@ -1,6 +1,6 @@
 use iced::{
     button, Align, Application, Button, Column, Command, Container, Element,
-    HorizontalAlignment, Length, Row, Settings, Subscription, Text,
+    HorizontalAlignment, Length, Row, Rule, Settings, Subscription, Text,
 };
 use std::time::{Duration, Instant};
 
@@ -8,6 +8,9 @@ pub fn main() {
     Stopwatch::run(Settings::default())
 }
 
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+
 struct Stopwatch {
     duration: Duration,
     state: State,
@@ -44,7 +47,16 @@ impl Application for Stopwatch {
     }
 
     fn title(&self) -> String {
-        String::from("Stopwatch - Iced")
+        // Only whole seconds are shown, so the window title changes once per
+        // second instead of on every tick
+        let seconds = self.duration.as_secs();
+
+        format!(
+            "{:0>2}:{:0>2}:{:0>2} - Stopwatch - Iced",
+            seconds / HOUR,
+            (seconds % HOUR) / MINUTE,
+            seconds % MINUTE,
+        )
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
@@ -78,15 +90,13 @@ impl Application for Stopwatch {
         match self.state {
             State::Idle => Subscription::none(),
             State::Ticking { .. } => {
-                time::every(Duration::from_millis(10)).map(Message::Tick)
+                iced_futures::time::every(Duration::from_millis(10))
+                    .map(Message::Tick)
             }
         }
     }
 
     fn view(&mut self) -> Element<Message> {
-        const MINUTE: u64 = 60;
-        const HOUR: u64 = 60 * MINUTE;
-
         let seconds = self.duration.as_secs();
 
         let duration = Text::new(format!(
@@ -96,7 +106,11 @@ impl Application for Stopwatch {
             seconds % MINUTE,
             self.duration.subsec_millis() / 10,
         ))
-        .size(40);
+        .size(40)
+        .color(match self.state {
+            State::Idle => [0.5, 0.5, 0.5],
+            State::Ticking { .. } => [0.0, 0.0, 0.0],
+        });
 
         let button = |state, label, style| {
             Button::new(
@@ -115,25 +129,33 @@ impl Application for Stopwatch {
                 State::Ticking { .. } => ("Stop", style::Button::Destructive),
             };
 
-            button(&mut self.toggle, label, color).on_press(|| Message::Toggle)
+            button(&mut self.toggle, label, color)
+                .width(Length::FillPortion(2))
+                .on_press_message(Message::Toggle)
         };
 
         let reset_button =
             button(&mut self.reset, "Reset", style::Button::Secondary)
-                .on_press(|| Message::Reset);
+                .width(Length::FillPortion(1))
+                .on_press_message(Message::Reset);
 
         let controls = Row::new()
+            .width(Length::Units(300))
             .spacing(20)
             .push(toggle_button)
             .push(reset_button);
 
         let content = Column::new()
+            .width(Length::Units(300))
             .align_items(Align::Center)
             .spacing(20)
             .push(duration)
+            .push(Rule::horizontal(10))
             .push(controls);
 
-        Container::new(content)
+        let card = Container::new(content).padding(30).style(style::Card);
+
+        Container::new(card)
             .width(Length::Fill)
             .height(Length::Fill)
             .center_x()
@@ -142,45 +164,24 @@ impl Application for Stopwatch {
     }
 }
 
-mod time {
-    use iced::futures;
-
-    pub fn every(
-        duration: std::time::Duration,
-    ) -> iced::Subscription<std::time::Instant> {
-        iced::Subscription::from_recipe(Every(duration))
-    }
-
-    struct Every(std::time::Duration);
-
-    impl<H, I> iced_native::subscription::Recipe<H, I> for Every
-    where
-        H: std::hash::Hasher,
-    {
-        type Output = std::time::Instant;
-
-        fn hash(&self, state: &mut H) {
-            use std::hash::Hash;
-
-            std::any::TypeId::of::<Self>().hash(state);
-            self.0.hash(state);
-        }
-
-        fn stream(
-            self: Box<Self>,
-            _input: futures::stream::BoxStream<'static, I>,
-        ) -> futures::stream::BoxStream<'static, Self::Output> {
-            use futures::stream::StreamExt;
-
-            async_std::stream::interval(self.0)
-                .map(|_| std::time::Instant::now())
-                .boxed()
+mod style {
+    use iced::{button, container, Background, Color, Vector};
+
+    pub struct Card;
+
+    impl container::StyleSheet for Card {
+        fn style(&self) -> container::Style {
+            container::Style {
+                background: Some(Background::Color(Color::from_rgb(
+                    0.95, 0.95, 0.97,
+                ))),
+                border_radius: 16.into(),
+                border_width: 1,
+                border_color: Color::from_rgb(0.85, 0.85, 0.88),
+                ..container::Style::default()
+            }
         }
     }
-}
-
-mod style {
-    use iced::{button, Background, Color, Vector};
 
     pub enum Button {
         Primary,
@@ -190,16 +191,40 @@ mod style {
 
     impl button::StyleSheet for Button {
         fn active(&self) -> button::Style {
-            button::Style {
-                background: Some(Background::Color(match self {
-                    Button::Primary => Color::from_rgb(0.11, 0.42, 0.87),
-                    Button::Secondary => Color::from_rgb(0.5, 0.5, 0.5),
-                    Button::Destructive => Color::from_rgb(0.8, 0.2, 0.2),
-                })),
-                border_radius: 12,
+            let filled = |color| button::Style {
+                background: Some(Background::Color(color)),
+                border_radius: 12.into(),
                 shadow_offset: Vector::new(1.0, 1.0),
                 text_color: Color::WHITE,
                 ..button::Style::default()
+            };
+
+            match self {
+                Button::Primary => filled(Color::from_rgb(0.11, 0.42, 0.87)),
+                Button::Secondary => button::Style {
+                    border_radius: 12.into(),
+                    border_width: 1,
+                    border_color: Color::from_rgb(0.5, 0.5, 0.5),
+                    text_color: Color::from_rgb(0.5, 0.5, 0.5),
+                    ..button::Style::default()
+                },
+                Button::Destructive => filled(Color::from_rgb(0.8, 0.2, 0.2)),
+            }
+        }
+
+        fn hovered(&self) -> button::Style {
+            let active = self.active();
+
+            match self {
+                Button::Secondary => button::Style {
+                    border_color: Color::from_rgb(0.3, 0.3, 0.3),
+                    text_color: Color::from_rgb(0.3, 0.3, 0.3),
+                    ..active
+                },
+                _ => button::Style {
+                    shadow_offset: active.shadow_offset + Vector::new(0.0, 1.0),
+                    ..active
+                },
             }
         }
     }
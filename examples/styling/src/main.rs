@@ -238,7 +238,7 @@ mod style {
                     background: Some(Background::Color(Color::from_rgb(
                         0.11, 0.42, 0.87,
                     ))),
-                    border_radius: 12,
+                    border_radius: 12.into(),
                     shadow_offset: Vector::new(1.0, 1.0),
                     text_color: Color::from_rgb8(0xEE, 0xEE, 0xEE),
                     ..button::Style::default()
@@ -362,7 +362,7 @@ mod style {
             fn active(&self) -> button::Style {
                 button::Style {
                     background: Some(Background::Color(ACTIVE)),
-                    border_radius: 3,
+                    border_radius: 3.into(),
                     text_color: Color::WHITE,
                     ..button::Style::default()
                 }
@@ -1,4 +1,7 @@
-use futures::future::{BoxFuture, Future, FutureExt};
+use futures::{
+    future::{self, BoxFuture, Future, FutureExt},
+    stream::{BoxStream, FuturesOrdered, StreamExt},
+};
 
 /// A collection of async operations.
 ///
@@ -8,6 +11,8 @@ use futures::future::{BoxFuture, Future, FutureExt};
 /// [`Command`]: struct.Command.html
 pub struct Command<T> {
     futures: Vec<BoxFuture<'static, T>>,
+    messages: Vec<T>,
+    streams: Vec<BoxStream<'static, T>>,
 }
 
 impl<T> Command<T> {
@@ -19,6 +24,23 @@ impl<T> Command<T> {
     pub fn none() -> Self {
         Self {
             futures: Vec::new(),
+            messages: Vec::new(),
+            streams: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that dispatches the given message right away.
+    ///
+    /// The runtime processes these messages in order, after any other pending
+    /// messages and before the next render. This allows you to chain the steps
+    /// of a state machine.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn message(message: T) -> Self {
+        Self {
+            futures: Vec::new(),
+            messages: vec![message],
+            streams: Vec::new(),
         }
     }
 
@@ -31,6 +53,32 @@ impl<T> Command<T> {
     ) -> Command<A> {
         Command {
             futures: vec![future.map(f).boxed()],
+            messages: Vec::new(),
+            streams: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the action of the given fallible
+    /// future.
+    ///
+    /// The result of the future is turned into a message with `on_ok` when it
+    /// succeeds, or with `on_err` when it fails.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn perform_result<A, E>(
+        future: impl Future<Output = Result<A, E>> + 'static + Send,
+        on_ok: impl Fn(A) -> T + 'static + Send,
+        on_err: impl Fn(E) -> T + 'static + Send,
+    ) -> Self {
+        Self {
+            futures: vec![future
+                .map(move |result| match result {
+                    Ok(value) => on_ok(value),
+                    Err(error) => on_err(error),
+                })
+                .boxed()],
+            messages: Vec::new(),
+            streams: Vec::new(),
         }
     }
 
@@ -56,6 +104,20 @@ impl<T> Command<T> {
                     future.map(move |result| f(result)).boxed()
                 })
                 .collect(),
+            messages: self
+                .messages
+                .drain(..)
+                .map(|message| f(message))
+                .collect(),
+            streams: self
+                .streams
+                .drain(..)
+                .map(|stream| {
+                    let f = f.clone();
+
+                    stream.map(move |result| f(result)).boxed()
+                })
+                .collect(),
         }
     }
 
@@ -63,20 +125,96 @@ impl<T> Command<T> {
     /// commands.
     ///
     /// Once this command is run, all the commands will be executed at once.
+    /// Their messages are produced in completion order. Use
+    /// [`Command::batch_ordered`] to produce them in submission order instead.
     ///
     /// [`Command`]: struct.Command.html
+    /// [`Command::batch_ordered`]: struct.Command.html#method.batch_ordered
     pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        let mut futures = Vec::new();
+        let mut messages = Vec::new();
+        let mut streams = Vec::new();
+
+        for command in commands {
+            futures.extend(command.futures);
+            messages.extend(command.messages);
+            streams.extend(command.streams);
+        }
+
         Self {
-            futures: commands
-                .into_iter()
-                .flat_map(|command| command.futures)
-                .collect(),
+            futures,
+            messages,
+            streams,
+        }
+    }
+
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands, producing their messages in submission order.
+    ///
+    /// Once this command is run, all the commands will be executed at once.
+    /// However, the messages of a command are held back until the messages of
+    /// all the commands submitted before it have been produced.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn batch_ordered(commands: impl IntoIterator<Item = Command<T>>) -> Self
+    where
+        T: Send + 'static,
+    {
+        let mut ordered = FuturesOrdered::new();
+        let mut streams = Vec::new();
+
+        for command in commands {
+            ordered.extend(
+                command
+                    .messages
+                    .into_iter()
+                    .map(|message| future::ready(message).boxed()),
+            );
+            ordered.extend(command.futures);
+            streams.extend(command.streams);
+        }
+
+        if !ordered.is_empty() {
+            streams.insert(0, ordered.boxed());
+        }
+
+        Self {
+            futures: Vec::new(),
+            messages: Vec::new(),
+            streams,
         }
     }
 
+    /// Takes the messages of the [`Command`] that should be dispatched right
+    /// away.
+    ///
+    /// See [`Command::message`] to learn more.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Command::message`]: struct.Command.html#method.message
+    pub fn take_messages(&mut self) -> Vec<T> {
+        std::mem::replace(&mut self.messages, Vec::new())
+    }
+
+    /// Takes the streams of the [`Command`], which may produce many messages
+    /// over time.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_streams(&mut self) -> Vec<BoxStream<'static, T>> {
+        std::mem::replace(&mut self.streams, Vec::new())
+    }
+
     /// Converts a [`Command`] into its underlying list of futures.
     ///
+    /// The streams of the [`Command`] are not included. Use
+    /// [`Command::take_streams`] to take them first.
+    ///
+    /// The messages of the [`Command`] are not included either. Use
+    /// [`Command::take_messages`] to take them first, or they will be lost.
+    ///
     /// [`Command`]: struct.Command.html
+    /// [`Command::take_messages`]: struct.Command.html#method.take_messages
+    /// [`Command::take_streams`]: struct.Command.html#method.take_streams
     pub fn futures(self) -> Vec<BoxFuture<'static, T>> {
         self.futures
     }
@@ -89,6 +227,8 @@ where
     fn from(future: A) -> Self {
         Self {
             futures: vec![future.boxed()],
+            messages: Vec::new(),
+            streams: Vec::new(),
         }
     }
 }
@@ -98,3 +238,116 @@ impl<T> std::fmt::Debug for Command<T> {
         f.debug_struct("Command").finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        Loaded(u32),
+        Failed(String),
+    }
+
+    fn messages(command: Command<Message>) -> Vec<Message> {
+        futures::executor::block_on(future::join_all(command.futures()))
+    }
+
+    #[test]
+    fn perform_result_maps_ok_values() {
+        let command = Command::perform_result(
+            future::ready(Ok::<u32, String>(42)),
+            Message::Loaded,
+            Message::Failed,
+        );
+
+        assert_eq!(messages(command), vec![Message::Loaded(42)]);
+    }
+
+    #[test]
+    fn perform_result_maps_errors() {
+        let command = Command::perform_result(
+            future::ready(Err::<u32, _>(String::from("Not found"))),
+            Message::Loaded,
+            Message::Failed,
+        );
+
+        assert_eq!(
+            messages(command),
+            vec![Message::Failed(String::from("Not found"))]
+        );
+    }
+
+    fn delayed(
+        value: u32,
+    ) -> (futures::channel::oneshot::Sender<()>, Command<Message>) {
+        let (sender, receiver) = futures::channel::oneshot::channel();
+
+        (
+            sender,
+            Command::perform(receiver, move |_| Message::Loaded(value)),
+        )
+    }
+
+    #[test]
+    fn batch_produces_messages_in_completion_order() {
+        use futures::stream::FuturesUnordered;
+
+        let (first, a) = delayed(1);
+        let (second, b) = delayed(2);
+
+        let mut messages: FuturesUnordered<_> =
+            Command::batch(vec![a, b]).futures().into_iter().collect();
+
+        second.send(()).expect("Complete second");
+        assert_eq!(
+            messages.next().now_or_never(),
+            Some(Some(Message::Loaded(2)))
+        );
+
+        first.send(()).expect("Complete first");
+        assert_eq!(
+            messages.next().now_or_never(),
+            Some(Some(Message::Loaded(1)))
+        );
+    }
+
+    #[test]
+    fn batch_ordered_produces_messages_in_submission_order() {
+        let (first, a) = delayed(1);
+        let (second, b) = delayed(2);
+
+        let mut command = Command::batch_ordered(vec![a, b]);
+        let mut streams = command.take_streams();
+
+        assert_eq!(streams.len(), 1);
+        assert!(command.futures().is_empty());
+
+        let messages = &mut streams[0];
+
+        second.send(()).expect("Complete second");
+        assert_eq!(messages.next().now_or_never(), None);
+
+        first.send(()).expect("Complete first");
+        assert_eq!(
+            messages.next().now_or_never(),
+            Some(Some(Message::Loaded(1)))
+        );
+        assert_eq!(
+            messages.next().now_or_never(),
+            Some(Some(Message::Loaded(2)))
+        );
+    }
+
+    #[test]
+    fn empty_batch_does_nothing() {
+        let mut batch = Command::<Message>::batch(Vec::new());
+        let mut ordered = Command::<Message>::batch_ordered(Vec::new());
+
+        assert!(batch.take_streams().is_empty());
+        assert!(batch.futures().is_empty());
+
+        assert!(ordered.take_streams().is_empty());
+        assert!(ordered.futures().is_empty());
+    }
+}
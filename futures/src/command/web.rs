@@ -1,4 +1,7 @@
-use futures::future::{Future, FutureExt};
+use futures::{
+    future::{self, Future, FutureExt},
+    stream::{FuturesOrdered, LocalBoxStream, StreamExt},
+};
 use std::pin::Pin;
 
 /// A collection of async operations.
@@ -9,6 +12,7 @@ use std::pin::Pin;
 /// [`Command`]: struct.Command.html
 pub struct Command<T> {
     futures: Vec<Pin<Box<dyn Future<Output = T> + 'static>>>,
+    streams: Vec<LocalBoxStream<'static, T>>,
 }
 
 impl<T> Command<T> {
@@ -20,6 +24,23 @@ impl<T> Command<T> {
     pub fn none() -> Self {
         Self {
             futures: Vec::new(),
+            streams: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that dispatches the given message right away.
+    ///
+    /// On the Web, these messages are processed in order as soon as the
+    /// current update finishes.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn message(message: T) -> Self
+    where
+        T: 'static,
+    {
+        Self {
+            futures: vec![future::ready(message).boxed_local()],
+            streams: Vec::new(),
         }
     }
 
@@ -32,6 +53,30 @@ impl<T> Command<T> {
     ) -> Command<A> {
         Command {
             futures: vec![future.map(f).boxed_local()],
+            streams: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the action of the given fallible
+    /// future.
+    ///
+    /// The result of the future is turned into a message with `on_ok` when it
+    /// succeeds, or with `on_err` when it fails.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn perform_result<A, E>(
+        future: impl Future<Output = Result<A, E>> + 'static,
+        on_ok: impl Fn(A) -> T + 'static,
+        on_err: impl Fn(E) -> T + 'static,
+    ) -> Self {
+        Self {
+            futures: vec![future
+                .map(move |result| match result {
+                    Ok(value) => on_ok(value),
+                    Err(error) => on_err(error),
+                })
+                .boxed_local()],
+            streams: Vec::new(),
         }
     }
 
@@ -57,6 +102,15 @@ impl<T> Command<T> {
                     future.map(move |result| f(result)).boxed_local()
                 })
                 .collect(),
+            streams: self
+                .streams
+                .drain(..)
+                .map(|stream| {
+                    let f = f.clone();
+
+                    stream.map(move |result| f(result)).boxed_local()
+                })
+                .collect(),
         }
     }
 
@@ -64,20 +118,68 @@ impl<T> Command<T> {
     /// commands.
     ///
     /// Once this command is run, all the commands will be executed at once.
+    /// Their messages are produced in completion order. Use
+    /// [`Command::batch_ordered`] to produce them in submission order instead.
     ///
     /// [`Command`]: struct.Command.html
+    /// [`Command::batch_ordered`]: struct.Command.html#method.batch_ordered
     pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        let mut futures = Vec::new();
+        let mut streams = Vec::new();
+
+        for command in commands {
+            futures.extend(command.futures);
+            streams.extend(command.streams);
+        }
+
+        Self { futures, streams }
+    }
+
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands, producing their messages in submission order.
+    ///
+    /// Once this command is run, all the commands will be executed at once.
+    /// However, the messages of a command are held back until the messages of
+    /// all the commands submitted before it have been produced.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn batch_ordered(commands: impl IntoIterator<Item = Command<T>>) -> Self
+    where
+        T: 'static,
+    {
+        let mut ordered = FuturesOrdered::new();
+        let mut streams = Vec::new();
+
+        for command in commands {
+            ordered.extend(command.futures);
+            streams.extend(command.streams);
+        }
+
+        if !ordered.is_empty() {
+            streams.insert(0, ordered.boxed_local());
+        }
+
         Self {
-            futures: commands
-                .into_iter()
-                .flat_map(|command| command.futures)
-                .collect(),
+            futures: Vec::new(),
+            streams,
         }
     }
 
+    /// Takes the streams of the [`Command`], which may produce many messages
+    /// over time.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_streams(&mut self) -> Vec<LocalBoxStream<'static, T>> {
+        std::mem::replace(&mut self.streams, Vec::new())
+    }
+
     /// Converts a [`Command`] into its underlying list of futures.
     ///
+    /// The streams of the [`Command`] are not included. Use
+    /// [`Command::take_streams`] to take them first.
+    ///
     /// [`Command`]: struct.Command.html
+    /// [`Command::take_streams`]: struct.Command.html#method.take_streams
     pub fn futures(self) -> Vec<Pin<Box<dyn Future<Output = T> + 'static>>> {
         self.futures
     }
@@ -90,6 +192,7 @@ where
     fn from(future: A) -> Self {
         Self {
             futures: vec![future.boxed_local()],
+            streams: Vec::new(),
         }
     }
 }
@@ -10,6 +10,9 @@ mod tokio;
 #[cfg(all(not(target_arch = "wasm32"), feature = "async-std"))]
 mod async_std;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "smol"))]
+mod smol;
+
 #[cfg(target_arch = "wasm32")]
 mod wasm_bindgen;
 
@@ -24,6 +27,9 @@ pub use self::tokio::Tokio;
 #[cfg(all(not(target_arch = "wasm32"), feature = "async-std"))]
 pub use self::async_std::AsyncStd;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "smol"))]
+pub use self::smol::Smol;
+
 #[cfg(target_arch = "wasm32")]
 pub use wasm_bindgen::WasmBindgen;
 
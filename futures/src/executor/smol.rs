@@ -0,0 +1,17 @@
+use crate::Executor;
+
+use futures::Future;
+
+/// A `smol` runtime.
+#[derive(Debug)]
+pub struct Smol;
+
+impl Executor for Smol {
+    fn new() -> Result<Self, futures::io::Error> {
+        Ok(Self)
+    }
+
+    fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        smol::spawn(future).detach();
+    }
+}
@@ -3,6 +3,14 @@ use crate::Executor;
 use futures::Future;
 
 /// A `tokio` runtime.
+///
+/// The runtime is owned by the [`Runtime`] of commands and subscriptions
+/// using it, which spawns both of them on it. The streams of subscriptions
+/// are created inside of it, so they can use `tokio` resources, like timers.
+///
+/// Dropping it shuts the runtime down, cancelling every pending future.
+///
+/// [`Runtime`]: ../struct.Runtime.html
 pub type Tokio = tokio::runtime::Runtime;
 
 impl Executor for Tokio {
@@ -18,3 +26,56 @@ impl Executor for Tokio {
         tokio::runtime::Runtime::enter(self, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, Runtime, Subscription};
+
+    use futures::{channel::mpsc, executor, StreamExt};
+    use std::{
+        collections::hash_map::DefaultHasher,
+        time::{Duration, Instant},
+    };
+
+    fn runtime<T: Send + 'static>() -> (
+        Runtime<DefaultHasher, (), Tokio, mpsc::UnboundedSender<T>, T>,
+        mpsc::UnboundedReceiver<T>,
+    ) {
+        let (sender, receiver) = mpsc::unbounded();
+        let executor = Tokio::new().expect("Create runtime");
+
+        (Runtime::new(executor, sender), receiver)
+    }
+
+    #[test]
+    fn spawned_command_delivers_its_message() {
+        let (mut runtime, mut receiver) = runtime();
+
+        // The timer is only available inside of the runtime, where the
+        // future is polled
+        runtime.spawn(Command::perform(
+            async {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+            },
+            |_| 42,
+        ));
+
+        assert_eq!(executor::block_on(receiver.next()), Some(42));
+    }
+
+    #[test]
+    fn subscription_runs_on_the_runtime() {
+        let (mut runtime, mut receiver) = runtime();
+        let start = Instant::now();
+
+        let subscription: Subscription<DefaultHasher, (), Instant> =
+            crate::time::after(Duration::from_millis(10));
+
+        runtime.track(subscription);
+
+        let message = executor::block_on(receiver.next()).expect("Message");
+
+        assert!(message >= start + Duration::from_millis(10));
+    }
+}
@@ -12,6 +12,12 @@ mod runtime;
 pub mod executor;
 pub mod subscription;
 
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+pub mod time;
+
 pub use command::Command;
 pub use executor::Executor;
 pub use runtime::Runtime;
@@ -65,8 +65,22 @@ where
     ///
     /// [`Command`]: struct.Command.html
     /// [`Runtime`]: struct.Runtime.html
-    pub fn spawn(&mut self, command: Command<Message>) {
-        use futures::{FutureExt, SinkExt};
+    pub fn spawn(&mut self, mut command: Command<Message>) {
+        use futures::{FutureExt, SinkExt, StreamExt};
+
+        for stream in command.take_streams() {
+            let future = stream.map(Ok).forward(self.sender.clone());
+
+            self.executor.spawn(future.map(|_| ()));
+        }
+
+        for message in command.take_messages() {
+            let mut sender = self.sender.clone();
+
+            self.executor.spawn(async move {
+                let _ = sender.send(message).await;
+            });
+        }
 
         let futures = command.futures();
 
@@ -1,6 +1,10 @@
 //! Listen to external events in your application.
+mod paused;
 mod tracker;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod throttle;
+
 pub use tracker::Tracker;
 
 use futures::stream::BoxStream;
@@ -50,6 +54,34 @@ where
         }
     }
 
+    /// Creates a [`Subscription`] that produces its output directly from the
+    /// input events of the runtime.
+    ///
+    /// Every [`Recipe`] receives a stream of the events handled by the
+    /// runtime, which are published to all the subscriptions alive with
+    /// [`Tracker::broadcast`]. The given `builder` turns this stream into the
+    /// output of the [`Subscription`]; for instance, by filtering the events
+    /// your application cares about.
+    ///
+    /// The `id` and the `builder` are used to identify the [`Subscription`].
+    /// This is why the `builder` needs to be a function pointer: closures
+    /// capturing state could not be told apart!
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    /// [`Recipe`]: trait.Recipe.html
+    /// [`Tracker::broadcast`]: struct.Tracker.html#method.broadcast
+    pub fn with_input<I>(
+        id: I,
+        builder: fn(BoxStream<'static, E>) -> BoxStream<'static, O>,
+    ) -> Self
+    where
+        I: std::hash::Hash + 'static,
+        E: 'static,
+        O: 'static,
+    {
+        Self::from_recipe(WithInput { id, builder })
+    }
+
     /// Batches all the provided subscriptions and returns the resulting
     /// [`Subscription`].
     ///
@@ -98,6 +130,99 @@ where
                 .collect(),
         }
     }
+
+    /// Transforms the [`Subscription`] output with the given function
+    /// pointer.
+    ///
+    /// Unlike [`map`], the function is stored by value and called directly,
+    /// without any dynamic dispatch. Enum variant constructors, like
+    /// `Message::Tick`, can be used as function pointers!
+    ///
+    /// The function pointer is part of the identity of the resulting
+    /// [`Subscription`]. Therefore, mapping the same [`Subscription`] with
+    /// different functions produces different subscriptions.
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    /// [`map`]: #method.map
+    pub fn map_with<A>(mut self, f: fn(O) -> A) -> Subscription<H, E, A>
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static,
+        A: 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(MapWith { recipe, mapper: f })
+                        as Box<dyn Recipe<H, E, Output = A>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Suppresses the output of the [`Subscription`] while the given flag is
+    /// set.
+    ///
+    /// The identity of the [`Subscription`] does not depend on the flag.
+    /// Therefore, pausing and resuming it does not restart its underlying
+    /// streams. For instance, a paused [`time::every`] keeps its cadence.
+    ///
+    /// Keep the flag in your application state, toggle it to pause or resume
+    /// the [`Subscription`] and keep wrapping the [`Subscription`] with it
+    /// while it is running, as a [`Subscription`] is identified differently
+    /// once it is wrapped.
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    /// [`time::every`]: ../time/fn.every.html
+    pub fn paused(
+        mut self,
+        is_paused: &std::sync::Arc<std::sync::atomic::AtomicBool>,
+    ) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static + Send,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(paused::Paused::new(recipe, is_paused.clone()))
+                        as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Limits the rate of the [`Subscription`] output, producing at most one
+    /// item for every `window` of time.
+    ///
+    /// Intermediate items produced during a `window` are dropped, but the last
+    /// one is always delivered once the `window` closes.
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn throttle(mut self, window: std::time::Duration) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static + Send,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(throttle::Throttle::new(recipe, window))
+                        as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
 }
 
 impl<I, O, H> std::fmt::Debug for Subscription<I, O, H> {
@@ -134,7 +259,8 @@ pub trait Recipe<Hasher: std::hash::Hasher, Event> {
     /// [`Subscription`].
     ///
     /// It receives some stream of generic events, which is normally defined by
-    /// shells.
+    /// shells. The stream yields every event broadcast by the runtime while
+    /// the [`Subscription`] is alive.
     ///
     /// [`Subscription`]: struct.Subscription.html
     /// [`Recipe`]: trait.Recipe.html
@@ -187,3 +313,208 @@ where
             .boxed()
     }
 }
+
+struct WithInput<I, Event, Output> {
+    id: I,
+    builder: fn(BoxStream<'static, Event>) -> BoxStream<'static, Output>,
+}
+
+impl<I, H, E, O> Recipe<H, E> for WithInput<I, E, O>
+where
+    I: std::hash::Hash + 'static,
+    H: std::hash::Hasher,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<I>().hash(state);
+        self.id.hash(state);
+        (self.builder as usize).hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        (self.builder)(input)
+    }
+}
+
+struct MapWith<Hasher, Event, A, B> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
+    mapper: fn(A) -> B,
+}
+
+impl<H, E, A, B> Recipe<H, E> for MapWith<H, E, A, B>
+where
+    A: 'static,
+    B: 'static,
+    H: std::hash::Hasher,
+{
+    type Output = B;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<B>().hash(state);
+        self.recipe.hash(state);
+        (self.mapper as usize).hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::StreamExt;
+
+        self.recipe.stream(input).map(self.mapper).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::{self, StreamExt};
+    use std::collections::hash_map::DefaultHasher;
+
+    type Subscription<O> = super::Subscription<DefaultHasher, u32, O>;
+
+    /// Produces the given numbers.
+    struct Numbers(Vec<u32>);
+
+    impl Recipe<DefaultHasher, u32> for Numbers {
+        type Output = u32;
+
+        fn hash(&self, state: &mut DefaultHasher) {
+            use std::hash::Hash;
+
+            self.0.hash(state);
+        }
+
+        fn stream(
+            self: Box<Self>,
+            _input: BoxStream<'static, u32>,
+        ) -> BoxStream<'static, u32> {
+            stream::iter(self.0).boxed()
+        }
+    }
+
+    fn numbers() -> Subscription<u32> {
+        Subscription::from_recipe(Numbers(vec![1, 2, 3]))
+    }
+
+    fn hashes<O>(subscription: Subscription<O>) -> Vec<u64> {
+        use std::hash::Hasher;
+
+        subscription
+            .recipes()
+            .iter()
+            .map(|recipe| {
+                let mut hasher = DefaultHasher::default();
+                recipe.hash(&mut hasher);
+
+                hasher.finish()
+            })
+            .collect()
+    }
+
+    fn output<O: 'static>(subscription: Subscription<O>) -> Vec<O> {
+        futures::executor::block_on(
+            stream::select_all(
+                subscription
+                    .recipes()
+                    .into_iter()
+                    .map(|recipe| recipe.stream(stream::empty().boxed())),
+            )
+            .collect(),
+        )
+    }
+
+    fn double(number: u32) -> u64 {
+        u64::from(number) * 2
+    }
+
+    fn triple(number: u32) -> u64 {
+        u64::from(number) * 3
+    }
+
+    #[test]
+    fn map_with_produces_the_same_output_as_map() {
+        assert_eq!(output(numbers().map_with(double)), vec![2, 4, 6]);
+        assert_eq!(
+            output(numbers().map_with(double)),
+            output(numbers().map(double))
+        );
+    }
+
+    #[test]
+    fn map_with_is_identified_by_its_function() {
+        assert_eq!(
+            hashes(numbers().map_with(double)),
+            hashes(numbers().map_with(double))
+        );
+        assert_ne!(
+            hashes(numbers().map_with(double)),
+            hashes(numbers().map_with(triple))
+        );
+    }
+
+    fn echo(input: BoxStream<'static, u32>) -> BoxStream<'static, u32> {
+        input
+    }
+
+    fn ignore(_input: BoxStream<'static, u32>) -> BoxStream<'static, u32> {
+        stream::empty().boxed()
+    }
+
+    #[test]
+    fn with_input_receives_broadcast_events() {
+        use futures::{
+            channel::mpsc, executor::LocalPool, task::LocalSpawnExt,
+        };
+
+        let mut tracker = Tracker::<DefaultHasher, u32>::new();
+        let (sender, receiver) = mpsc::channel(10);
+
+        let mut pool = LocalPool::new();
+
+        for future in
+            tracker.update(Subscription::with_input("echo", echo), sender)
+        {
+            pool.spawner().spawn_local(future).expect("Spawn future");
+        }
+
+        tracker.broadcast(1);
+        tracker.broadcast(2);
+
+        assert_eq!(
+            pool.run_until(receiver.take(2).collect::<Vec<_>>()),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn with_input_is_identified_by_its_id_and_builder() {
+        fn subscription(
+            id: &'static str,
+            builder: fn(BoxStream<'static, u32>) -> BoxStream<'static, u32>,
+        ) -> Subscription<u32> {
+            Subscription::with_input(id, builder)
+        }
+
+        assert_eq!(
+            hashes(subscription("echo", echo)),
+            hashes(subscription("echo", echo))
+        );
+        assert_ne!(
+            hashes(subscription("echo", echo)),
+            hashes(subscription("other", echo))
+        );
+        assert_ne!(
+            hashes(subscription("echo", echo)),
+            hashes(subscription("echo", ignore))
+        );
+    }
+}
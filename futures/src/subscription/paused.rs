@@ -0,0 +1,101 @@
+use crate::subscription::Recipe;
+
+use futures::stream::{BoxStream, StreamExt};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+pub struct Paused<Hasher, Event, Output> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = Output>>,
+    is_paused: Arc<AtomicBool>,
+}
+
+impl<H, E, O> Paused<H, E, O> {
+    pub fn new(
+        recipe: Box<dyn Recipe<H, E, Output = O>>,
+        is_paused: Arc<AtomicBool>,
+    ) -> Self {
+        Paused { recipe, is_paused }
+    }
+}
+
+impl<H, E, O> Recipe<H, E> for Paused<H, E, O>
+where
+    H: std::hash::Hasher,
+    O: 'static + Send,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Paused<(), (), ()>>().hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        let is_paused = self.is_paused;
+
+        self.recipe
+            .stream(input)
+            .filter(move |_| {
+                futures::future::ready(!is_paused.load(Ordering::Relaxed))
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{channel::mpsc, FutureExt};
+    use std::collections::hash_map::DefaultHasher;
+
+    /// Produces the events it receives.
+    struct Echo;
+
+    impl Recipe<DefaultHasher, u32> for Echo {
+        type Output = u32;
+
+        fn hash(&self, _state: &mut DefaultHasher) {}
+
+        fn stream(
+            self: Box<Self>,
+            input: BoxStream<'static, u32>,
+        ) -> BoxStream<'static, u32> {
+            input
+        }
+    }
+
+    #[test]
+    fn paused_stream_resumes_where_it_stopped() {
+        let is_paused = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::unbounded();
+
+        let mut stream = Box::new(Paused::<DefaultHasher, u32, u32>::new(
+            Box::new(Echo),
+            is_paused.clone(),
+        ))
+        .stream(receiver.boxed());
+
+        sender.unbounded_send(1).expect("Send event");
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+
+        is_paused.store(true, Ordering::Relaxed);
+
+        sender.unbounded_send(2).expect("Send event");
+        assert_eq!(stream.next().now_or_never(), None);
+
+        is_paused.store(false, Ordering::Relaxed);
+
+        sender.unbounded_send(3).expect("Send event");
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+
+        sender.close_channel();
+        assert_eq!(stream.next().now_or_never(), Some(None));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::subscription::Recipe;
+
+use futures::{
+    future::FutureExt,
+    stream::{BoxStream, Stream, StreamExt},
+    task::{Context, Poll},
+};
+use futures_timer::Delay;
+use std::{
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+pub struct Throttle<Hasher, Event, Output> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = Output>>,
+    window: Duration,
+}
+
+impl<H, E, O> Throttle<H, E, O> {
+    pub fn new(
+        recipe: Box<dyn Recipe<H, E, Output = O>>,
+        window: Duration,
+    ) -> Self {
+        Throttle { recipe, window }
+    }
+}
+
+impl<H, E, O> Recipe<H, E> for Throttle<H, E, O>
+where
+    H: std::hash::Hasher,
+    O: 'static + Send,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Throttled<()>>().hash(state);
+        self.window.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        Throttled {
+            stream: self.recipe.stream(input),
+            window: self.window,
+            last_emitted: None,
+            pending: None,
+            delay: None,
+            is_done: false,
+        }
+        .boxed()
+    }
+}
+
+/// The maximum amount of items taken from the inner stream in a single poll,
+/// so a stream that is always ready cannot starve the executor.
+const MAX_ITEMS_PER_POLL: usize = 32;
+
+struct Throttled<T> {
+    stream: BoxStream<'static, T>,
+    window: Duration,
+    last_emitted: Option<Instant>,
+    pending: Option<T>,
+    delay: Option<Delay>,
+    is_done: bool,
+}
+
+// The pending item is never pinned
+impl<T> Unpin for Throttled<T> {}
+
+impl<T> Stream for Throttled<T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<T>> {
+        // Keep only the latest item produced by the inner stream
+        let mut is_drained = self.is_done;
+
+        for _ in 0..MAX_ITEMS_PER_POLL {
+            if is_drained {
+                break;
+            }
+
+            match self.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    self.pending = Some(item);
+                }
+                Poll::Ready(None) => {
+                    self.is_done = true;
+                    is_drained = true;
+                }
+                Poll::Pending => {
+                    is_drained = true;
+                }
+            }
+        }
+
+        // Items left in the inner stream are taken in the next poll
+        if !is_drained {
+            cx.waker().wake_by_ref();
+        }
+
+        if self.pending.is_none() {
+            return if self.is_done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+
+        let now = Instant::now();
+
+        if let Some(last_emitted) = self.last_emitted {
+            let deadline = last_emitted + self.window;
+
+            // Wait until the window closes, so the last item of a burst is
+            // still delivered
+            if now < deadline {
+                let delay = self
+                    .delay
+                    .get_or_insert_with(|| Delay::new(deadline - now));
+
+                if delay.poll_unpin(cx).is_pending() {
+                    return Poll::Pending;
+                }
+            }
+        }
+
+        self.delay = None;
+        self.last_emitted = Some(Instant::now());
+
+        Poll::Ready(self.pending.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::subscription::{Recipe, Subscription};
+
+    use futures::stream::{self, BoxStream, StreamExt};
+    use futures_timer::Delay;
+    use std::collections::hash_map::DefaultHasher;
+    use std::time::{Duration, Instant};
+
+    /// Produces `items` numbers, one every `interval`.
+    struct Source {
+        items: usize,
+        interval: Duration,
+    }
+
+    impl Recipe<DefaultHasher, ()> for Source {
+        type Output = usize;
+
+        fn hash(&self, state: &mut DefaultHasher) {
+            use std::hash::Hash;
+
+            self.items.hash(state);
+        }
+
+        fn stream(
+            self: Box<Self>,
+            _input: BoxStream<'static, ()>,
+        ) -> BoxStream<'static, usize> {
+            let Source { items, interval } = *self;
+
+            stream::unfold(0, move |item| async move {
+                if item == items {
+                    None
+                } else {
+                    Delay::new(interval).await;
+
+                    Some((item, item + 1))
+                }
+            })
+            .boxed()
+        }
+    }
+
+    #[test]
+    fn throttles_a_fast_source() {
+        let window = Duration::from_millis(50);
+
+        let mut recipes = Subscription::from_recipe(Source {
+            items: 40,
+            interval: Duration::from_millis(5),
+        })
+        .throttle(window)
+        .recipes();
+
+        let recipe = recipes.pop().expect("Throttled recipe");
+
+        let output: Vec<(usize, Instant)> = futures::executor::block_on(
+            recipe
+                .stream(stream::empty().boxed())
+                .map(|item| (item, Instant::now()))
+                .collect(),
+        );
+
+        // 40 items over 200ms produce about one item per window
+        assert!(output.len() >= 3, "{} items", output.len());
+        assert!(output.len() <= 6, "{} items", output.len());
+
+        for pair in output.windows(2) {
+            assert!(pair[1].1 - pair[0].1 >= window - Duration::from_millis(5));
+        }
+
+        // The last item of the burst is delivered
+        assert_eq!(output.last().map(|(item, _)| *item), Some(39));
+    }
+}
@@ -0,0 +1,209 @@
+//! Listen and react to time.
+//!
+//! The timers are provided by the first enabled executor feature, in this
+//! order: `tokio`, `async-std`, and `smol`. The choice is made at compile
+//! time and does not depend on the executor that runs the subscriptions.
+//! For instance, if both `tokio` and `smol` are enabled, the timers are
+//! `tokio` timers, which need a `tokio` runtime even if the application uses
+//! a `smol` executor.
+use crate::subscription::{self, Subscription};
+
+use futures::stream::BoxStream;
+
+/// Returns a [`Subscription`] that produces messages at a set interval.
+///
+/// The first message is produced after a `duration`, and then continues to
+/// produce more messages every `duration` after that.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+pub fn every<H, E>(
+    duration: std::time::Duration,
+) -> Subscription<H, E, std::time::Instant>
+where
+    H: std::hash::Hasher,
+{
+    Subscription::from_recipe(Every(duration))
+}
+
+struct Every(std::time::Duration);
+
+impl<H, E> subscription::Recipe<H, E> for Every
+where
+    H: std::hash::Hasher,
+{
+    type Output = std::time::Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    #[cfg(feature = "tokio")]
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        let start = tokio::time::Instant::now() + self.0;
+
+        tokio::time::interval_at(start, self.0)
+            .map(|_| std::time::Instant::now())
+            .boxed()
+    }
+
+    #[cfg(all(not(feature = "tokio"), feature = "async-std"))]
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        async_std::stream::interval(self.0)
+            .map(|_| std::time::Instant::now())
+            .boxed()
+    }
+
+    #[cfg(not(any(feature = "tokio", feature = "async-std")))]
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        smol::Timer::interval(self.0).boxed()
+    }
+}
+
+/// Returns a [`Subscription`] that produces a single message once the given
+/// `duration` has elapsed, and then completes.
+///
+/// If the [`Subscription`] stops being requested before the `duration`
+/// elapses, the delay is cancelled and no message is produced.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+pub fn after<H, E>(
+    duration: std::time::Duration,
+) -> Subscription<H, E, std::time::Instant>
+where
+    H: std::hash::Hasher,
+{
+    Subscription::from_recipe(After(duration))
+}
+
+struct After(std::time::Duration);
+
+impl<H, E> subscription::Recipe<H, E> for After
+where
+    H: std::hash::Hasher,
+{
+    type Output = std::time::Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    #[cfg(feature = "tokio")]
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        futures::stream::once(async move {
+            tokio::time::delay_for(self.0).await;
+
+            std::time::Instant::now()
+        })
+        .boxed()
+    }
+
+    #[cfg(all(not(feature = "tokio"), feature = "async-std"))]
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        futures::stream::once(async move {
+            async_std::task::sleep(self.0).await;
+
+            std::time::Instant::now()
+        })
+        .boxed()
+    }
+
+    #[cfg(not(any(feature = "tokio", feature = "async-std")))]
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<'static, E>,
+    ) -> BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        futures::stream::once(smol::Timer::after(self.0)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::{self, StreamExt};
+    use std::collections::hash_map::DefaultHasher;
+    use std::time::{Duration, Instant};
+
+    const TOLERANCE: Duration = Duration::from_millis(100);
+
+    fn block_on<T>(future: impl futures::Future<Output = T>) -> T {
+        #[cfg(feature = "tokio")]
+        {
+            tokio::runtime::Runtime::new()
+                .expect("Create runtime")
+                .block_on(future)
+        }
+
+        #[cfg(not(feature = "tokio"))]
+        {
+            futures::executor::block_on(future)
+        }
+    }
+
+    fn stream<R>(recipe: R) -> BoxStream<'static, Instant>
+    where
+        R: 'static + subscription::Recipe<DefaultHasher, (), Output = Instant>,
+    {
+        Box::new(recipe).stream(stream::empty().boxed())
+    }
+
+    #[test]
+    fn every_ticks_at_the_interval() {
+        let duration = Duration::from_millis(50);
+        let start = Instant::now();
+
+        let ticks: Vec<Instant> =
+            block_on(stream(Every(duration)).take(2).collect());
+
+        assert_eq!(ticks.len(), 2);
+        assert!(ticks[0] >= start + duration);
+        assert!(ticks[0] <= start + duration + TOLERANCE);
+        assert!(ticks[1] >= start + duration * 2);
+        assert!(ticks[1] <= start + duration * 2 + TOLERANCE);
+    }
+
+    #[test]
+    fn after_produces_a_single_message() {
+        let duration = Duration::from_millis(50);
+        let start = Instant::now();
+
+        let messages: Vec<Instant> =
+            block_on(stream(After(duration)).collect());
+
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0] >= start + duration);
+        assert!(messages[0] <= start + duration + TOLERANCE);
+    }
+}
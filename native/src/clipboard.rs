@@ -5,4 +5,17 @@ pub trait Clipboard {
     ///
     /// [`Clipboard`]: trait.Clipboard.html
     fn content(&self) -> Option<String>;
+
+    /// Replaces the content of the [`Clipboard`] with the given text.
+    ///
+    /// It returns whether the content was written. Widgets should not discard
+    /// any data, like when cutting text, unless the write succeeded.
+    ///
+    /// By default, it does nothing and returns `false`, for clipboards that
+    /// can only be read.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    fn set_content(&self, _content: String) -> bool {
+        false
+    }
 }
@@ -0,0 +1,173 @@
+use crate::{Rectangle, Size};
+
+/// The strategy used to fit the contents of a widget, like an image, into
+/// its bounds.
+///
+/// It follows the [`object-fit`] property of CSS.
+///
+/// [`object-fit`]: https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentFit {
+    /// Scale the content as large as possible while keeping its aspect ratio
+    /// and fitting it entirely inside the bounds.
+    ///
+    /// The content may not cover the whole bounds.
+    Contain,
+
+    /// Scale the content as small as possible while keeping its aspect ratio
+    /// and covering the whole bounds.
+    ///
+    /// The parts of the content outside of the bounds are clipped.
+    Cover,
+
+    /// Stretch the content to the size of the bounds, ignoring its aspect
+    /// ratio.
+    Fill,
+
+    /// Keep the content at its original size.
+    ///
+    /// The parts of the content outside of the bounds are clipped.
+    None,
+
+    /// Behave like `None` when the content fits inside the bounds, and like
+    /// `Contain` otherwise.
+    ///
+    /// The content is only ever scaled down.
+    ScaleDown,
+}
+
+impl ContentFit {
+    /// Returns the size of content with the given size once fitted into
+    /// bounds of the given size.
+    ///
+    /// Content with an empty size is stretched to the bounds, as it has no
+    /// aspect ratio to keep.
+    pub fn fit(self, content: Size, bounds: Size) -> Size {
+        if content.width <= 0.0 || content.height <= 0.0 {
+            return bounds;
+        }
+
+        let width_ratio = bounds.width / content.width;
+        let height_ratio = bounds.height / content.height;
+
+        let scale = match self {
+            ContentFit::Contain => width_ratio.min(height_ratio),
+            ContentFit::Cover => width_ratio.max(height_ratio),
+            ContentFit::Fill => return bounds,
+            ContentFit::None => 1.0,
+            ContentFit::ScaleDown => width_ratio.min(height_ratio).min(1.0),
+        };
+
+        Size::new(content.width * scale, content.height * scale)
+    }
+
+    /// Returns the rectangle where content with the given size should be
+    /// drawn once fitted into the given bounds.
+    ///
+    /// The content is centered in the bounds. The resulting rectangle may be
+    /// larger than the bounds, in which case the content should be clipped.
+    pub fn place(self, content: Size, bounds: Rectangle) -> Rectangle {
+        let size = self.fit(content, Size::new(bounds.width, bounds.height));
+
+        Rectangle {
+            x: bounds.x + (bounds.width - size.width) / 2.0,
+            y: bounds.y + (bounds.height - size.height) / 2.0,
+            width: size.width,
+            height: size.height,
+        }
+    }
+}
+
+impl Default for ContentFit {
+    fn default() -> Self {
+        ContentFit::Contain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 2:1 image fitted into a square
+    const CONTENT: Size = Size {
+        width: 200.0,
+        height: 100.0,
+    };
+
+    const BOUNDS: Size = Size {
+        width: 100.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn contain_fits_the_whole_content() {
+        assert_eq!(
+            ContentFit::Contain.fit(CONTENT, BOUNDS),
+            Size::new(100.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn cover_fills_the_whole_bounds() {
+        assert_eq!(
+            ContentFit::Cover.fit(CONTENT, BOUNDS),
+            Size::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn fill_stretches_the_content() {
+        assert_eq!(ContentFit::Fill.fit(CONTENT, BOUNDS), BOUNDS);
+    }
+
+    #[test]
+    fn none_keeps_the_original_size() {
+        assert_eq!(ContentFit::None.fit(CONTENT, BOUNDS), CONTENT);
+    }
+
+    #[test]
+    fn scale_down_only_shrinks_the_content() {
+        assert_eq!(
+            ContentFit::ScaleDown.fit(CONTENT, BOUNDS),
+            Size::new(100.0, 50.0)
+        );
+        assert_eq!(
+            ContentFit::ScaleDown.fit(CONTENT, Size::new(400.0, 400.0)),
+            CONTENT
+        );
+    }
+
+    #[test]
+    fn empty_content_is_stretched() {
+        assert_eq!(ContentFit::Contain.fit(Size::ZERO, BOUNDS), BOUNDS);
+    }
+
+    #[test]
+    fn placed_content_is_centered() {
+        let bounds = Rectangle {
+            x: 10.0,
+            y: 10.0,
+            width: 100.0,
+            height: 100.0,
+        };
+
+        assert_eq!(
+            ContentFit::Contain.place(CONTENT, bounds),
+            Rectangle {
+                x: 10.0,
+                y: 35.0,
+                width: 100.0,
+                height: 50.0,
+            }
+        );
+        assert_eq!(
+            ContentFit::Cover.place(CONTENT, bounds),
+            Rectangle {
+                x: -40.0,
+                y: 10.0,
+                width: 200.0,
+                height: 100.0,
+            }
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
-    layout, Clipboard, Color, Event, Hasher, Layout, Length, Point, Widget,
+    layout, overlay, renderer, Align, Clipboard, Color, Event, Hasher, Layout,
+    Length, Point, Rectangle, Widget,
 };
 
+use std::time::Instant;
+
 /// A generic [`Widget`].
 ///
 /// It is useful to build composable user interfaces that do not leak
@@ -201,6 +204,45 @@ where
         }
     }
 
+    /// Multiplies the alpha of everything drawn by the [`Element`] by the
+    /// given opacity.
+    ///
+    /// The opacity is clamped to `[0.0, 1.0]`. Nested opacities multiply, so
+    /// an opacity of `0.5` inside another one of `0.5` draws at `0.25`. This
+    /// can be used to fade an [`Element`] in and out, driving the opacity with
+    /// a [`Subscription`].
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Subscription`]: subscription/type.Subscription.html
+    pub fn opacity(self, opacity: f32) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a + renderer::Opacity,
+    {
+        Element {
+            widget: Box::new(Opacity::new(self, opacity)),
+        }
+    }
+
+    /// Overrides the cross-axis alignment of the [`Element`] inside its parent
+    /// [`Row`] or [`Column`].
+    ///
+    /// This is useful when a single child needs a different alignment than
+    /// the one set with `align_items` for the rest of its siblings.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Row`]: widget/struct.Row.html
+    /// [`Column`]: widget/struct.Column.html
+    pub fn align_self(self, align: Align) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(AlignSelf::new(self, align)),
+        }
+    }
+
     /// Returns the width of the [`Element`].
     ///
     /// [`Element`]: struct.Element.html
@@ -272,7 +314,9 @@ impl<'a, A, B, Renderer> Map<'a, A, B, Renderer> {
 
 impl<'a, A, B, Renderer> Widget<B, Renderer> for Map<'a, A, B, Renderer>
 where
-    Renderer: crate::Renderer,
+    A: 'static,
+    B: 'static,
+    Renderer: crate::Renderer + 'a,
 {
     fn width(&self) -> Length {
         self.widget.width()
@@ -315,6 +359,38 @@ where
             .for_each(|message| messages.push((self.mapper)(message)));
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.widget.can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn align_self(&self) -> Option<Align> {
+        self.widget.align_self()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, B, Renderer>> {
+        let mapper = self.mapper.as_ref();
+
+        self.widget
+            .overlay(layout)
+            .map(move |overlay| overlay.map(mapper))
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.widget.redraw_at()
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -385,6 +461,36 @@ where
         )
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.element.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.element
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn align_self(&self) -> Option<Align> {
+        self.element.widget.align_self()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.element.widget.overlay(layout)
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.element.widget.redraw_at()
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -405,3 +511,213 @@ where
         self.element.widget.hash_layout(state);
     }
 }
+
+struct AlignSelf<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    align: Align,
+}
+
+impl<'a, Message, Renderer> AlignSelf<'a, Message, Renderer> {
+    fn new(element: Element<'a, Message, Renderer>, align: Align) -> Self {
+        AlignSelf { element, align }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AlignSelf<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.element.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.widget.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element.widget.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.element.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.element.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.element
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn align_self(&self) -> Option<Align> {
+        Some(self.align)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.element.widget.overlay(layout)
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.element.widget.redraw_at()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.element
+            .widget
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        self.align.hash(state);
+        self.element.widget.hash_layout(state);
+    }
+}
+
+struct Opacity<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    opacity: f32,
+}
+
+impl<'a, Message, Renderer> Opacity<'a, Message, Renderer> {
+    fn new(element: Element<'a, Message, Renderer>, opacity: f32) -> Self {
+        Opacity {
+            element,
+            opacity: if opacity.is_nan() {
+                1.0
+            } else {
+                opacity.max(0.0).min(1.0)
+            },
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Opacity<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer + renderer::Opacity,
+{
+    fn width(&self) -> Length {
+        self.element.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.widget.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element.widget.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.element.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.element.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.element
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn align_self(&self) -> Option<Align> {
+        self.element.widget.align_self()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.element.widget.overlay(layout)
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.element.widget.redraw_at()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.opacity(
+            defaults,
+            self.element.widget.as_ref(),
+            layout,
+            cursor_position,
+            self.opacity,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.element.widget.hash_layout(state);
+    }
+}
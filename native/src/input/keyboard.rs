@@ -1,8 +1,10 @@
 //! Build keyboard events.
 mod event;
+mod key_binding;
 mod key_code;
 mod modifiers_state;
 
 pub use event::Event;
+pub use key_binding::{KeyBinding, ParseKeyBindingError};
 pub use key_code::KeyCode;
 pub use modifiers_state::ModifiersState;
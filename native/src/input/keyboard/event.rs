@@ -8,6 +8,7 @@ use crate::input::ButtonState;
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard key was pressed or released.
     Input {
@@ -23,4 +24,10 @@ pub enum Event {
 
     /// A unicode character was received.
     CharacterReceived(char),
+
+    /// A widget gained or lost focus.
+    ///
+    /// It holds whether a widget has focus now. The runtime produces it after
+    /// the widgets have handled the events that changed their focus.
+    FocusChanged(bool),
 }
@@ -0,0 +1,514 @@
+use super::{KeyCode, ModifiersState};
+
+use std::{fmt, str::FromStr};
+
+/// A combination of modifiers and a key, like `Ctrl+S`.
+///
+/// A [`KeyBinding`] can be parsed from a human-readable string and displayed
+/// back in the same format:
+///
+/// ```
+/// use iced_native::input::keyboard::{KeyBinding, KeyCode};
+///
+/// let binding: KeyBinding = "Ctrl+Shift+S".parse().unwrap();
+///
+/// assert_eq!(binding.key_code(), KeyCode::S);
+/// assert_eq!(binding.to_string(), "Ctrl+Shift+S");
+/// ```
+///
+/// [`KeyBinding`]: struct.KeyBinding.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    modifiers: ModifiersState,
+    key_code: KeyCode,
+}
+
+impl KeyBinding {
+    /// Creates a new [`KeyBinding`] with the given modifiers and key.
+    ///
+    /// [`KeyBinding`]: struct.KeyBinding.html
+    pub fn new(modifiers: ModifiersState, key_code: KeyCode) -> Self {
+        KeyBinding {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns the modifiers of the [`KeyBinding`].
+    ///
+    /// [`KeyBinding`]: struct.KeyBinding.html
+    pub fn modifiers(&self) -> ModifiersState {
+        self.modifiers
+    }
+
+    /// Returns the key of the [`KeyBinding`].
+    ///
+    /// [`KeyBinding`]: struct.KeyBinding.html
+    pub fn key_code(&self) -> KeyCode {
+        self.key_code
+    }
+
+    /// Returns true if the given key and modifiers trigger the
+    /// [`KeyBinding`].
+    ///
+    /// [`KeyBinding`]: struct.KeyBinding.html
+    pub fn matches(
+        &self,
+        key_code: KeyCode,
+        modifiers: ModifiersState,
+    ) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+}
+
+impl From<KeyCode> for KeyBinding {
+    fn from(key_code: KeyCode) -> KeyBinding {
+        KeyBinding::new(ModifiersState::default(), key_code)
+    }
+}
+
+/// An error produced when parsing an invalid [`KeyBinding`].
+///
+/// [`KeyBinding`]: struct.KeyBinding.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseKeyBindingError {
+    /// The binding has no key.
+    MissingKey,
+
+    /// A part of the binding is not a known modifier or key.
+    UnknownKey(String),
+}
+
+impl fmt::Display for ParseKeyBindingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseKeyBindingError::MissingKey => {
+                write!(f, "the key binding has no key")
+            }
+            ParseKeyBindingError::UnknownKey(key) => {
+                write!(f, "unknown key: {}", key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseKeyBindingError {}
+
+impl FromStr for KeyBinding {
+    type Err = ParseKeyBindingError;
+
+    fn from_str(s: &str) -> Result<KeyBinding, ParseKeyBindingError> {
+        let mut modifiers = ModifiersState::default();
+        let mut key_code = None;
+
+        for part in s.split('+').map(str::trim) {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.control = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                "logo" | "super" | "cmd" | "command" | "win" => {
+                    modifiers.logo = true
+                }
+                _ => {
+                    if key_code.is_some() {
+                        return Err(ParseKeyBindingError::UnknownKey(
+                            part.to_owned(),
+                        ));
+                    }
+
+                    key_code = Some(parse_key_code(part).ok_or_else(|| {
+                        ParseKeyBindingError::UnknownKey(part.to_owned())
+                    })?);
+                }
+            }
+        }
+
+        key_code
+            .map(|key_code| KeyBinding::new(modifiers, key_code))
+            .ok_or(ParseKeyBindingError::MissingKey)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.control {
+            write!(f, "Ctrl+")?;
+        }
+
+        if self.modifiers.shift {
+            write!(f, "Shift+")?;
+        }
+
+        if self.modifiers.alt {
+            write!(f, "Alt+")?;
+        }
+
+        if self.modifiers.logo {
+            write!(f, "Logo+")?;
+        }
+
+        match self.key_code {
+            KeyCode::Key1 => write!(f, "1"),
+            KeyCode::Key2 => write!(f, "2"),
+            KeyCode::Key3 => write!(f, "3"),
+            KeyCode::Key4 => write!(f, "4"),
+            KeyCode::Key5 => write!(f, "5"),
+            KeyCode::Key6 => write!(f, "6"),
+            KeyCode::Key7 => write!(f, "7"),
+            KeyCode::Key8 => write!(f, "8"),
+            KeyCode::Key9 => write!(f, "9"),
+            KeyCode::Key0 => write!(f, "0"),
+            key_code => write!(f, "{:?}", key_code),
+        }
+    }
+}
+
+fn parse_key_code(key: &str) -> Option<KeyCode> {
+    let key_code = match key.to_lowercase().as_str() {
+        "1" => KeyCode::Key1,
+        "2" => KeyCode::Key2,
+        "3" => KeyCode::Key3,
+        "4" => KeyCode::Key4,
+        "5" => KeyCode::Key5,
+        "6" => KeyCode::Key6,
+        "7" => KeyCode::Key7,
+        "8" => KeyCode::Key8,
+        "9" => KeyCode::Key9,
+        "0" => KeyCode::Key0,
+        "a" => KeyCode::A,
+        "b" => KeyCode::B,
+        "c" => KeyCode::C,
+        "d" => KeyCode::D,
+        "e" => KeyCode::E,
+        "f" => KeyCode::F,
+        "g" => KeyCode::G,
+        "h" => KeyCode::H,
+        "i" => KeyCode::I,
+        "j" => KeyCode::J,
+        "k" => KeyCode::K,
+        "l" => KeyCode::L,
+        "m" => KeyCode::M,
+        "n" => KeyCode::N,
+        "o" => KeyCode::O,
+        "p" => KeyCode::P,
+        "q" => KeyCode::Q,
+        "r" => KeyCode::R,
+        "s" => KeyCode::S,
+        "t" => KeyCode::T,
+        "u" => KeyCode::U,
+        "v" => KeyCode::V,
+        "w" => KeyCode::W,
+        "x" => KeyCode::X,
+        "y" => KeyCode::Y,
+        "z" => KeyCode::Z,
+        "f1" => KeyCode::F1,
+        "f2" => KeyCode::F2,
+        "f3" => KeyCode::F3,
+        "f4" => KeyCode::F4,
+        "f5" => KeyCode::F5,
+        "f6" => KeyCode::F6,
+        "f7" => KeyCode::F7,
+        "f8" => KeyCode::F8,
+        "f9" => KeyCode::F9,
+        "f10" => KeyCode::F10,
+        "f11" => KeyCode::F11,
+        "f12" => KeyCode::F12,
+        "esc" | "escape" => KeyCode::Escape,
+        "enter" | "return" => KeyCode::Enter,
+        "space" => KeyCode::Space,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "left" => KeyCode::Left,
+        "up" => KeyCode::Up,
+        "right" => KeyCode::Right,
+        "down" => KeyCode::Down,
+        "minus" | "-" => KeyCode::Minus,
+        "equals" | "=" => KeyCode::Equals,
+        "comma" | "," => KeyCode::Comma,
+        "period" | "." => KeyCode::Period,
+        "slash" | "/" => KeyCode::Slash,
+        "backslash" | "\\" => KeyCode::Backslash,
+        "semicolon" | ";" => KeyCode::Semicolon,
+        "apostrophe" | "'" => KeyCode::Apostrophe,
+        "grave" | "`" => KeyCode::Grave,
+        "lbracket" | "[" => KeyCode::LBracket,
+        "rbracket" | "]" => KeyCode::RBracket,
+        // Any other key is parsed from the name used to display it
+        _ => {
+            return KEY_CODES.iter().copied().find(|key_code| {
+                format!("{:?}", key_code).eq_ignore_ascii_case(key)
+            })
+        }
+    };
+
+    Some(key_code)
+}
+
+const KEY_CODES: [KeyCode; 161] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Key0,
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::Escape,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::F13,
+    KeyCode::F14,
+    KeyCode::F15,
+    KeyCode::F16,
+    KeyCode::F17,
+    KeyCode::F18,
+    KeyCode::F19,
+    KeyCode::F20,
+    KeyCode::F21,
+    KeyCode::F22,
+    KeyCode::F23,
+    KeyCode::F24,
+    KeyCode::Snapshot,
+    KeyCode::Scroll,
+    KeyCode::Pause,
+    KeyCode::Insert,
+    KeyCode::Home,
+    KeyCode::Delete,
+    KeyCode::End,
+    KeyCode::PageDown,
+    KeyCode::PageUp,
+    KeyCode::Left,
+    KeyCode::Up,
+    KeyCode::Right,
+    KeyCode::Down,
+    KeyCode::Backspace,
+    KeyCode::Enter,
+    KeyCode::Space,
+    KeyCode::Compose,
+    KeyCode::Caret,
+    KeyCode::Numlock,
+    KeyCode::Numpad0,
+    KeyCode::Numpad1,
+    KeyCode::Numpad2,
+    KeyCode::Numpad3,
+    KeyCode::Numpad4,
+    KeyCode::Numpad5,
+    KeyCode::Numpad6,
+    KeyCode::Numpad7,
+    KeyCode::Numpad8,
+    KeyCode::Numpad9,
+    KeyCode::AbntC1,
+    KeyCode::AbntC2,
+    KeyCode::Add,
+    KeyCode::Apostrophe,
+    KeyCode::Apps,
+    KeyCode::At,
+    KeyCode::Ax,
+    KeyCode::Backslash,
+    KeyCode::Calculator,
+    KeyCode::Capital,
+    KeyCode::Colon,
+    KeyCode::Comma,
+    KeyCode::Convert,
+    KeyCode::Decimal,
+    KeyCode::Divide,
+    KeyCode::Equals,
+    KeyCode::Grave,
+    KeyCode::Kana,
+    KeyCode::Kanji,
+    KeyCode::LAlt,
+    KeyCode::LBracket,
+    KeyCode::LControl,
+    KeyCode::LShift,
+    KeyCode::LWin,
+    KeyCode::Mail,
+    KeyCode::MediaSelect,
+    KeyCode::MediaStop,
+    KeyCode::Minus,
+    KeyCode::Multiply,
+    KeyCode::Mute,
+    KeyCode::MyComputer,
+    KeyCode::NavigateForward,
+    KeyCode::NavigateBackward,
+    KeyCode::NextTrack,
+    KeyCode::NoConvert,
+    KeyCode::NumpadComma,
+    KeyCode::NumpadEnter,
+    KeyCode::NumpadEquals,
+    KeyCode::OEM102,
+    KeyCode::Period,
+    KeyCode::PlayPause,
+    KeyCode::Power,
+    KeyCode::PrevTrack,
+    KeyCode::RAlt,
+    KeyCode::RBracket,
+    KeyCode::RControl,
+    KeyCode::RShift,
+    KeyCode::RWin,
+    KeyCode::Semicolon,
+    KeyCode::Slash,
+    KeyCode::Sleep,
+    KeyCode::Stop,
+    KeyCode::Subtract,
+    KeyCode::Sysrq,
+    KeyCode::Tab,
+    KeyCode::Underline,
+    KeyCode::Unlabeled,
+    KeyCode::VolumeDown,
+    KeyCode::VolumeUp,
+    KeyCode::Wake,
+    KeyCode::WebBack,
+    KeyCode::WebFavorites,
+    KeyCode::WebForward,
+    KeyCode::WebHome,
+    KeyCode::WebRefresh,
+    KeyCode::WebSearch,
+    KeyCode::WebStop,
+    KeyCode::Yen,
+    KeyCode::Copy,
+    KeyCode::Paste,
+    KeyCode::Cut,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binding(
+        control: bool,
+        shift: bool,
+        alt: bool,
+        logo: bool,
+        key_code: KeyCode,
+    ) -> KeyBinding {
+        KeyBinding::new(
+            ModifiersState {
+                shift,
+                control,
+                alt,
+                logo,
+            },
+            key_code,
+        )
+    }
+
+    #[test]
+    fn every_key_binding_round_trips() {
+        for &key_code in KEY_CODES.iter() {
+            for &binding in &[
+                KeyBinding::from(key_code),
+                binding(true, true, true, true, key_code),
+            ] {
+                assert_eq!(
+                    binding.to_string().parse::<KeyBinding>(),
+                    Ok(binding),
+                    "{}",
+                    binding
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn key_codes_are_listed_once() {
+        let mut key_codes = KEY_CODES.to_vec();
+        key_codes.sort();
+        key_codes.dedup();
+
+        assert_eq!(key_codes.len(), KEY_CODES.len());
+    }
+
+    #[test]
+    fn keys_are_parsed_regardless_of_case() {
+        assert_eq!(parse_key_code("s"), Some(KeyCode::S));
+        assert_eq!(parse_key_code("PageUp"), Some(KeyCode::PageUp));
+        assert_eq!(parse_key_code("numpad5"), Some(KeyCode::Numpad5));
+        assert_eq!(parse_key_code("Sleep"), Some(KeyCode::Sleep));
+    }
+
+    #[test]
+    fn key_aliases_are_parsed() {
+        assert_eq!(parse_key_code("Esc"), Some(KeyCode::Escape));
+        assert_eq!(parse_key_code("Return"), Some(KeyCode::Enter));
+        assert_eq!(parse_key_code("-"), Some(KeyCode::Minus));
+        assert_eq!(parse_key_code("["), Some(KeyCode::LBracket));
+    }
+
+    #[test]
+    fn unknown_keys_are_not_parsed() {
+        assert_eq!(parse_key_code(""), None);
+        assert_eq!(parse_key_code("Hyper"), None);
+    }
+
+    #[test]
+    fn modifier_aliases_are_parsed() {
+        assert_eq!(
+            "cmd + option + s".parse(),
+            Ok(binding(false, false, true, true, KeyCode::S))
+        );
+        assert_eq!(
+            "Control+Shift+1".parse(),
+            Ok(binding(true, true, false, false, KeyCode::Key1))
+        );
+    }
+
+    #[test]
+    fn invalid_bindings_are_rejected() {
+        assert_eq!(
+            "Ctrl+Shift".parse::<KeyBinding>(),
+            Err(ParseKeyBindingError::MissingKey)
+        );
+        assert_eq!(
+            "Ctrl+S+T".parse::<KeyBinding>(),
+            Err(ParseKeyBindingError::UnknownKey(String::from("T")))
+        );
+        assert_eq!(
+            "Ctrl+Hyper".parse::<KeyBinding>(),
+            Err(ParseKeyBindingError::UnknownKey(String::from("Hyper")))
+        );
+    }
+}
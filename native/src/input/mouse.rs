@@ -1,6 +1,8 @@
 //! Build mouse events.
 mod button;
+mod click;
 mod event;
 
 pub use button::Button;
+pub use click::{ClickCounter, CLICK_INTERVAL, CLICK_TOLERANCE};
 pub use event::{Event, ScrollDelta};
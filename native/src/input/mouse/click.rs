@@ -0,0 +1,232 @@
+use super::{Button, Event};
+use crate::input::ButtonState;
+
+use std::time::{Duration, Instant};
+
+/// The maximum amount of time between two presses of a button for them to
+/// count as consecutive clicks.
+pub const CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The maximum distance, in logical pixels, that the cursor can move between
+/// two presses of a button for them to count as consecutive clicks.
+pub const CLICK_TOLERANCE: f32 = 4.0;
+
+/// Counts consecutive clicks of the mouse buttons.
+///
+/// A runtime feeds every mouse [`Event`] to a [`ClickCounter`], which produces
+/// an [`Event::Clicked`] for every press of a button. Presses of the same
+/// button that happen quickly enough and close enough to each other increase
+/// the click count. Otherwise, the count starts again at 1.
+///
+/// [`Event`]: enum.Event.html
+/// [`ClickCounter`]: struct.ClickCounter.html
+/// [`Event::Clicked`]: enum.Event.html#variant.Clicked
+#[derive(Debug, Clone)]
+pub struct ClickCounter {
+    interval: Duration,
+    tolerance: f32,
+    cursor_position: (f32, f32),
+    last_click: Option<LastClick>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LastClick {
+    button: Button,
+    position: (f32, f32),
+    time: Instant,
+    count: u16,
+}
+
+impl ClickCounter {
+    /// Creates a new [`ClickCounter`] using the default [`CLICK_INTERVAL`] and
+    /// [`CLICK_TOLERANCE`].
+    ///
+    /// [`ClickCounter`]: struct.ClickCounter.html
+    /// [`CLICK_INTERVAL`]: constant.CLICK_INTERVAL.html
+    /// [`CLICK_TOLERANCE`]: constant.CLICK_TOLERANCE.html
+    pub fn new() -> Self {
+        ClickCounter {
+            interval: CLICK_INTERVAL,
+            tolerance: CLICK_TOLERANCE,
+            cursor_position: (0.0, 0.0),
+            last_click: None,
+        }
+    }
+
+    /// Sets the maximum amount of time between consecutive clicks of the
+    /// [`ClickCounter`].
+    ///
+    /// [`ClickCounter`]: struct.ClickCounter.html
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the maximum distance the cursor can move between consecutive
+    /// clicks of the [`ClickCounter`].
+    ///
+    /// [`ClickCounter`]: struct.ClickCounter.html
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Processes a mouse [`Event`] that happened right now.
+    ///
+    /// It returns an [`Event::Clicked`] when the [`Event`] is the press of a
+    /// button.
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`Event::Clicked`]: enum.Event.html#variant.Clicked
+    pub fn update(&mut self, event: &Event) -> Option<Event> {
+        self.update_at(event, Instant::now())
+    }
+
+    /// Processes a mouse [`Event`] that happened at the given `time`.
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn update_at(&mut self, event: &Event, time: Instant) -> Option<Event> {
+        match *event {
+            Event::CursorMoved { x, y } => {
+                self.cursor_position = (x, y);
+
+                None
+            }
+            Event::Input {
+                button,
+                state: ButtonState::Pressed,
+                ..
+            } => {
+                let position = self.cursor_position;
+
+                let count = match self.last_click {
+                    Some(last_click)
+                        if last_click.button == button
+                            && time.duration_since(last_click.time)
+                                <= self.interval
+                            && distance(last_click.position, position)
+                                <= self.tolerance =>
+                    {
+                        last_click.count.saturating_add(1)
+                    }
+                    _ => 1,
+                };
+
+                self.last_click = Some(LastClick {
+                    button,
+                    position,
+                    time,
+                    count,
+                });
+
+                Some(Event::Clicked { button, count })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for ClickCounter {
+    fn default() -> Self {
+        ClickCounter::new()
+    }
+}
+
+fn distance((ax, ay): (f32, f32), (bx, by): (f32, f32)) -> f32 {
+    ((ax - bx).powi(2) + (ay - by).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::keyboard::ModifiersState;
+
+    fn press(
+        counter: &mut ClickCounter,
+        (x, y): (f32, f32),
+        time: Instant,
+    ) -> Option<Event> {
+        let _ = counter.update_at(&Event::CursorMoved { x, y }, time);
+
+        counter.update_at(
+            &Event::Input {
+                button: Button::Left,
+                state: ButtonState::Pressed,
+                modifiers: ModifiersState::default(),
+            },
+            time,
+        )
+    }
+
+    fn clicked(count: u16) -> Option<Event> {
+        Some(Event::Clicked {
+            button: Button::Left,
+            count,
+        })
+    }
+
+    #[test]
+    fn rapid_presses_at_the_same_point_are_a_double_click() {
+        let mut counter = ClickCounter::new();
+        let start = Instant::now();
+
+        assert_eq!(press(&mut counter, (10.0, 10.0), start), clicked(1));
+        assert_eq!(
+            press(
+                &mut counter,
+                (10.0, 10.0),
+                start + Duration::from_millis(100)
+            ),
+            clicked(2)
+        );
+    }
+
+    #[test]
+    fn slow_press_resets_the_count() {
+        let mut counter = ClickCounter::new();
+        let start = Instant::now();
+
+        let _ = press(&mut counter, (10.0, 10.0), start);
+
+        assert_eq!(
+            press(
+                &mut counter,
+                (10.0, 10.0),
+                start + CLICK_INTERVAL + Duration::from_millis(1)
+            ),
+            clicked(1)
+        );
+    }
+
+    #[test]
+    fn moved_press_resets_the_count() {
+        let mut counter = ClickCounter::new();
+        let start = Instant::now();
+
+        let _ = press(&mut counter, (10.0, 10.0), start);
+
+        assert_eq!(
+            press(
+                &mut counter,
+                (10.0 + CLICK_TOLERANCE + 1.0, 10.0),
+                start + Duration::from_millis(100)
+            ),
+            clicked(1)
+        );
+    }
+
+    #[test]
+    fn interval_and_tolerance_can_be_configured() {
+        let mut counter = ClickCounter::new()
+            .interval(Duration::from_secs(2))
+            .tolerance(20.0);
+        let start = Instant::now();
+
+        let _ = press(&mut counter, (10.0, 10.0), start);
+
+        assert_eq!(
+            press(&mut counter, (25.0, 10.0), start + Duration::from_secs(1)),
+            clicked(2)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use super::Button;
-use crate::input::ButtonState;
+use crate::input::{keyboard::ModifiersState, ButtonState};
 
 /// A mouse event.
 ///
@@ -8,6 +8,7 @@ use crate::input::ButtonState;
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// The mouse cursor entered the window.
     CursorEntered,
@@ -31,6 +32,24 @@ pub enum Event {
 
         /// The button identifier
         button: Button,
+
+        /// The state of the keyboard modifiers at the time of the event
+        modifiers: ModifiersState,
+    },
+
+    /// A mouse button was pressed, possibly as part of consecutive clicks.
+    ///
+    /// It is produced by the runtime right after the
+    /// [`Input`](#variant.Input) event of the press, using a
+    /// [`ClickCounter`].
+    ///
+    /// [`ClickCounter`]: struct.ClickCounter.html
+    Clicked {
+        /// The button identifier
+        button: Button,
+
+        /// The amount of consecutive clicks, starting at 1
+        count: u16,
     },
 
     /// The mouse wheel was scrolled.
@@ -41,7 +60,11 @@ pub enum Event {
 }
 
 /// A scroll movement.
+///
+/// Mouse wheels usually produce line-based movements, while touchpads
+/// usually produce pixel-based ones.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollDelta {
     /// A line-based scroll movement
     Lines {
@@ -51,10 +74,12 @@ pub enum ScrollDelta {
         /// The number of vertical lines scrolled
         y: f32,
     },
+
     /// A pixel-based scroll movement
     Pixels {
         /// The number of horizontal pixels scrolled
         x: f32,
+
         /// The number of vertical pixels scrolled
         y: f32,
     },
@@ -2,12 +2,14 @@
 mod debugger;
 mod limits;
 mod node;
+mod overflow;
 
 pub mod flex;
 
 pub use debugger::Debugger;
 pub use limits::Limits;
 pub use node::Node;
+pub use overflow::Overflow;
 
 use crate::{Point, Rectangle, Vector};
 
@@ -34,6 +36,13 @@ impl<'a> Layout<'a> {
         }
     }
 
+    pub(crate) fn translate(self, offset: Vector) -> Self {
+        Self {
+            position: self.position + offset,
+            node: self.node,
+        }
+    }
+
     /// Gets the bounds of the [`Layout`].
     ///
     /// The returned [`Rectangle`] describes the position and size of a
@@ -53,6 +62,14 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Overflow`] of the [`Node`].
+    ///
+    /// [`Overflow`]: struct.Overflow.html
+    /// [`Node`]: struct.Node.html
+    pub fn overflow(&self) -> Overflow {
+        self.node.overflow()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     ///
     /// [`Layout`]: struct.Layout.html
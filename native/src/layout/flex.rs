@@ -17,8 +17,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::{
-    layout::{Limits, Node},
-    Align, Element, Point, Size,
+    layout::{Limits, Node, Overflow},
+    Align, Element, Padding, Point, Size,
 };
 
 /// The main axis of a flex layout.
@@ -64,7 +64,7 @@ pub fn resolve<Message, Renderer>(
     axis: Axis,
     renderer: &Renderer,
     limits: &Limits,
-    padding: f32,
+    padding: Padding,
     spacing: f32,
     align_items: Align,
     items: &[Element<'_, Message, Renderer>],
@@ -72,7 +72,10 @@ pub fn resolve<Message, Renderer>(
 where
     Renderer: crate::Renderer,
 {
-    let limits = limits.pad(padding);
+    let limits = limits.shrink(Size::new(
+        f32::from(padding.horizontal()),
+        f32::from(padding.vertical()),
+    ));
     let total_spacing = spacing * items.len().saturating_sub(1) as f32;
     let max_cross = axis.cross(limits.max());
 
@@ -143,23 +146,27 @@ where
         }
     }
 
-    let mut main = padding;
+    let (padding_main, padding_cross) =
+        axis.pack(f32::from(padding.left), f32::from(padding.top));
+    let mut main = padding_main;
 
-    for (i, node) in nodes.iter_mut().enumerate() {
+    for (i, (node, child)) in nodes.iter_mut().zip(items).enumerate() {
         if i > 0 {
             main += spacing;
         }
 
-        let (x, y) = axis.pack(main, padding);
+        let align = child.widget.align_self().unwrap_or(align_items);
+
+        let (x, y) = axis.pack(main, padding_cross);
 
         node.move_to(Point::new(x, y));
 
         match axis {
             Axis::Horizontal => {
-                node.align(Align::Start, align_items, Size::new(0.0, cross));
+                node.align(Align::Start, align, Size::new(0.0, cross));
             }
             Axis::Vertical => {
-                node.align(align_items, Align::Start, Size::new(cross, 0.0));
+                node.align(align, Align::Start, Size::new(cross, 0.0));
             }
         }
 
@@ -168,11 +175,16 @@ where
         main += axis.main(size);
     }
 
-    let (width, height) = axis.pack(main - padding, cross);
-    let size = limits.resolve(Size::new(width, height));
+    let (width, height) = axis.pack(main - padding_main, cross);
+    let content = Size::new(width, height);
+    let size = limits.resolve(content);
 
     Node::with_children(
-        Size::new(size.width + padding * 2.0, size.height + padding * 2.0),
+        Size::new(
+            size.width + f32::from(padding.horizontal()),
+            size.height + f32::from(padding.vertical()),
+        ),
         nodes,
     )
+    .with_overflow(Overflow::between(content, size))
 }
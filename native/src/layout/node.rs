@@ -1,10 +1,11 @@
-use crate::{Align, Point, Rectangle, Size};
+use crate::{layout::Overflow, Align, Point, Rectangle, Size};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    overflow: Overflow,
 }
 
 impl Node {
@@ -29,9 +30,23 @@ impl Node {
                 height: size.height,
             },
             children,
+            overflow: Overflow::NONE,
         }
     }
 
+    /// Sets the [`Overflow`] of the contents of the [`Node`] that are not
+    /// represented by its children.
+    ///
+    /// Widgets that clip their own contents, like text, can use this to
+    /// report that their contents do not fit.
+    ///
+    /// [`Overflow`]: struct.Overflow.html
+    /// [`Node`]: struct.Node.html
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
     /// Returns the [`Size`] of the [`Node`].
     ///
     /// [`Node`]: struct.Node.html
@@ -54,6 +69,28 @@ impl Node {
         &self.children
     }
 
+    /// Returns the [`Overflow`] of the [`Node`].
+    ///
+    /// A [`Node`] overflows in an axis when its children exceed its bounds in
+    /// that axis, or when it was explicitly reported with
+    /// [`with_overflow`].
+    ///
+    /// [`Overflow`]: struct.Overflow.html
+    /// [`Node`]: struct.Node.html
+    /// [`with_overflow`]: #method.with_overflow
+    pub fn overflow(&self) -> Overflow {
+        let content = self.children.iter().fold(Size::ZERO, |size, child| {
+            let bounds = child.bounds();
+
+            Size::new(
+                size.width.max(bounds.x + bounds.width),
+                size.height.max(bounds.y + bounds.height),
+            )
+        });
+
+        self.overflow.union(Overflow::between(content, self.size()))
+    }
+
     /// Aligns the [`Node`] in the given space.
     ///
     /// [`Node`]: struct.Node.html
@@ -0,0 +1,52 @@
+use crate::Size;
+
+/// The axes in which the contents of a [`Node`] do not fit its bounds.
+///
+/// [`Node`]: struct.Node.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Overflow {
+    /// Whether the contents exceed the width of the [`Node`].
+    ///
+    /// [`Node`]: struct.Node.html
+    pub horizontal: bool,
+
+    /// Whether the contents exceed the height of the [`Node`].
+    ///
+    /// [`Node`]: struct.Node.html
+    pub vertical: bool,
+}
+
+impl Overflow {
+    /// No overflow in any axis.
+    pub const NONE: Overflow = Overflow {
+        horizontal: false,
+        vertical: false,
+    };
+
+    /// Computes the [`Overflow`] of some contents with the given [`Size`]
+    /// inside of the given bounds.
+    ///
+    /// [`Overflow`]: struct.Overflow.html
+    /// [`Size`]: ../struct.Size.html
+    pub fn between(content: Size, bounds: Size) -> Overflow {
+        Overflow {
+            horizontal: content.width > bounds.width,
+            vertical: content.height > bounds.height,
+        }
+    }
+
+    /// Returns true if there is overflow in any axis.
+    pub fn any(self) -> bool {
+        self.horizontal || self.vertical
+    }
+
+    /// Combines two [`Overflow`], keeping the overflowing axes of both.
+    ///
+    /// [`Overflow`]: struct.Overflow.html
+    pub fn union(self, other: Overflow) -> Overflow {
+        Overflow {
+            horizontal: self.horizontal || other.horizontal,
+            vertical: self.vertical || other.vertical,
+        }
+    }
+}
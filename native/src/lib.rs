@@ -41,23 +41,28 @@
 #![forbid(rust_2018_idioms)]
 pub mod input;
 pub mod layout;
+pub mod overlay;
 pub mod renderer;
+pub mod shortcuts;
 pub mod subscription;
 pub mod widget;
 pub mod window;
 
 mod clipboard;
+mod content_fit;
 mod element;
 mod event;
 mod hasher;
 mod mouse_cursor;
+mod recording;
 mod runtime;
 mod size;
 mod user_interface;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Point,
-    Rectangle, Vector, VerticalAlignment,
+    Align, Background, BorderRadius, BorderStyle, Color, Font,
+    HorizontalAlignment, Length, LinearGradient, Padding, Point, Rectangle,
+    Vector, VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
 
@@ -65,13 +70,16 @@ pub use iced_futures::{executor, futures, Command};
 pub use executor::Executor;
 
 pub use clipboard::Clipboard;
+pub use content_fit::ContentFit;
 pub use element::Element;
 pub use event::Event;
 pub use hasher::Hasher;
 pub use layout::Layout;
 pub use mouse_cursor::MouseCursor;
+pub use recording::{Recorder, Recording, Replay};
 pub use renderer::Renderer;
 pub use runtime::Runtime;
+pub use shortcuts::Shortcuts;
 pub use size::Size;
 pub use subscription::Subscription;
 pub use user_interface::{Cache, UserInterface};
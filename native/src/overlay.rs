@@ -0,0 +1,71 @@
+//! Display interactive elements on top of other widgets.
+//!
+//! A [`Widget`] can produce an overlay with its `overlay` method. The
+//! [`UserInterface`] lays out the overlay at absolute coordinates, draws it
+//! after the rest of the widgets and feeds it every event before them. This
+//! is useful to build menus, popovers or any other content that should not
+//! be clipped by the bounds of its [`Widget`].
+//!
+//! [`Widget`]: ../widget/trait.Widget.html
+//! [`UserInterface`]: ../struct.UserInterface.html
+mod element;
+
+pub use element::Element;
+
+use crate::{layout, Clipboard, Event, Layout, Point, Size};
+
+/// An interactive component drawn on top of the rest of the user interface.
+///
+/// [`Overlay`]: trait.Overlay.html
+pub trait Overlay<Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Returns the [`Node`] of the [`Overlay`].
+    ///
+    /// It receives the bounds of the viewport and the position the
+    /// [`Overlay`] is anchored to, in absolute coordinates. The returned
+    /// [`Node`] should use absolute coordinates too, and it should fit in the
+    /// viewport whenever possible.
+    ///
+    /// [`Node`]: ../layout/struct.Node.html
+    /// [`Overlay`]: trait.Overlay.html
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node;
+
+    /// Draws the [`Overlay`] using the associated `Renderer`.
+    ///
+    /// [`Overlay`]: trait.Overlay.html
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output;
+
+    /// Processes a runtime [`Event`].
+    ///
+    /// An [`Overlay`] receives every [`Event`] before the rest of the user
+    /// interface, even when the cursor is outside of its bounds. This allows
+    /// it to be dismissed when clicking away from it.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Overlay`]: trait.Overlay.html
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+    }
+}
@@ -0,0 +1,146 @@
+use crate::Event;
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// A sequence of [`Event`] with timestamps, which can be replayed later.
+///
+/// If the `serde` feature is enabled, a [`Recording`] can be serialized and
+/// deserialized. This can be used to attach a reproducible sequence of events
+/// to a bug report!
+///
+/// [`Event`]: enum.Event.html
+/// [`Recording`]: struct.Recording.html
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recording {
+    events: Vec<(Duration, Event)>,
+}
+
+impl Recording {
+    /// Creates an empty [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an [`Event`] to the [`Recording`], produced at the given time
+    /// since the start of the [`Recording`].
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`Recording`]: struct.Recording.html
+    pub fn push(&mut self, timestamp: Duration, event: Event) {
+        self.events.push((timestamp, event));
+    }
+
+    /// Returns the events of the [`Recording`] and their timestamps.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn events(&self) -> &[(Duration, Event)] {
+        &self.events
+    }
+
+    /// Starts a [`Replay`] of the [`Recording`].
+    ///
+    /// [`Replay`]: struct.Replay.html
+    /// [`Recording`]: struct.Recording.html
+    pub fn replay(self) -> Replay {
+        Replay {
+            events: self.events.into(),
+            started_at: Instant::now(),
+        }
+    }
+}
+
+/// A [`Recording`] in progress.
+///
+/// [`Recording`]: struct.Recording.html
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    recording: Recording,
+    started_at: Instant,
+}
+
+impl Recorder {
+    /// Starts a new [`Recorder`].
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn new() -> Self {
+        Recorder {
+            recording: Recording::new(),
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Records an [`Event`] produced right now.
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn record(&mut self, event: Event) {
+        self.recording.push(self.started_at.elapsed(), event);
+    }
+
+    /// Stops the [`Recorder`] and returns the resulting [`Recording`].
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    /// [`Recording`]: struct.Recording.html
+    pub fn finish(self) -> Recording {
+        self.recording
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`Recording`] being replayed.
+///
+/// [`Recording`]: struct.Recording.html
+#[derive(Debug, Clone)]
+pub struct Replay {
+    events: VecDeque<(Duration, Event)>,
+    started_at: Instant,
+}
+
+impl Replay {
+    /// Returns the events of the [`Replay`] that are due at this moment.
+    ///
+    /// [`Replay`]: struct.Replay.html
+    pub fn poll(&mut self) -> Vec<Event> {
+        let elapsed = self.started_at.elapsed();
+        let mut events = Vec::new();
+
+        while let Some((timestamp, _)) = self.events.front() {
+            if *timestamp > elapsed {
+                break;
+            }
+
+            if let Some((_, event)) = self.events.pop_front() {
+                events.push(event);
+            }
+        }
+
+        events
+    }
+
+    /// Returns the instant when the next event of the [`Replay`] is due, if
+    /// any.
+    ///
+    /// [`Replay`]: struct.Replay.html
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.events
+            .front()
+            .map(|(timestamp, _)| self.started_at + *timestamp)
+    }
+
+    /// Returns whether the [`Replay`] has finished.
+    ///
+    /// [`Replay`]: struct.Replay.html
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+}
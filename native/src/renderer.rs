@@ -22,10 +22,13 @@
 
 #[cfg(debug_assertions)]
 mod null;
+mod opacity;
+
 #[cfg(debug_assertions)]
 pub use null::Null;
+pub use opacity::Opacity;
 
-use crate::{layout, Element};
+use crate::{layout, Element, Rectangle};
 
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
@@ -56,4 +59,18 @@ pub trait Renderer: Sized {
     ) -> layout::Node {
         element.layout(self, limits)
     }
+
+    /// Combines the output of a user interface with the output of an
+    /// [`Overlay`] drawn on top of it.
+    ///
+    /// The overlay output should be drawn after the base one, without being
+    /// clipped by it.
+    ///
+    /// [`Overlay`]: ../overlay/trait.Overlay.html
+    fn overlay(
+        &mut self,
+        base: Self::Output,
+        overlay: Self::Output,
+        overlay_bounds: Rectangle,
+    ) -> Self::Output;
 }
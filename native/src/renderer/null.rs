@@ -1,12 +1,14 @@
 use crate::{
-    button, checkbox, column, progress_bar, radio, row, scrollable, slider,
-    text, text_input, Color, Element, Font, HorizontalAlignment, Layout, Point,
-    Rectangle, Renderer, Size, VerticalAlignment,
+    badge, button, cached, checkbox, column, container, pick_list,
+    progress_bar, radio, row, rule, scrollable, slider, space, text,
+    text_input, tooltip, Color, Element, Font, HorizontalAlignment, Layout,
+    Point, Rectangle, Renderer, Size, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
 ///
-/// It can be useful if you are writing tests!
+/// It can be useful if you are writing tests! It measures text as if every
+/// character was half as wide as the text size.
 #[derive(Debug, Clone, Copy)]
 pub struct Null;
 
@@ -22,6 +24,9 @@ impl Null {
 impl Renderer for Null {
     type Output = ();
     type Defaults = ();
+
+    fn overlay(&mut self, _base: (), _overlay: (), _overlay_bounds: Rectangle) {
+    }
 }
 
 impl column::Renderer for Null {
@@ -46,6 +51,25 @@ impl row::Renderer for Null {
     }
 }
 
+impl container::Renderer for Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &Self::Style,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl space::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle) {}
+}
+
 impl text::Renderer for Null {
     fn default_size(&self) -> u16 {
         20
@@ -53,12 +77,38 @@ impl text::Renderer for Null {
 
     fn measure(
         &self,
-        _content: &str,
-        _size: u16,
+        content: &str,
+        size: u16,
         _font: Font,
-        _bounds: Size,
+        letter_spacing: f32,
+        bounds: Size,
     ) -> (f32, f32) {
-        (0.0, 20.0)
+        // Every character is half as wide as the text size, and lines wrap
+        // at the width of the bounds
+        let advance = f32::from(size) / 2.0 + letter_spacing;
+        let columns = ((bounds.width + letter_spacing) / advance)
+            .floor()
+            .max(1.0) as usize;
+
+        let (width, lines) =
+            content
+                .split('\n')
+                .fold((0.0f32, 0), |(width, lines), line| {
+                    let characters = line.chars().count();
+                    let line_width = if characters > 0 {
+                        characters.min(columns) as f32 * advance
+                            - letter_spacing
+                    } else {
+                        0.0
+                    };
+
+                    (
+                        width.max(line_width),
+                        lines + 1 + characters.saturating_sub(1) / columns,
+                    )
+                });
+
+        (width, lines as f32 * f32::from(size))
     }
 
     fn draw(
@@ -68,6 +118,7 @@ impl text::Renderer for Null {
         _content: &str,
         _size: u16,
         _font: Font,
+        _letter_spacing: f32,
         _color: Option<Color>,
         _horizontal_alignment: HorizontalAlignment,
         _vertical_alignment: VerticalAlignment,
@@ -83,6 +134,7 @@ impl scrollable::Renderer for Null {
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _offset: u32,
+        _scrollbar_width: u16,
     ) -> Option<scrollable::Scrollbar> {
         None
     }
@@ -135,6 +187,7 @@ impl text_input::Renderer for Null {
         _value: &text_input::Value,
         _state: &text_input::State,
         _style: &Self::Style,
+        _icons: Vec<Self::Output>,
     ) -> Self::Output {
     }
 }
@@ -149,8 +202,11 @@ impl button::Renderer for Null {
         _defaults: &Self::Defaults,
         _bounds: Rectangle,
         _cursor_position: Point,
+        _is_mouse_over: bool,
         _is_disabled: bool,
         _is_pressed: bool,
+        _is_loading: bool,
+        _is_focused: bool,
         _style: &Self::Style,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
@@ -158,6 +214,86 @@ impl button::Renderer for Null {
     }
 }
 
+impl cached::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        _id: u64,
+        _hash: u64,
+        content: &Element<'_, Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        content.draw(self, defaults, layout, cursor_position)
+    }
+}
+
+impl badge::Renderer for Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _count: u32,
+        _style: &Self::Style,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) -> Self::Output {
+    }
+}
+
+impl tooltip::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _tip: &str,
+        _text_size: u16,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl pick_list::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _selected: Option<String>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _is_open: bool,
+        _style: &(),
+    ) {
+    }
+
+    fn draw_menu(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _options: &[String],
+        _selected_option: Option<usize>,
+        _hovered_option: Option<usize>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl rule::Renderer for Null {
+    type Style = ();
+
+    fn draw(&mut self, _bounds: Rectangle, _style: &(), _is_horizontal: bool) {}
+}
+
 impl radio::Renderer for Null {
     type Style = ();
 
@@ -170,6 +306,8 @@ impl radio::Renderer for Null {
         _bounds: Rectangle,
         _is_selected: bool,
         _is_mouse_over: bool,
+        _is_focused: bool,
+        _is_enabled: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -188,6 +326,7 @@ impl checkbox::Renderer for Null {
         _bounds: Rectangle,
         _is_checked: bool,
         _is_mouse_over: bool,
+        _is_focused: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -201,6 +340,29 @@ impl slider::Renderer for Null {
         30
     }
 
+    fn handle_bounds(
+        &self,
+        bounds: Rectangle,
+        range: std::ops::RangeInclusive<f32>,
+        value: f32,
+        _is_dragging: bool,
+        _style_sheet: &Self::Style,
+    ) -> Rectangle {
+        let (start, end) = range.into_inner();
+
+        let percent = if end > start {
+            (value - start) / (end - start)
+        } else {
+            0.0
+        };
+
+        Rectangle {
+            x: bounds.x + (bounds.width - bounds.height) * percent,
+            width: bounds.height,
+            ..bounds
+        }
+    }
+
     fn draw(
         &mut self,
         _bounds: Rectangle,
@@ -0,0 +1,21 @@
+use crate::{Layout, Point, Renderer, Widget};
+
+/// A renderer able to draw widgets partially transparent.
+pub trait Opacity: Renderer {
+    /// Draws a [`Widget`] with the alpha of everything it draws multiplied by
+    /// the given opacity.
+    ///
+    /// This will be called when [`Element::opacity`] has been used. The
+    /// opacity is always in `[0.0, 1.0]`.
+    ///
+    /// [`Widget`]: ../widget/trait.Widget.html
+    /// [`Element::opacity`]: ../struct.Element.html#method.opacity
+    fn opacity<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        widget: &dyn Widget<Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        opacity: f32,
+    ) -> Self::Output;
+}
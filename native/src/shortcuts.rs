@@ -0,0 +1,305 @@
+//! Register named actions triggered by keyboard shortcuts.
+use crate::{
+    input::{
+        keyboard::{self, KeyBinding},
+        ButtonState,
+    },
+    subscription::{EventStream, Recipe},
+    Event, Hasher, Subscription,
+};
+use iced_futures::futures::{
+    future,
+    stream::{BoxStream, StreamExt},
+};
+
+use std::hash::Hash;
+
+/// A registry of named actions and their [`KeyBinding`].
+///
+/// Each action produces a message when its [`KeyBinding`] is pressed, as long
+/// as you keep returning the [`subscription`] of the [`Shortcuts`] in your
+/// application.
+///
+/// Bindings can be changed at any time with [`rebind`]. The registry can also
+/// be iterated to build a "keyboard shortcuts" help screen.
+///
+/// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+/// [`subscription`]: #method.subscription
+/// [`Shortcuts`]: struct.Shortcuts.html
+/// [`rebind`]: #method.rebind
+#[derive(Debug, Clone)]
+pub struct Shortcuts<Message> {
+    shortcuts: Vec<Shortcut<Message>>,
+}
+
+/// A named action of some [`Shortcuts`].
+///
+/// [`Shortcuts`]: struct.Shortcuts.html
+#[derive(Debug, Clone)]
+pub struct Shortcut<Message> {
+    name: String,
+    binding: KeyBinding,
+    message: Message,
+}
+
+impl<Message> Shortcut<Message> {
+    /// Returns the name of the [`Shortcut`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`KeyBinding`] of the [`Shortcut`].
+    ///
+    /// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn binding(&self) -> KeyBinding {
+        self.binding
+    }
+
+    /// Returns the message produced by the [`Shortcut`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn message(&self) -> &Message {
+        &self.message
+    }
+}
+
+/// An error produced when a [`KeyBinding`] is already used by another
+/// [`Shortcut`].
+///
+/// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+/// [`Shortcut`]: struct.Shortcut.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// The conflicting [`KeyBinding`].
+    ///
+    /// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+    pub binding: KeyBinding,
+
+    /// The name of the [`Shortcut`] already using the [`KeyBinding`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    /// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+    pub existing: String,
+}
+
+impl std::fmt::Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is already bound to \"{}\"",
+            self.binding, self.existing
+        )
+    }
+}
+
+impl std::error::Error for Conflict {}
+
+impl<Message> Shortcuts<Message> {
+    /// Creates an empty registry of [`Shortcuts`].
+    ///
+    /// [`Shortcuts`]: struct.Shortcuts.html
+    pub fn new() -> Self {
+        Shortcuts {
+            shortcuts: Vec::new(),
+        }
+    }
+
+    /// Registers a named action that produces the given message when the
+    /// [`KeyBinding`] is pressed.
+    ///
+    /// If an action with the same name exists, it is replaced.
+    ///
+    /// It fails with a [`Conflict`] if another action already uses the
+    /// [`KeyBinding`].
+    ///
+    /// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+    /// [`Conflict`]: struct.Conflict.html
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        binding: impl Into<KeyBinding>,
+        message: Message,
+    ) -> Result<(), Conflict> {
+        let name = name.into();
+        let binding = binding.into();
+
+        self.check(&name, binding)?;
+        let _ = self.unregister(&name);
+
+        self.shortcuts.push(Shortcut {
+            name,
+            binding,
+            message,
+        });
+
+        Ok(())
+    }
+
+    /// Changes the [`KeyBinding`] of the action with the given name.
+    ///
+    /// It fails with a [`Conflict`] if another action already uses the
+    /// [`KeyBinding`]. Nothing happens if there is no action with the given
+    /// name.
+    ///
+    /// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+    /// [`Conflict`]: struct.Conflict.html
+    pub fn rebind(
+        &mut self,
+        name: &str,
+        binding: impl Into<KeyBinding>,
+    ) -> Result<(), Conflict> {
+        let binding = binding.into();
+
+        self.check(name, binding)?;
+
+        if let Some(shortcut) = self
+            .shortcuts
+            .iter_mut()
+            .find(|shortcut| shortcut.name == name)
+        {
+            shortcut.binding = binding;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the action with the given name, returning its [`Shortcut`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn unregister(&mut self, name: &str) -> Option<Shortcut<Message>> {
+        let index = self
+            .shortcuts
+            .iter()
+            .position(|shortcut| shortcut.name == name)?;
+
+        Some(self.shortcuts.remove(index))
+    }
+
+    /// Returns the [`Shortcut`] with the given name, if any.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn get(&self, name: &str) -> Option<&Shortcut<Message>> {
+        self.shortcuts.iter().find(|shortcut| shortcut.name == name)
+    }
+
+    /// Returns the [`Shortcut`] triggered by the given key and modifiers, if
+    /// any.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn find(
+        &self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+    ) -> Option<&Shortcut<Message>> {
+        self.shortcuts
+            .iter()
+            .find(|shortcut| shortcut.binding.matches(key_code, modifiers))
+    }
+
+    /// Returns an iterator over the registered [`Shortcut`], in registration
+    /// order.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn iter(&self) -> impl Iterator<Item = &Shortcut<Message>> {
+        self.shortcuts.iter()
+    }
+
+    fn check(&self, name: &str, binding: KeyBinding) -> Result<(), Conflict> {
+        match self.shortcuts.iter().find(|shortcut| {
+            shortcut.binding == binding && shortcut.name != name
+        }) {
+            Some(shortcut) => Err(Conflict {
+                binding,
+                existing: shortcut.name.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<Message> Shortcuts<Message>
+where
+    Message: 'static + Clone + Send,
+{
+    /// Returns a [`Subscription`] that produces the message of a
+    /// [`Shortcut`] every time its [`KeyBinding`] is pressed.
+    ///
+    /// The [`Subscription`] is restarted whenever the registered actions,
+    /// their bindings or the variants of their messages change.
+    ///
+    /// [`Subscription`]: ../subscription/type.Subscription.html
+    /// [`Shortcut`]: struct.Shortcut.html
+    /// [`KeyBinding`]: ../input/keyboard/struct.KeyBinding.html
+    pub fn subscription(&self) -> Subscription<Message> {
+        Subscription::from_recipe(Dispatch {
+            shortcuts: self
+                .shortcuts
+                .iter()
+                .map(|shortcut| {
+                    (
+                        shortcut.name.clone(),
+                        shortcut.binding,
+                        shortcut.message.clone(),
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<Message> Default for Shortcuts<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Dispatch<Message> {
+    shortcuts: Vec<(String, KeyBinding, Message)>,
+}
+
+impl<Message> Recipe<Hasher, Event> for Dispatch<Message>
+where
+    Message: 'static + Clone + Send,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Dispatch<()>>().hash(state);
+
+        for (name, binding, message) in &self.shortcuts {
+            name.hash(state);
+            binding.hash(state);
+            std::mem::discriminant(message).hash(state);
+        }
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<'static, Self::Output> {
+        let shortcuts = self.shortcuts;
+
+        event_stream
+            .filter_map(move |event| {
+                let message = match event {
+                    Event::Keyboard(keyboard::Event::Input {
+                        state: ButtonState::Pressed,
+                        key_code,
+                        modifiers,
+                    }) => shortcuts
+                        .iter()
+                        .find(|(_, binding, _)| {
+                            binding.matches(key_code, modifiers)
+                        })
+                        .map(|(_, _, message)| message.clone()),
+                    _ => None,
+                };
+
+                future::ready(message)
+            })
+            .boxed()
+    }
+}
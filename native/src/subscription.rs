@@ -1,6 +1,9 @@
 //! Listen to external events in your application.
-use crate::{Event, Hasher};
-use iced_futures::futures::stream::BoxStream;
+use crate::{window, Event, Hasher};
+use iced_futures::futures::{
+    future,
+    stream::{BoxStream, StreamExt},
+};
 
 /// A request to listen to external events.
 ///
@@ -31,17 +34,244 @@ pub type Tracker = iced_futures::subscription::Tracker<Hasher, Event>;
 
 pub use iced_futures::subscription::Recipe;
 
-mod events;
+mod idle;
+mod keyboard;
 
-use events::Events;
+use idle::Idle;
+use keyboard::Keyboard;
 
 /// Returns a [`Subscription`] to all the runtime events.
 ///
 /// This subscription will notify your application of any [`Event`] handled by
-/// the runtime.
+/// the runtime, except for rendered frames. Use [`frames`] to listen to them.
 ///
 /// [`Subscription`]: type.Subscription.html
 /// [`Event`]: ../enum.Event.html
+/// [`frames`]: fn.frames.html
 pub fn events() -> Subscription<Event> {
-    Subscription::from_recipe(Events)
+    Subscription::with_input("events", |event_stream| {
+        event_stream
+            .filter(|event| future::ready(!is_frame(event)))
+            .boxed()
+    })
+}
+
+/// Returns a [`Subscription`] to the window events handled by the runtime.
+///
+/// It can be used to react to the window being resized, or gaining and losing
+/// focus. Rendered frames are left out; use [`frames`] to listen to them.
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`frames`]: fn.frames.html
+pub fn window_events() -> Subscription<window::Event> {
+    Subscription::with_input("window_events", |event_stream| {
+        event_stream
+            .filter_map(|event| {
+                future::ready(match event {
+                    Event::Window(window::Event::RedrawRequested(_)) => None,
+                    Event::Window(window_event) => Some(window_event),
+                    _ => None,
+                })
+            })
+            .boxed()
+    })
+}
+
+/// Returns a [`Subscription`] that produces an [`Instant`] every time a frame
+/// is rendered.
+///
+/// It can be used to drive smooth animations, synchronized with the refresh
+/// rate of the display. While the [`Subscription`] is active, every produced
+/// message causes a new frame to be rendered, which produces the next
+/// [`Instant`]. It stops producing values while the window is minimized, and
+/// it resumes once the window is rendered again.
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+pub fn frames() -> Subscription<std::time::Instant> {
+    Subscription::with_input("frames", |event_stream| {
+        event_stream
+            .filter_map(|event| {
+                future::ready(match event {
+                    Event::Window(window::Event::RedrawRequested(at)) => {
+                        Some(at)
+                    }
+                    _ => None,
+                })
+            })
+            .boxed()
+    })
+}
+
+/// Returns a [`Subscription`] to the keyboard events handled by the runtime,
+/// regardless of the widget that has focus.
+///
+/// It can be used to implement application-wide shortcuts. When
+/// `ignore_focused` is `true`, the key presses and characters received while
+/// a widget has focus, like a [`TextInput`] being edited, are left out.
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`TextInput`]: ../widget/text_input/struct.TextInput.html
+pub fn keyboard(
+    ignore_focused: bool,
+) -> Subscription<crate::input::keyboard::Event> {
+    Subscription::from_recipe(Keyboard::new(ignore_focused))
+}
+
+/// Returns a [`Subscription`] that notifies your application once no
+/// [`Event`] has been handled by the runtime for the given `threshold`.
+///
+/// The produced [`Instant`] is the moment the application became idle. Any
+/// [`Event`] resets the countdown, and the application will be notified again
+/// after it becomes idle once more.
+///
+/// Changing the `threshold` restarts the [`Subscription`], so you can
+/// configure it at runtime directly in your `subscription` logic.
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Event`]: ../enum.Event.html
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+pub fn idle(
+    threshold: std::time::Duration,
+) -> Subscription<std::time::Instant> {
+    Subscription::from_recipe(Idle::new(threshold))
+}
+
+/// Returns whether the given [`Event`] lets the [`frames`] subscription know
+/// that a frame was rendered.
+///
+/// The runtime broadcasts it like any other [`Event`], but only [`frames`]
+/// should react to it.
+///
+/// [`Event`]: ../enum.Event.html
+/// [`frames`]: fn.frames.html
+fn is_frame(event: &Event) -> bool {
+    match event {
+        Event::Window(window::Event::RedrawRequested(_)) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_futures::futures::{executor, stream};
+
+    fn output<T: 'static>(
+        subscription: Subscription<T>,
+        events: Vec<Event>,
+    ) -> Vec<T> {
+        let recipe = subscription.recipes().into_iter().next().expect("Recipe");
+
+        executor::block_on(
+            recipe.stream(stream::iter(events).boxed()).collect(),
+        )
+    }
+
+    #[test]
+    fn window_events_are_forwarded() {
+        let events = vec![
+            Event::Window(window::Event::Resized {
+                width: 800,
+                height: 600,
+            }),
+            Event::Mouse(crate::input::mouse::Event::CursorLeft),
+            Event::Window(window::Event::Unfocused),
+            Event::Window(window::Event::Focused),
+        ];
+
+        assert_eq!(
+            output(window_events(), events),
+            vec![
+                window::Event::Resized {
+                    width: 800,
+                    height: 600,
+                },
+                window::Event::Unfocused,
+                window::Event::Focused,
+            ]
+        );
+    }
+
+    #[test]
+    fn rendered_frames_only_reach_the_frames_subscription() {
+        let input = vec![
+            Event::Window(window::Event::Focused),
+            Event::Window(window::Event::RedrawRequested(
+                std::time::Instant::now(),
+            )),
+        ];
+
+        assert_eq!(output(events(), input.clone()), &input[..1]);
+        assert_eq!(output(window_events(), input.clone()).len(), 1);
+        assert_eq!(output(frames(), input).len(), 1);
+    }
+
+    fn key(state: crate::input::ButtonState) -> Event {
+        Event::Keyboard(crate::input::keyboard::Event::Input {
+            state,
+            key_code: crate::input::keyboard::KeyCode::Space,
+            modifiers: crate::input::keyboard::ModifiersState::default(),
+        })
+    }
+
+    #[test]
+    fn key_presses_and_releases_reach_the_keyboard_subscription() {
+        use crate::input::ButtonState;
+
+        let events = vec![
+            key(ButtonState::Pressed),
+            Event::Window(window::Event::Focused),
+            key(ButtonState::Released),
+        ];
+
+        let output = output(keyboard(false), events);
+
+        assert_eq!(output.len(), 2);
+        assert_eq!(Event::Keyboard(output[0]), key(ButtonState::Pressed));
+        assert_eq!(Event::Keyboard(output[1]), key(ButtonState::Released));
+    }
+
+    #[test]
+    fn keys_handled_by_a_focused_widget_can_be_ignored() {
+        use crate::input::{keyboard::Event as KeyboardEvent, ButtonState};
+
+        let events = vec![
+            key(ButtonState::Pressed),
+            Event::Keyboard(KeyboardEvent::FocusChanged(true)),
+            key(ButtonState::Pressed),
+            Event::Keyboard(KeyboardEvent::CharacterReceived(' ')),
+            Event::Keyboard(KeyboardEvent::FocusChanged(false)),
+            key(ButtonState::Released),
+        ];
+
+        assert_eq!(output(keyboard(true), events.clone()).len(), 2);
+        assert_eq!(output(keyboard(false), events).len(), 4);
+    }
+
+    #[test]
+    fn every_rendered_frame_yields_one_instant() {
+        use std::time::{Duration, Instant};
+
+        let start = Instant::now();
+        let rendered: Vec<Instant> = (0..3)
+            .map(|i| start + Duration::from_millis(16 * i))
+            .collect();
+
+        // Each simulated frame handles some input before being rendered
+        let events = rendered
+            .iter()
+            .flat_map(|at| {
+                vec![
+                    Event::Mouse(crate::input::mouse::Event::CursorMoved {
+                        x: 0.0,
+                        y: 0.0,
+                    }),
+                    Event::Window(window::Event::RedrawRequested(*at)),
+                ]
+            })
+            .collect();
+
+        assert_eq!(output(frames(), events), rendered);
+    }
 }
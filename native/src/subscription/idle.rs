@@ -0,0 +1,95 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    Event, Hasher,
+};
+use futures_timer::Delay;
+use iced_futures::futures::{
+    future::FutureExt,
+    stream::{BoxStream, Stream, StreamExt},
+    task::{Context, Poll},
+};
+
+use std::{
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+pub struct Idle {
+    threshold: Duration,
+}
+
+impl Idle {
+    pub fn new(threshold: Duration) -> Self {
+        Idle { threshold }
+    }
+}
+
+impl Recipe<Hasher, Event> for Idle {
+    type Output = Instant;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.threshold.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<'static, Self::Output> {
+        Timeout {
+            events: event_stream,
+            threshold: self.threshold,
+            delay: Delay::new(self.threshold),
+            is_notified: false,
+        }
+        .boxed()
+    }
+}
+
+struct Timeout {
+    events: EventStream,
+    threshold: Duration,
+    delay: Delay,
+    is_notified: bool,
+}
+
+impl Stream for Timeout {
+    type Item = Instant;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Instant>> {
+        // Every event restarts the countdown, but rendering a frame is not
+        // activity
+        loop {
+            match self.events.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) if super::is_frame(&event) => {}
+                Poll::Ready(Some(_)) => {
+                    let threshold = self.threshold;
+
+                    self.delay.reset(threshold);
+                    self.is_notified = false;
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => break,
+            }
+        }
+
+        // The application is only notified once until the next event
+        if self.is_notified {
+            return Poll::Pending;
+        }
+
+        match self.delay.poll_unpin(cx) {
+            Poll::Ready(()) => {
+                self.is_notified = true;
+
+                Poll::Ready(Some(Instant::now()))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{
+    input::keyboard,
+    subscription::{EventStream, Recipe},
+    Event, Hasher,
+};
+use iced_futures::futures::{
+    future,
+    stream::{BoxStream, StreamExt},
+};
+
+pub struct Keyboard {
+    ignore_focused: bool,
+}
+
+impl Keyboard {
+    pub fn new(ignore_focused: bool) -> Self {
+        Keyboard { ignore_focused }
+    }
+}
+
+impl Recipe<Hasher, Event> for Keyboard {
+    type Output = keyboard::Event;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.ignore_focused.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<'static, Self::Output> {
+        let ignore_focused = self.ignore_focused;
+        let mut is_focused = false;
+
+        event_stream
+            .filter_map(move |event| {
+                future::ready(match event {
+                    Event::Keyboard(keyboard::Event::FocusChanged(focused)) => {
+                        is_focused = focused;
+
+                        None
+                    }
+                    Event::Keyboard(_) if ignore_focused && is_focused => None,
+                    Event::Keyboard(keyboard_event) => Some(keyboard_event),
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+}
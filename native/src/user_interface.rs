@@ -2,7 +2,7 @@ use crate::{
     input::mouse, layout, Clipboard, Element, Event, Layout, Point, Size,
 };
 
-use std::hash::Hasher;
+use std::{hash::Hasher, time::Instant};
 
 /// A set of interactive graphical elements with a specific [`Layout`].
 ///
@@ -181,14 +181,40 @@ where
         let mut messages = Vec::new();
 
         for event in events {
-            if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event {
-                self.cursor_position = Point::new(x, y);
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                    self.cursor_position = Point::new(x, y);
+                }
+                Event::Mouse(mouse::Event::CursorLeft) => {
+                    // The cursor is not over any widget anymore
+                    self.cursor_position = Point::new(-1.0, -1.0);
+                }
+                _ => {}
             }
 
+            let base_cursor =
+                match self.root.widget.overlay(Layout::new(&self.layout)) {
+                    Some(mut overlay) => {
+                        let layout = overlay.layout(renderer, self.bounds);
+
+                        overlay.on_event(
+                            event.clone(),
+                            Layout::new(&layout),
+                            self.cursor_position,
+                            &mut messages,
+                            renderer,
+                            clipboard,
+                        );
+
+                        hide_cursor(self.cursor_position, &layout)
+                    }
+                    None => self.cursor_position,
+                };
+
             self.root.widget.on_event(
                 event,
                 Layout::new(&self.layout),
-                self.cursor_position,
+                base_cursor,
                 &mut messages,
                 renderer,
                 clipboard,
@@ -198,6 +224,27 @@ where
         messages
     }
 
+    /// Returns whether a widget of the [`UserInterface`] has focus.
+    ///
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn is_focused(&self) -> bool {
+        self.root
+            .widget
+            .focused_bounds(Layout::new(&self.layout))
+            .is_some()
+    }
+
+    /// Returns the moment the [`UserInterface`] needs to be drawn again on its
+    /// own, if any.
+    ///
+    /// The runtime should schedule a redraw at this moment even if no events
+    /// or messages come in.
+    ///
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn redraw_at(&self) -> Option<Instant> {
+        self.root.widget.redraw_at()
+    }
+
     /// Draws the [`UserInterface`] with the provided [`Renderer`].
     ///
     /// It returns the current state of the [`MouseCursor`]. You should update
@@ -261,13 +308,44 @@ where
     ///     // Flush rendering operations...
     /// }
     /// ```
-    pub fn draw(&self, renderer: &mut Renderer) -> Renderer::Output {
-        self.root.widget.draw(
-            renderer,
-            &Renderer::Defaults::default(),
-            Layout::new(&self.layout),
-            self.cursor_position,
-        )
+    pub fn draw(&mut self, renderer: &mut Renderer) -> Renderer::Output {
+        let defaults = Renderer::Defaults::default();
+        let cursor_position = self.cursor_position;
+
+        let overlay = match self.root.widget.overlay(Layout::new(&self.layout))
+        {
+            Some(overlay) => {
+                let layout = overlay.layout(renderer, self.bounds);
+                let output = overlay.draw(
+                    renderer,
+                    &defaults,
+                    Layout::new(&layout),
+                    cursor_position,
+                );
+
+                Some((output, layout))
+            }
+            None => None,
+        };
+
+        match overlay {
+            Some((output, layout)) => {
+                let base = self.root.widget.draw(
+                    renderer,
+                    &defaults,
+                    Layout::new(&self.layout),
+                    hide_cursor(cursor_position, &layout),
+                );
+
+                renderer.overlay(base, output, layout.bounds())
+            }
+            None => self.root.widget.draw(
+                renderer,
+                &defaults,
+                Layout::new(&self.layout),
+                cursor_position,
+            ),
+        }
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
@@ -285,6 +363,15 @@ where
     }
 }
 
+/// Hides the cursor from the widgets below an overlay while it is over it.
+fn hide_cursor(cursor_position: Point, overlay: &layout::Node) -> Point {
+    if overlay.bounds().contains(cursor_position) {
+        Point::new(-1.0, -1.0)
+    } else {
+        cursor_position
+    }
+}
+
 /// Reusable data of a specific [`UserInterface`].
 ///
 /// [`UserInterface`]: struct.UserInterface.html
@@ -327,3 +414,169 @@ impl PartialEq for Cache {
 }
 
 impl Eq for Cache {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        input::{keyboard, ButtonState},
+        overlay,
+        renderer::Null,
+        Hasher, Length, Widget,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        AnchorPressed,
+        Dismissed,
+    }
+
+    /// A widget that opens a menu right below itself.
+    struct Anchor;
+
+    /// A menu that is dismissed when clicking away from it.
+    struct Menu;
+
+    fn is_press(event: &Event) -> bool {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    impl Widget<Message, Null> for Anchor {
+        fn width(&self) -> Length {
+            Length::Units(100)
+        }
+
+        fn height(&self) -> Length {
+            Length::Units(20)
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Null,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::new(100.0, 20.0))
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher) {}
+
+        fn on_event(
+            &mut self,
+            event: Event,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            messages: &mut Vec<Message>,
+            _renderer: &Null,
+            _clipboard: Option<&dyn Clipboard>,
+        ) {
+            if is_press(&event) && layout.bounds().contains(cursor_position) {
+                messages.push(Message::AnchorPressed);
+            }
+        }
+
+        fn overlay(
+            &mut self,
+            layout: Layout<'_>,
+        ) -> Option<overlay::Element<'_, Message, Null>> {
+            let bounds = layout.bounds();
+
+            Some(overlay::Element::new(
+                Point::new(bounds.x, bounds.y + bounds.height),
+                Box::new(Menu),
+            ))
+        }
+    }
+
+    impl overlay::Overlay<Message, Null> for Menu {
+        fn layout(
+            &self,
+            _renderer: &Null,
+            _bounds: Size,
+            position: Point,
+        ) -> layout::Node {
+            let mut node = layout::Node::new(Size::new(100.0, 50.0));
+            node.move_to(position);
+
+            node
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn on_event(
+            &mut self,
+            event: Event,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            messages: &mut Vec<Message>,
+            _renderer: &Null,
+            _clipboard: Option<&dyn Clipboard>,
+        ) {
+            if is_press(&event) && !layout.bounds().contains(cursor_position) {
+                messages.push(Message::Dismissed);
+            }
+        }
+    }
+
+    fn click(x: f32, y: f32) -> Vec<Message> {
+        let mut renderer = Null::new();
+        let mut user_interface = UserInterface::build(
+            Element::new(Anchor),
+            Size::new(400.0, 300.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        user_interface.update(
+            vec![
+                Event::Mouse(mouse::Event::CursorMoved { x, y }),
+                Event::Mouse(mouse::Event::Input {
+                    state: ButtonState::Pressed,
+                    button: mouse::Button::Left,
+                    modifiers: keyboard::ModifiersState::default(),
+                }),
+            ],
+            None,
+            &renderer,
+        )
+    }
+
+    #[test]
+    fn clicking_away_dismisses_the_overlay() {
+        assert_eq!(click(300.0, 200.0), vec![Message::Dismissed]);
+    }
+
+    #[test]
+    fn clicking_inside_the_overlay_keeps_it_open() {
+        assert_eq!(click(50.0, 40.0), vec![]);
+    }
+
+    #[test]
+    fn overlay_receives_events_before_the_widgets() {
+        assert_eq!(
+            click(50.0, 10.0),
+            vec![Message::Dismissed, Message::AnchorPressed]
+        );
+    }
+}
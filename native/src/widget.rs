@@ -20,24 +20,37 @@
 //!
 //! [`Widget`]: trait.Widget.html
 //! [renderer]: ../renderer/index.html
+pub mod animated;
+pub mod badge;
 pub mod button;
+pub mod cached;
 pub mod checkbox;
 pub mod column;
 pub mod container;
 pub mod image;
+pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod row;
+pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
 pub mod svg;
 pub mod text;
 pub mod text_input;
+pub mod title_bar;
+pub mod tooltip;
 
+#[doc(no_inline)]
+pub use animated::Animated;
+#[doc(no_inline)]
+pub use badge::Badge;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use cached::Cached;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
@@ -46,12 +59,16 @@ pub use container::Container;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use pick_list::PickList;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
+pub use rule::Rule;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
@@ -63,8 +80,17 @@ pub use svg::Svg;
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use title_bar::TitleBar;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+
+use crate::{
+    layout, overlay, Align, Clipboard, Event, Hasher, Layout, Length, Point,
+    Rectangle,
+};
 
-use crate::{layout, Clipboard, Event, Hasher, Layout, Length, Point};
+use std::time::Instant;
 
 /// A component that displays information and allows interaction.
 ///
@@ -152,4 +178,84 @@ where
         _clipboard: Option<&dyn Clipboard>,
     ) {
     }
+
+    /// Returns the bounds of the focused [`Widget`] in this subtree, if any.
+    ///
+    /// Widgets that can be focused should return their bounds while focused,
+    /// and widgets with children should forward the call to them. This is
+    /// used, for instance, by a [`Scrollable`] to keep the focused widget
+    /// visible.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Scrollable`]: scrollable/struct.Scrollable.html
+    fn focused_bounds(&self, _layout: Layout<'_>) -> Option<Rectangle> {
+        None
+    }
+
+    /// Returns whether the [`Widget`], or one of its children under the
+    /// cursor, can still scroll vertically by the given wheel delta.
+    ///
+    /// A [`Scrollable`] uses this to forward wheel events to the scrollables
+    /// nested in it until they reach one of their ends. Widgets with children
+    /// should forward the call to them.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Scrollable`]: scrollable/struct.Scrollable.html
+    fn can_scroll(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _delta_y: f32,
+    ) -> bool {
+        false
+    }
+
+    /// Returns the cross-axis alignment the [`Widget`] wants to use inside a
+    /// [`Row`] or a [`Column`], overriding the alignment of its parent.
+    ///
+    /// Widgets that simply wrap another one should forward the call to it.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Row`]: struct.Row.html
+    /// [`Column`]: struct.Column.html
+    fn align_self(&self) -> Option<Align> {
+        None
+    }
+
+    /// Returns the [`Overlay`] of the [`Widget`], if there is any.
+    ///
+    /// An [`Overlay`] is drawn on top of the rest of the user interface and
+    /// receives events before it. Widgets with children should forward the
+    /// call to them.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Overlay`]: ../overlay/trait.Overlay.html
+    /// [`Widget`]: trait.Widget.html
+    fn overlay(
+        &mut self,
+        _layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        None
+    }
+
+    /// Returns the moment the [`Widget`] needs to be drawn again, if any.
+    ///
+    /// The runtime only redraws when there are events or messages. A
+    /// [`Widget`] that changes over time on its own, like a blinking cursor,
+    /// can use this method to wake it up. Widgets with children should
+    /// forward the call to them and return the earliest moment.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    fn redraw_at(&self) -> Option<Instant> {
+        None
+    }
 }
@@ -0,0 +1,264 @@
+//! Animate the position changes of some content.
+//!
+//! An [`Animated`] widget has some local [`State`].
+//!
+//! [`Animated`]: struct.Animated.html
+//! [`State`]: struct.State.html
+use crate::{
+    layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Vector, Widget,
+};
+
+use std::{
+    cell::Cell,
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// A widget that smoothly moves its contents when their position changes
+/// between frames, instead of snapping them to the new position.
+///
+/// The [`State`] of an [`Animated`] widget identifies its contents between
+/// frames. As long as you keep using the same [`State`] for the same
+/// contents, the widget will detect layout changes (like items being
+/// reordered in a list) and interpolate the drawn position over a short
+/// duration.
+///
+/// Changes in size are not animated.
+///
+/// The runtime only redraws when there are events or messages. Therefore,
+/// you should keep a time subscription active while
+/// [`State::is_animating`] returns `true`.
+///
+/// [`Animated`]: struct.Animated.html
+/// [`State`]: struct.State.html
+/// [`State::is_animating`]: struct.State.html#method.is_animating
+#[allow(missing_debug_implementations)]
+pub struct Animated<'a, Message, Renderer> {
+    state: &'a mut State,
+    duration: Duration,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Animated<'a, Message, Renderer> {
+    /// Creates a new [`Animated`] widget with the given [`State`] and
+    /// contents.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`State`]: struct.State.html
+    pub fn new<T>(state: &'a mut State, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Animated {
+            state,
+            duration: Duration::from_millis(200),
+            content: content.into(),
+        }
+    }
+
+    /// Sets the duration of the animations of the [`Animated`] widget.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Animated<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.content
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.widget.overlay(layout)
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.content.widget.redraw_at()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let target = Point::new(bounds.x, bounds.y);
+        let now = Instant::now();
+
+        let animation = match self.state.animation.get() {
+            Some(animation) if animation.target == target => animation,
+            Some(animation) => {
+                let current = animation.position(now);
+
+                Animation {
+                    from: Vector::new(
+                        current.x - target.x,
+                        current.y - target.y,
+                    ),
+                    target,
+                    started_at: now,
+                    duration: self.duration,
+                }
+            }
+            None => Animation {
+                from: Vector::new(0.0, 0.0),
+                target,
+                started_at: now,
+                duration: Duration::from_secs(0),
+            },
+        };
+
+        self.state.animation.set(Some(animation));
+
+        let current = animation.position(now);
+
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.translate(Vector::new(
+                current.x - target.x,
+                current.y - target.y,
+            )),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<State>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+/// The local state of an [`Animated`] widget.
+///
+/// It remembers the last position of the contents and the current animation,
+/// if any.
+///
+/// [`Animated`]: struct.Animated.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    animation: Cell<Option<Animation>>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the contents of the [`Animated`] widget are currently
+    /// moving.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn is_animating(&self) -> bool {
+        match self.animation.get() {
+            Some(animation) => animation.is_running(Instant::now()),
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    from: Vector,
+    target: Point,
+    started_at: Instant,
+    duration: Duration,
+}
+
+impl Animation {
+    fn is_running(&self, now: Instant) -> bool {
+        now.duration_since(self.started_at) < self.duration
+    }
+
+    fn position(&self, now: Instant) -> Point {
+        let progress = if self.is_running(now) {
+            now.duration_since(self.started_at).as_secs_f32()
+                / self.duration.as_secs_f32()
+        } else {
+            1.0
+        };
+
+        // Ease out cubic
+        let remaining = (1.0 - progress).powi(3);
+
+        Point::new(
+            self.target.x + self.from.x * remaining,
+            self.target.y + self.from.y * remaining,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Animated<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'static,
+{
+    fn from(
+        animated: Animated<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(animated)
+    }
+}
@@ -0,0 +1,191 @@
+//! Display a small count on top of some content.
+use crate::{
+    layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Widget,
+};
+
+use std::{hash::Hash, time::Instant};
+
+/// A widget that draws a small badge with a count at the top-right corner of
+/// its content.
+///
+/// The badge is hidden when the count is zero.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Badge<'a, Message> = iced_native::Badge<'a, Message, Null>;
+/// let badge = Badge::<()>::new(3, Text::new("Inbox"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Badge<'a, Message, Renderer: self::Renderer> {
+    count: u32,
+    content: Element<'a, Message, Renderer>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Badge<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a [`Badge`] with the given count on top of some content.
+    ///
+    /// [`Badge`]: struct.Badge.html
+    pub fn new<T>(count: u32, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Badge {
+            count,
+            content: content.into(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the style of the [`Badge`].
+    ///
+    /// [`Badge`]: struct.Badge.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Badge<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.content
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.widget.overlay(layout)
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.content.widget.redraw_at()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            self.count,
+            &self.style,
+            &self.content,
+            layout,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Badge<'static, (), Renderer>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`Badge`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Badge`] in your user interface.
+///
+/// [`Badge`]: struct.Badge.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Badge`].
+    ///
+    /// It receives:
+    ///   * the bounds of the content of the [`Badge`]
+    ///   * the cursor position
+    ///   * the count of the [`Badge`], which hides it when zero
+    ///   * the style of the [`Badge`]
+    ///   * the content of the [`Badge`] and its [`Layout`]
+    ///
+    /// [`Badge`]: struct.Badge.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        count: u32,
+        style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Badge<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        badge: Badge<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(badge)
+    }
+}
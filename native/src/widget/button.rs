@@ -5,11 +5,11 @@
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
 use crate::{
-    input::{mouse, ButtonState},
-    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    input::{keyboard, mouse, ButtonState},
+    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
     Rectangle, Widget,
 };
-use std::hash::Hash;
+use std::{hash::Hash, time::Instant};
 
 /// A generic widget that produces a message when pressed.
 ///
@@ -31,12 +31,18 @@ use std::hash::Hash;
 pub struct Button<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     content: Element<'a, Message, Renderer>,
-    on_press: Option<Box<dyn Fn() -> Message>>,
+    on_press: Option<OnPress<Message>>,
     width: Length,
     height: Length,
     min_width: u32,
+    max_width: Option<u32>,
     min_height: u32,
     padding: u16,
+    horizontal_alignment: Align,
+    vertical_alignment: Align,
+    min_touch_target: u16,
+    is_loading: bool,
+    is_focused: bool,
     style: Renderer::Style,
 }
 
@@ -60,8 +66,14 @@ where
             width: Length::Shrink,
             height: Length::Shrink,
             min_width: 0,
+            max_width: None,
             min_height: 0,
             padding: Renderer::DEFAULT_PADDING,
+            horizontal_alignment: Align::Center,
+            vertical_alignment: Align::Center,
+            min_touch_target: 0,
+            is_loading: false,
+            is_focused: false,
             style: Renderer::Style::default(),
         }
     }
@@ -90,6 +102,17 @@ where
         self
     }
 
+    /// Sets the maximum width of the [`Button`].
+    ///
+    /// The maximum width takes precedence over the minimum width when they
+    /// conflict.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
     /// Sets the minimum height of the [`Button`].
     ///
     /// [`Button`]: struct.Button.html
@@ -106,13 +129,87 @@ where
         self
     }
 
+    /// Sets the content alignment for the horizontal axis of the [`Button`].
+    ///
+    /// The content is aligned within the padding of the [`Button`], and it is
+    /// centered by default.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the content alignment for the vertical axis of the [`Button`].
+    ///
+    /// The content is aligned within the padding of the [`Button`], and it is
+    /// centered by default.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn align_y(mut self, alignment: Align) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the minimum size of the area of the [`Button`] that reacts to the
+    /// mouse.
+    ///
+    /// The area is expanded around the center of the [`Button`] when it is
+    /// smaller than the given size, while its drawn size stays the same. This
+    /// is useful to meet accessibility guidelines for touch targets.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn min_touch_target(mut self, size: u16) -> Self {
+        self.min_touch_target = size;
+        self
+    }
+
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
     /// [`Button`]: struct.Button.html
     pub fn on_press<F>(mut self, on_press: F) -> Self
         where F: 'static + Fn() -> Message
     {
-        self.on_press = Some(Box::new(on_press));
+        self.on_press = Some(OnPress::Closure(Box::new(on_press)));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed.
+    ///
+    /// Unlike [`on_press`], the message is stored directly and cloned on
+    /// every press, which avoids allocating a closure for constant messages.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`on_press`]: #method.on_press
+    pub fn on_press_message(mut self, message: Message) -> Self
+    where
+        Message: Clone,
+    {
+        self.on_press = Some(OnPress::Message(message, Message::clone));
+        self
+    }
+
+    /// Sets whether the [`Button`] is busy performing the action of a previous
+    /// press.
+    ///
+    /// A loading [`Button`] ignores any press and is drawn with a busy
+    /// indicator instead of its content. This is useful to prevent submitting
+    /// the same action twice while an asynchronous `Command` is running.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Sets whether the [`Button`] has keyboard focus.
+    ///
+    /// A focused [`Button`] is pressed when Space or Enter is pressed, unless
+    /// it is disabled or loading.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
         self
     }
 
@@ -125,6 +222,20 @@ where
     }
 }
 
+enum OnPress<Message> {
+    Message(Message, fn(&Message) -> Message),
+    Closure(Box<dyn Fn() -> Message>),
+}
+
+impl<Message> OnPress<Message> {
+    fn produce(&self) -> Message {
+        match self {
+            OnPress::Message(message, clone) => clone(message),
+            OnPress::Closure(on_press) => on_press(),
+        }
+    }
+}
+
 /// The local state of a [`Button`].
 ///
 /// [`Button`]: struct.Button.html
@@ -161,6 +272,11 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let padding = f32::from(self.padding);
+        let limits = match self.max_width {
+            Some(max_width) => limits.max_width(max_width),
+            None => *limits,
+        };
+
         let limits = limits
             .min_width(self.min_width)
             .min_height(self.min_height)
@@ -169,9 +285,16 @@ where
             .pad(padding);
 
         let mut content = self.content.layout(renderer, &limits);
+        let content_space = limits.resolve(content.size());
+
         content.move_to(Point::new(padding, padding));
+        content.align(
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            content_space,
+        );
 
-        let size = limits.resolve(content.size()).pad(padding);
+        let size = content_space.pad(padding);
 
         layout::Node::with_children(size, vec![content])
     }
@@ -185,13 +308,20 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
+        if self.is_loading {
+            self.state.is_pressed = false;
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state,
+                ..
             }) => {
                 if let Some(on_press) = self.on_press.as_ref() {
-                    let bounds = layout.bounds();
+                    let min_size = f32::from(self.min_touch_target);
+                    let bounds = layout.bounds().expand_to(min_size, min_size);
 
                     match state {
                         ButtonState::Pressed => {
@@ -205,16 +335,42 @@ where
                             self.state.is_pressed = false;
 
                             if is_clicked {
-                                messages.push(on_press());
+                                messages.push(on_press.produce());
                             }
                         }
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if self.is_focused => match key_code {
+                keyboard::KeyCode::Space | keyboard::KeyCode::Enter => {
+                    if let Some(on_press) = self.on_press.as_ref() {
+                        messages.push(on_press.produce());
+                    }
+                }
+                _ => {}
+            },
             _ => {}
         }
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.is_focused {
+            return Some(layout.bounds());
+        }
+
+        self.content
+            .widget
+            .focused_bounds(layout.children().next().unwrap())
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.content.widget.redraw_at()
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -222,12 +378,21 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let min_size = f32::from(self.min_touch_target);
+        let is_mouse_over = bounds
+            .expand_to(min_size, min_size)
+            .contains(cursor_position);
+
         renderer.draw(
             defaults,
-            layout.bounds(),
+            bounds,
             cursor_position,
+            is_mouse_over,
             self.on_press.is_none(),
             self.state.is_pressed,
+            self.is_loading,
+            self.is_focused,
             &self.style,
             &self.content,
             layout.children().next().unwrap(),
@@ -236,6 +401,9 @@ where
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.width.hash(state);
+        self.max_width.hash(state);
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
         self.content.hash_layout(state);
     }
 }
@@ -258,14 +426,33 @@ pub trait Renderer: crate::Renderer + Sized {
 
     /// Draws a [`Button`].
     ///
+    /// It receives:
+    ///   * the bounds of the [`Button`]
+    ///   * the current cursor position
+    ///   * whether the mouse is over the area of the [`Button`] that reacts
+    ///     to presses
+    ///   * whether the [`Button`] is disabled or pressed
+    ///   * whether the [`Button`] is loading, in which case a busy indicator
+    ///     should be drawn instead of its content
+    ///   * whether the [`Button`] has keyboard focus or not
+    ///   * the style of the [`Button`]
+    ///   * the content of the [`Button`] and its layout
+    ///
+    /// Renderers should pick the pressed style when the [`Button`] is pressed
+    /// and the mouse is over it, and the hovered style when the mouse is over
+    /// it otherwise.
+    ///
     /// [`Button`]: struct.Button.html
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
         bounds: Rectangle,
         cursor_position: Point,
+        is_mouse_over: bool,
         is_disabled: bool,
         is_pressed: bool,
+        is_loading: bool,
+        is_focused: bool,
         style: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -284,3 +471,278 @@ where
         Element::new(button)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Size, Text};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Pressed,
+    }
+
+    fn layout(
+        button: &Button<'_, Message, Null>,
+        max_width: f32,
+    ) -> layout::Node {
+        Widget::<Message, Null>::layout(
+            button,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(max_width, 200.0)),
+        )
+    }
+
+    fn on_events(
+        button: &mut Button<'_, Message, Null>,
+        events: &[(Event, Point)],
+    ) -> Vec<Message> {
+        let node = layout(button, 200.0);
+        let mut messages = Vec::new();
+
+        for (event, cursor_position) in events {
+            button.on_event(
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                &mut messages,
+                &Null::new(),
+                None,
+            );
+        }
+
+        messages
+    }
+
+    fn mouse_input(state: ButtonState, x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::Input {
+                state,
+                button: mouse::Button::Left,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(x, y),
+        )
+    }
+
+    fn key_press(key_code: keyboard::KeyCode) -> (Event, Point) {
+        (
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(-1.0, -1.0),
+        )
+    }
+
+    fn click(x: f32, y: f32) -> [(Event, Point); 2] {
+        [
+            mouse_input(ButtonState::Pressed, x, y),
+            mouse_input(ButtonState::Released, x, y),
+        ]
+    }
+
+    #[test]
+    fn on_press_message_is_cloned_on_click() {
+        let mut state = State::new();
+        let mut button = Button::new(&mut state, Text::new("Press"))
+            .on_press_message(Message::Pressed);
+
+        assert!(match button.on_press {
+            Some(OnPress::Message(..)) => true,
+            _ => false,
+        });
+
+        assert_eq!(
+            on_events(&mut button, &click(10.0, 10.0)),
+            vec![Message::Pressed]
+        );
+        assert_eq!(
+            on_events(&mut button, &click(10.0, 10.0)),
+            vec![Message::Pressed]
+        );
+    }
+
+    #[test]
+    fn button_is_pressed_while_the_mouse_button_is_held() {
+        let mut state = State::new();
+        let mut button = Button::new(&mut state, Text::new("Press"))
+            .on_press_message(Message::Pressed);
+
+        let _ = on_events(
+            &mut button,
+            &[mouse_input(ButtonState::Pressed, 10.0, 10.0)],
+        );
+        assert!(button.state.is_pressed);
+
+        let _ = on_events(
+            &mut button,
+            &[mouse_input(ButtonState::Released, 10.0, 10.0)],
+        );
+        assert!(!button.state.is_pressed);
+    }
+
+    #[test]
+    fn loading_button_swallows_presses() {
+        let mut state = State::new();
+        let mut button = Button::new(&mut state, Text::new("Press"))
+            .on_press_message(Message::Pressed)
+            .loading(true);
+
+        assert_eq!(on_events(&mut button, &click(10.0, 10.0)), vec![]);
+        assert!(!button.state.is_pressed);
+    }
+
+    #[test]
+    fn focused_button_is_pressed_with_the_keyboard() {
+        let mut state = State::new();
+        let mut button = Button::new(&mut state, Text::new("Press"))
+            .on_press_message(Message::Pressed)
+            .focused(true);
+
+        assert_eq!(
+            on_events(
+                &mut button,
+                &[
+                    key_press(keyboard::KeyCode::Space),
+                    key_press(keyboard::KeyCode::Enter),
+                    key_press(keyboard::KeyCode::A),
+                ]
+            ),
+            vec![Message::Pressed, Message::Pressed]
+        );
+    }
+
+    #[test]
+    fn unfocused_button_ignores_the_keyboard() {
+        let mut state = State::new();
+        let mut button = Button::new(&mut state, Text::new("Press"))
+            .on_press_message(Message::Pressed);
+
+        assert_eq!(
+            on_events(&mut button, &[key_press(keyboard::KeyCode::Space)]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn disabled_or_loading_button_ignores_the_keyboard() {
+        let mut state = State::new();
+        let mut disabled: Button<'_, Message, Null> =
+            Button::new(&mut state, Text::new("Press")).focused(true);
+
+        assert_eq!(
+            on_events(&mut disabled, &[key_press(keyboard::KeyCode::Enter)]),
+            vec![]
+        );
+
+        let mut state = State::new();
+        let mut loading = Button::new(&mut state, Text::new("Press"))
+            .on_press_message(Message::Pressed)
+            .focused(true)
+            .loading(true);
+
+        assert_eq!(
+            on_events(&mut loading, &[key_press(keyboard::KeyCode::Enter)]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn button_in_a_fill_row_respects_its_max_width() {
+        use crate::Row;
+
+        let mut state = State::new();
+        let button = Button::new(&mut state, Text::new("Press"))
+            .width(Length::Fill)
+            .max_width(60);
+
+        let row: Row<'_, Message, Null> =
+            Row::new().width(Length::Fill).push(button);
+
+        let node = Widget::<Message, Null>::layout(
+            &row,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        assert_eq!(node.size().width, 200.0);
+        assert_eq!(node.children()[0].size().width, 60.0);
+    }
+
+    #[test]
+    fn max_width_wins_over_min_width() {
+        let mut state = State::new();
+        let button = Button::new(&mut state, Text::new("Press"))
+            .min_width(80)
+            .max_width(60);
+
+        assert_eq!(layout(&button, 200.0).size().width, 60.0);
+    }
+
+    #[test]
+    fn left_aligned_content_sits_against_the_padding() {
+        let mut state = State::new();
+        let button = Button::new(&mut state, Text::new("Press"))
+            .min_width(100)
+            .padding(10)
+            .align_x(Align::Start);
+
+        let node = layout(&button, 200.0);
+
+        assert_eq!(node.children()[0].bounds().x, 10.0);
+    }
+
+    #[test]
+    fn centered_content_is_symmetric() {
+        let mut state = State::new();
+        let button = Button::new(&mut state, Text::new("Press"))
+            .min_width(100)
+            .min_height(60)
+            .padding(10);
+
+        let node = layout(&button, 200.0);
+        let bounds = node.bounds();
+        let content = node.children()[0].bounds();
+
+        assert_eq!(
+            content.x - bounds.x,
+            bounds.x + bounds.width - (content.x + content.width)
+        );
+        assert_eq!(
+            content.y - bounds.y,
+            bounds.y + bounds.height - (content.y + content.height)
+        );
+        assert!(content.x > 10.0);
+    }
+
+    #[test]
+    fn focused_button_reports_its_bounds() {
+        let mut state = State::new();
+        let focused: Button<'_, Message, Null> =
+            Button::new(&mut state, Text::new("Press")).focused(true);
+        let node = layout(&focused, 200.0);
+
+        assert_eq!(
+            Widget::<Message, Null>::focused_bounds(
+                &focused,
+                Layout::new(&node)
+            ),
+            Some(node.bounds())
+        );
+
+        let mut state = State::new();
+        let unfocused: Button<'_, Message, Null> =
+            Button::new(&mut state, Text::new("Press"));
+        let node = layout(&unfocused, 200.0);
+
+        assert_eq!(
+            Widget::<Message, Null>::focused_bounds(
+                &unfocused,
+                Layout::new(&node)
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Reuse the drawn output of expensive, mostly static content.
+//!
+//! A [`Cached`] has some local [`State`].
+//!
+//! [`Cached`]: struct.Cached.html
+//! [`State`]: struct.State.html
+use crate::{
+    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Widget,
+};
+
+use std::{
+    hash::{Hash, Hasher as _},
+    sync::atomic::{self, AtomicU64},
+    time::Instant,
+};
+
+/// A widget that draws its contents once and reuses the drawn output until
+/// their layout or bounds change.
+///
+/// The renderer is free to keep the drawn output around in whatever form is
+/// cheapest to reuse, like an offscreen texture.
+///
+/// The drawn output is not updated when properties that do not affect layout
+/// change, like colors or the cursor position. Therefore, a [`Cached`] widget
+/// is only useful for contents that are mostly static. You can use
+/// [`State::clear`] to force a redraw.
+///
+/// [`Cached`]: struct.Cached.html
+/// [`State::clear`]: struct.State.html#method.clear
+#[allow(missing_debug_implementations)]
+pub struct Cached<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Cached<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Cached`] widget with the given [`State`] and contents.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    /// [`State`]: struct.State.html
+    pub fn new<T>(state: &'a mut State, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Cached {
+            state,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Cached<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.content
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn align_self(&self) -> Option<Align> {
+        self.content.widget.align_self()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.widget.overlay(layout)
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.content.widget.redraw_at()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let hash = {
+            let mut hasher = Hasher::default();
+
+            self.content.hash_layout(&mut hasher);
+            self.state.generation.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            self.state.id,
+            hash,
+            &self.content,
+            layout,
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<State>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+/// The local state of a [`Cached`] widget.
+///
+/// It identifies the drawn output kept by the renderer.
+///
+/// [`Cached`]: struct.Cached.html
+#[derive(Debug)]
+pub struct State {
+    id: u64,
+    generation: u64,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        State {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            generation: 0,
+        }
+    }
+
+    /// Invalidates the drawn output of the [`Cached`] widget, forcing its
+    /// contents to be drawn again.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    pub fn clear(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::new()
+    }
+}
+
+/// The renderer of a [`Cached`] widget.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Cached`] widget in your user interface.
+///
+/// [`Cached`]: struct.Cached.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws the contents of a [`Cached`] widget.
+    ///
+    /// The renderer may reuse the output it produced for the same `id` as long
+    /// as the `hash` and the bounds of the `layout` have not changed.
+    ///
+    /// [`Cached`]: struct.Cached.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        id: u64,
+        hash: u64,
+        content: &Element<'_, Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Cached<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        cached: Cached<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(cached)
+    }
+}
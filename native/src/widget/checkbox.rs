@@ -2,7 +2,7 @@
 use std::hash::Hash;
 
 use crate::{
-    input::{mouse, ButtonState},
+    input::{keyboard, mouse, ButtonState},
     layout, row, text, Align, Clipboard, Element, Event, Font, Hasher,
     HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
     VerticalAlignment, Widget,
@@ -30,6 +30,9 @@ pub struct Checkbox<Message, Renderer: self::Renderer> {
     is_checked: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
+    is_label_clickable: bool,
+    min_touch_target: u16,
+    is_focused: bool,
     width: Length,
     style: Renderer::Style,
 }
@@ -53,6 +56,9 @@ impl<Message, Renderer: self::Renderer> Checkbox<Message, Renderer> {
             is_checked,
             on_toggle: Box::new(f),
             label: String::from(label),
+            is_label_clickable: true,
+            min_touch_target: 0,
+            is_focused: false,
             width: Length::Shrink,
             style: Renderer::Style::default(),
         }
@@ -66,6 +72,40 @@ impl<Message, Renderer: self::Renderer> Checkbox<Message, Renderer> {
         self
     }
 
+    /// Sets whether clicking the label of the [`Checkbox`] toggles it.
+    ///
+    /// When disabled, only the checkbox itself reacts to the mouse. By default,
+    /// the label is clickable.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn label_clickable(mut self, is_label_clickable: bool) -> Self {
+        self.is_label_clickable = is_label_clickable;
+        self
+    }
+
+    /// Sets the minimum size of the area of the [`Checkbox`] that reacts to the
+    /// mouse.
+    ///
+    /// The area is expanded around the center of the [`Checkbox`] when it is
+    /// smaller than the given size, while its drawn size stays the same. This
+    /// is useful to meet accessibility guidelines for touch targets.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn min_touch_target(mut self, size: u16) -> Self {
+        self.min_touch_target = size;
+        self
+    }
+
+    /// Sets whether the [`Checkbox`] has keyboard focus.
+    ///
+    /// A focused [`Checkbox`] is toggled when Space or Enter is pressed.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -121,17 +161,45 @@ where
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
+                ..
             }) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+                let bounds = if self.is_label_clickable {
+                    layout.bounds()
+                } else {
+                    layout.children().next().unwrap().bounds()
+                };
+
+                let min_size = f32::from(self.min_touch_target);
+                let bounds = bounds.expand_to(min_size, min_size);
+
+                let mouse_over = bounds.contains(cursor_position);
 
                 if mouse_over {
                     messages.push((self.on_toggle)(!self.is_checked));
                 }
             }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if self.is_focused => match key_code {
+                keyboard::KeyCode::Space | keyboard::KeyCode::Enter => {
+                    messages.push((self.on_toggle)(!self.is_checked));
+                }
+                _ => {}
+            },
             _ => {}
         }
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.is_focused {
+            Some(layout.bounds())
+        } else {
+            None
+        }
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -153,18 +221,24 @@ where
             &self.label,
             text::Renderer::default_size(renderer),
             Font::Default,
+            0.0,
             None,
             HorizontalAlignment::Left,
             VerticalAlignment::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = if self.is_label_clickable {
+            bounds.contains(cursor_position)
+        } else {
+            checkbox_bounds.contains(cursor_position)
+        };
 
         self::Renderer::draw(
             renderer,
             checkbox_bounds,
             self.is_checked,
             is_mouse_over,
+            self.is_focused,
             label,
             &self.style,
         )
@@ -197,6 +271,7 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is selected or not
     ///   * whether the mouse is over the [`Checkbox`] or not
+    ///   * whether the [`Checkbox`] has keyboard focus or not
     ///   * the drawn label of the [`Checkbox`]
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -205,6 +280,7 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_focused: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
@@ -222,3 +298,119 @@ where
         Element::new(checkbox)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Size};
+
+    fn on_events(
+        checkbox: &mut Checkbox<bool, Null>,
+        events: &[(Event, Point)],
+    ) -> Vec<bool> {
+        let node = Widget::<bool, Null>::layout(
+            checkbox,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut messages = Vec::new();
+
+        for (event, cursor_position) in events {
+            checkbox.on_event(
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                &mut messages,
+                &Null::new(),
+                None,
+            );
+        }
+
+        messages
+    }
+
+    fn click(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                button: mouse::Button::Left,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(x, y),
+        )
+    }
+
+    fn key_press(key_code: keyboard::KeyCode) -> (Event, Point) {
+        (
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(-1.0, -1.0),
+        )
+    }
+
+    #[test]
+    fn clicking_flips_the_checkbox() {
+        let mut unchecked = Checkbox::new(false, "Check", |checked| checked);
+        let mut checked = Checkbox::new(true, "Check", |checked| checked);
+
+        assert_eq!(on_events(&mut unchecked, &[click(10.0, 10.0)]), vec![true]);
+        assert_eq!(on_events(&mut checked, &[click(10.0, 10.0)]), vec![false]);
+    }
+
+    #[test]
+    fn label_is_only_clickable_when_enabled() {
+        let mut clickable = Checkbox::new(false, "Check", |checked| checked);
+        let mut unclickable = Checkbox::new(false, "Check", |checked| checked)
+            .label_clickable(false);
+
+        assert_eq!(on_events(&mut clickable, &[click(60.0, 10.0)]), vec![true]);
+        assert_eq!(on_events(&mut unclickable, &[click(60.0, 10.0)]), vec![]);
+    }
+
+    #[test]
+    fn focused_checkbox_is_toggled_with_the_keyboard() {
+        let mut focused =
+            Checkbox::new(false, "Check", |checked| checked).focused(true);
+        let mut unfocused = Checkbox::new(false, "Check", |checked| checked);
+
+        assert_eq!(
+            on_events(
+                &mut focused,
+                &[
+                    key_press(keyboard::KeyCode::Space),
+                    key_press(keyboard::KeyCode::Enter)
+                ]
+            ),
+            vec![true, true]
+        );
+        assert_eq!(
+            on_events(&mut unfocused, &[key_press(keyboard::KeyCode::Space)]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn focused_checkbox_reports_its_bounds() {
+        let checkbox = Checkbox::new(false, "Check", |checked| checked);
+        let node = Widget::<bool, Null>::layout(
+            &checkbox,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        assert_eq!(
+            Widget::<bool, Null>::focused_bounds(&checkbox, Layout::new(&node)),
+            None
+        );
+
+        let checkbox = checkbox.focused(true);
+
+        assert_eq!(
+            Widget::<bool, Null>::focused_bounds(&checkbox, Layout::new(&node)),
+            Some(node.bounds())
+        );
+    }
+}
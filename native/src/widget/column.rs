@@ -1,9 +1,9 @@
 //! Distribute content vertically.
-use std::hash::Hash;
+use std::{hash::Hash, time::Instant};
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Widget,
+    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Padding, Point, Rectangle, Widget,
 };
 
 use std::u32;
@@ -16,7 +16,7 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message, Renderer> {
     spacing: u16,
-    padding: u16,
+    padding: Padding,
     width: Length,
     height: Length,
     max_width: u32,
@@ -30,15 +30,24 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     ///
     /// [`Column`]: struct.Column.html
     pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Column`] with the given elements.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
         Column {
             spacing: 0,
-            padding: 0,
+            padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
             align_items: Align::Start,
-            children: Vec::new(),
+            children,
         }
     }
 
@@ -52,11 +61,15 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the padding of the [`Column`].
+    /// Sets the [`Padding`] of the [`Column`].
+    ///
+    /// You can pass a single `u16` to pad every side uniformly, or a
+    /// `(top, right, bottom, left)` tuple to pad each side separately.
     ///
+    /// [`Padding`]: ../struct.Padding.html
     /// [`Column`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -112,6 +125,18 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     }
 }
 
+impl<'a, Message, Renderer>
+    std::iter::FromIterator<Element<'a, Message, Renderer>>
+    for Column<'a, Message, Renderer>
+{
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Element<'a, Message, Renderer>>,
+    {
+        Self::with_children(iter.into_iter().collect())
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Column<'a, Message, Renderer>
 where
@@ -140,7 +165,7 @@ where
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
-            self.padding as f32,
+            self.padding,
             self.spacing as f32,
             self.align_items,
             &self.children,
@@ -170,6 +195,44 @@ where
         );
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.widget.focused_bounds(layout))
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .any(|(child, layout)| {
+                child.widget.can_scroll(layout, cursor_position, delta_y)
+            })
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.widget.overlay(layout))
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.children
+            .iter()
+            .filter_map(|child| child.widget.redraw_at())
+            .min()
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -188,6 +251,7 @@ where
         self.max_height.hash(state);
         self.align_items.hash(state);
         self.spacing.hash(state);
+        self.padding.hash(state);
 
         for child in &self.children {
             child.widget.hash_layout(state);
@@ -233,3 +297,58 @@ where
         Element::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Size, Text};
+
+    #[test]
+    fn asymmetric_padding_offsets_the_children() {
+        let column: Column<'_, (), Null> = Column::new()
+            .padding((5, 10, 15, 20))
+            .push(Text::new("A"))
+            .push(Text::new("B"));
+
+        let node = Widget::<(), Null>::layout(
+            &column,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let children: Vec<Point> = node
+            .children()
+            .iter()
+            .map(|child| Point::new(child.bounds().x, child.bounds().y))
+            .collect();
+
+        assert_eq!(
+            children,
+            vec![Point::new(20.0, 5.0), Point::new(20.0, 25.0)]
+        );
+        assert_eq!(node.size(), Size::new(40.0, 60.0));
+    }
+
+    #[test]
+    fn align_self_overrides_the_alignment_of_a_child() {
+        let column: Column<'_, (), Null> = Column::new()
+            .width(Length::Fill)
+            .align_items(Align::Center)
+            .push(Text::new("AB"))
+            .push(Element::from(Text::new("AB")).align_self(Align::Start));
+
+        let node = Widget::<(), Null>::layout(
+            &column,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let children: Vec<f32> = node
+            .children()
+            .iter()
+            .map(|child| child.bounds().x)
+            .collect();
+
+        assert_eq!(children, vec![90.0, 0.0]);
+    }
+}
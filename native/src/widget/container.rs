@@ -1,9 +1,9 @@
 //! Decorate content and apply alignment.
-use std::hash::Hash;
+use std::{hash::Hash, time::Instant};
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Widget,
 };
 
 use std::u32;
@@ -17,6 +17,7 @@ pub struct Container<'a, Message, Renderer: self::Renderer> {
     height: Length,
     max_width: u32,
     max_height: u32,
+    padding: u16,
     horizontal_alignment: Align,
     vertical_alignment: Align,
     style: Renderer::Style,
@@ -39,6 +40,7 @@ where
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            padding: 0,
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
             style: Renderer::Style::default(),
@@ -62,7 +64,11 @@ where
         self
     }
 
-    /// Sets the maximum width of the [`Container`].
+    /// Sets the maximum width of the contents of the [`Container`].
+    ///
+    /// The [`Container`] keeps its own width, and the contents are aligned
+    /// within it. Therefore, a centered [`Container`] that fills the window
+    /// keeps its capped contents in the middle.
     ///
     /// [`Container`]: struct.Container.html
     pub fn max_width(mut self, max_width: u32) -> Self {
@@ -70,14 +76,28 @@ where
         self
     }
 
-    /// Sets the maximum height of the [`Container`] in pixels.
+    /// Sets the maximum height of the contents of the [`Container`] in
+    /// pixels.
+    ///
+    /// The [`Container`] keeps its own height, and the contents are aligned
+    /// within it.
     ///
     /// [`Container`]: struct.Container.html
     pub fn max_height(mut self, max_height: u32) -> Self {
         self.max_height = max_height;
         self
     }
-    
+
+    /// Sets the padding of the [`Container`].
+    ///
+    /// The contents are inset by the padding on all four sides.
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
     /// Sets the content alignment for the horizontal axis of the [`Container`].
     ///
     /// [`Container`]: struct.Container.html
@@ -137,19 +157,26 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let padding = f32::from(self.padding);
+
         let limits = limits
             .loose()
-            .max_width(self.max_width)
-            .max_height(self.max_height)
             .width(self.width)
-            .height(self.height);
+            .height(self.height)
+            .pad(padding);
+
+        let content_limits = limits
+            .loose()
+            .max_width(self.max_width)
+            .max_height(self.max_height);
 
-        let mut content = self.content.layout(renderer, &limits.loose());
+        let mut content = self.content.layout(renderer, &content_limits);
         let size = limits.resolve(content.size());
 
+        content.move_to(Point::new(padding, padding));
         content.align(self.horizontal_alignment, self.vertical_alignment, size);
 
-        layout::Node::with_children(size, vec![content])
+        layout::Node::with_children(size.pad(padding), vec![content])
     }
 
     fn on_event(
@@ -171,6 +198,38 @@ where
         )
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content
+            .widget
+            .focused_bounds(layout.children().next().unwrap())
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.content.widget.can_scroll(
+            layout.children().next().unwrap(),
+            cursor_position,
+            delta_y,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content
+            .widget
+            .overlay(layout.children().next().unwrap())
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.content.widget.redraw_at()
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -194,6 +253,7 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.padding.hash(state);
 
         self.content.hash_layout(state);
     }
@@ -236,3 +296,71 @@ where
         Element::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Size, Text};
+
+    fn layout(container: &Container<'_, (), Null>, width: f32) -> layout::Node {
+        Widget::<(), Null>::layout(
+            container,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(width, 500.0)),
+        )
+    }
+
+    #[test]
+    fn centered_max_width_has_equal_margins() {
+        let container = Container::new(Text::new("Hello").width(Length::Fill))
+            .width(Length::Fill)
+            .max_width(400)
+            .center_x();
+
+        let node = layout(&container, 1000.0);
+        let content = node.children()[0].bounds();
+
+        assert_eq!(content.width, 400.0);
+        assert_eq!(
+            content.x,
+            node.bounds().width - (content.x + content.width)
+        );
+    }
+
+    #[test]
+    fn max_width_larger_than_the_window_is_ignored() {
+        let container = Container::new(Text::new("Hello").width(Length::Fill))
+            .width(Length::Fill)
+            .max_width(400)
+            .center_x();
+
+        let node = layout(&container, 300.0);
+        let content = node.children()[0].bounds();
+
+        assert_eq!(content.x, 0.0);
+        assert_eq!(content.width, 300.0);
+    }
+
+    #[test]
+    fn padding_insets_the_content() {
+        let container = Container::new(
+            Text::new("Hello").width(Length::Fill).height(Length::Fill),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(10);
+
+        let node = layout(&container, 200.0);
+
+        assert_eq!(node.size(), Size::new(200.0, 500.0));
+        assert_eq!(
+            node.children()[0].bounds(),
+            Rectangle {
+                x: 10.0,
+                y: 10.0,
+                width: 180.0,
+                height: 480.0,
+            }
+        );
+    }
+}
@@ -1,6 +1,8 @@
 //! Display images in your user interface.
 use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
 
+pub use crate::ContentFit;
+
 use std::{
     hash::{Hash, Hasher as _},
     path::PathBuf,
@@ -9,6 +11,14 @@ use std::{
 
 /// A frame that displays an image while keeping aspect ratio.
 ///
+/// A dimension set with [`width`] or [`height`] overrides the intrinsic size
+/// of the image, while a `Shrink` one follows it, keeping the aspect ratio.
+/// While the intrinsic size of the image is unknown, a `Shrink` dimension is
+/// laid out to zero.
+///
+/// When the bounds of the [`Image`] do not match the aspect ratio of the
+/// image, its [`ContentFit`] decides how the image is scaled into them.
+///
 /// # Example
 ///
 /// ```
@@ -18,11 +28,18 @@ use std::{
 /// ```
 ///
 /// <img src="https://github.com/hecrj/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+///
+/// [`Image`]: struct.Image.html
+/// [`ContentFit`]: enum.ContentFit.html
+/// [`width`]: #method.width
+/// [`height`]: #method.height
 #[derive(Debug)]
 pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    nine_slice: Option<NineSlice>,
 }
 
 impl Image {
@@ -34,6 +51,8 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            nine_slice: None,
         }
     }
 
@@ -52,6 +71,33 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    ///
+    /// [`ContentFit`]: enum.ContentFit.html
+    /// [`ContentFit::Contain`]: enum.ContentFit.html#variant.Contain
+    /// [`Image`]: struct.Image.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Draws the [`Image`] using nine-slice scaling with the given insets.
+    ///
+    /// The corners of the image keep their original size, the edges are
+    /// stretched along a single axis, and the center fills the rest. The
+    /// aspect ratio of the image is not kept in this mode, and its
+    /// [`ContentFit`] is ignored.
+    ///
+    /// [`ContentFit`]: enum.ContentFit.html
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn nine_slice(mut self, nine_slice: NineSlice) -> Self {
+        self.nine_slice = Some(nine_slice);
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -72,20 +118,40 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
-
-        let aspect_ratio = width as f32 / height as f32;
+        let intrinsic_size = Size::new(width as f32, height as f32);
 
         let mut size = limits
             .width(self.width)
             .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
+            .resolve(intrinsic_size);
+
+        // The aspect ratio is unknown until the image is decoded, and it is
+        // not kept by nine-slice scaling
+        if self.nine_slice.is_some() || width == 0 || height == 0 {
+            return layout::Node::new(size);
+        }
+
+        let aspect_ratio = intrinsic_size.width / intrinsic_size.height;
 
-        let viewport_aspect_ratio = size.width / size.height;
+        // Only the dimensions that follow the image are adjusted, explicit
+        // ones are kept as requested
+        match (self.width, self.height) {
+            (Length::Shrink, Length::Shrink) => {
+                let viewport_aspect_ratio = size.width / size.height;
 
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
+                if viewport_aspect_ratio > aspect_ratio {
+                    size.width = size.height * aspect_ratio;
+                } else {
+                    size.height = size.width / aspect_ratio;
+                }
+            }
+            (Length::Shrink, _) => {
+                size.width = (size.height * aspect_ratio).min(size.width);
+            }
+            (_, Length::Shrink) => {
+                size.height = (size.width / aspect_ratio).min(size.height);
+            }
+            _ => {}
         }
 
         layout::Node::new(size)
@@ -98,13 +164,49 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        renderer.draw(
+            self.handle.clone(),
+            self.content_fit,
+            self.nine_slice,
+            layout,
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
+        self.nine_slice.hash(state);
+    }
+}
+
+/// The insets of a nine-slice [`Image`], in pixels of the source image.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NineSlice {
+    /// The height of the top edge
+    pub top: u16,
+    /// The width of the right edge
+    pub right: u16,
+    /// The height of the bottom edge
+    pub bottom: u16,
+    /// The width of the left edge
+    pub left: u16,
+}
+
+impl NineSlice {
+    /// Creates new [`NineSlice`] insets with the same value on every side.
+    ///
+    /// [`NineSlice`]: struct.NineSlice.html
+    pub fn uniform(inset: u16) -> Self {
+        NineSlice {
+            top: inset,
+            right: inset,
+            bottom: inset,
+            left: inset,
+        }
     }
 }
 
@@ -209,13 +311,32 @@ impl std::fmt::Debug for Data {
 pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an [`Image`] located on the given path.
     ///
+    /// It should return `(0, 0)` while the dimensions are unknown, like when
+    /// the image has not been decoded yet or cannot be decoded.
+    ///
     /// [`Image`]: struct.Image.html
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
     /// Draws an [`Image`].
     ///
+    /// It receives:
+    ///   * the [`Handle`] of the image
+    ///   * the [`ContentFit`] of the image
+    ///   * the [`NineSlice`] insets of the image, if any
+    ///   * the [`Layout`] of the image
+    ///
     /// [`Image`]: struct.Image.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// [`Handle`]: struct.Handle.html
+    /// [`ContentFit`]: enum.ContentFit.html
+    /// [`NineSlice`]: struct.NineSlice.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        content_fit: ContentFit,
+        nine_slice: Option<NineSlice>,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -226,3 +347,74 @@ where
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rectangle;
+
+    /// A renderer whose images have already been decoded with the given
+    /// dimensions.
+    struct Decoded(u32, u32);
+
+    impl crate::Renderer for Decoded {
+        type Output = ();
+        type Defaults = ();
+
+        fn overlay(&mut self, _base: (), _overlay: (), _bounds: Rectangle) {}
+    }
+
+    impl self::Renderer for Decoded {
+        fn dimensions(&self, _handle: &Handle) -> (u32, u32) {
+            (self.0, self.1)
+        }
+
+        fn draw(
+            &mut self,
+            _handle: Handle,
+            _content_fit: ContentFit,
+            _nine_slice: Option<NineSlice>,
+            _layout: Layout<'_>,
+        ) {
+        }
+    }
+
+    fn size(image: Image, renderer: Decoded) -> Size {
+        Widget::<(), Decoded>::layout(
+            &image,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        )
+        .size()
+    }
+
+    #[test]
+    fn explicit_size_overrides_the_intrinsic_one() {
+        let image = Image::new("ferris.png")
+            .width(Length::Units(100))
+            .height(Length::Units(100));
+
+        assert_eq!(size(image, Decoded(400, 200)), Size::new(100.0, 100.0));
+    }
+
+    #[test]
+    fn shrinking_dimension_keeps_the_aspect_ratio() {
+        let image = Image::new("ferris.png").width(Length::Units(100));
+
+        assert_eq!(size(image, Decoded(400, 200)), Size::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn shrinking_image_has_its_intrinsic_size() {
+        let image = Image::new("ferris.png");
+
+        assert_eq!(size(image, Decoded(400, 200)), Size::new(400.0, 200.0));
+    }
+
+    #[test]
+    fn shrinking_image_is_empty_until_decoded() {
+        let image = Image::new("ferris.png");
+
+        assert_eq!(size(image, Decoded(0, 0)), Size::ZERO);
+    }
+}
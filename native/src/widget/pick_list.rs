@@ -0,0 +1,721 @@
+//! Let your users choose a single value from a dropdown list of options.
+//!
+//! A [`PickList`] has some local [`State`].
+//!
+//! [`PickList`]: struct.PickList.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, overlay, text, Clipboard, Element, Event, Font, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::{borrow::Cow, hash::Hash};
+
+/// A widget that shows the selected option and lets the user pick another
+/// one from a dropdown list.
+///
+/// The list is drawn in an overlay, so it is not clipped by the parents of
+/// the [`PickList`]. It is closed when an option is picked, when Escape is
+/// pressed or when the user clicks outside of it. While it is open, Up and
+/// Down move the highlighted option and Enter picks it.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, pick_list};
+/// #
+/// # pub type PickList<'a, T, Message> =
+/// #     iced_native::PickList<'a, T, Message, Null>;
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Language {
+///     Rust,
+///     Elm,
+/// }
+///
+/// impl std::fmt::Display for Language {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     LanguageSelected(Language),
+/// }
+///
+/// let mut state = pick_list::State::new();
+/// let options = [Language::Rust, Language::Elm];
+///
+/// let pick_list = PickList::new(
+///     &mut state,
+///     &options[..],
+///     Some(Language::Rust),
+///     Message::LanguageSelected,
+/// );
+/// ```
+///
+/// [`PickList`]: struct.PickList.html
+#[allow(missing_debug_implementations)]
+pub struct PickList<'a, T, Message, Renderer: self::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    state: &'a mut State,
+    options: Cow<'a, [T]>,
+    selected: Option<T>,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Font,
+    style: Renderer::Style,
+}
+
+impl<'a, T, Message, Renderer> PickList<'a, T, Message, Renderer>
+where
+    T: Clone + Eq + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`PickList`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`PickList`]
+    ///   * the options of the [`PickList`]
+    ///   * the current selected option
+    ///   * a function that will be called when an option is picked. It
+    ///   receives the option and must produce a `Message`.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_selected: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        PickList {
+            state,
+            options: options.into(),
+            selected,
+            on_selected: Box::new(on_selected),
+            width: Length::Shrink,
+            padding: 5,
+            text_size: None,
+            font: Font::Default,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`PickList`] and of its options.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+    hovered_option: Option<usize>,
+}
+
+impl State {
+    /// Creates a new [`State`], with the list of options closed.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the list of options of the [`PickList`] is open.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for PickList<'a, T, Message, Renderer>
+where
+    T: Clone + Eq + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self
+            .text_size
+            .unwrap_or(text::Renderer::default_size(renderer));
+        let padding = f32::from(self.padding);
+
+        let limits =
+            limits.width(self.width).height(Length::Shrink).pad(padding);
+
+        let labels_width = self
+            .options
+            .iter()
+            .map(|option| {
+                let (width, _) = renderer.measure(
+                    &option.to_string(),
+                    text_size,
+                    self.font,
+                    0.0,
+                    Size::INFINITY,
+                );
+
+                width.round()
+            })
+            .fold(0.0, f32::max);
+
+        // Leave room for the arrow icon
+        let size = limits.resolve(Size::new(
+            labels_width + f32::from(text_size),
+            f32::from(text_size),
+        ));
+
+        layout::Node::new(size.pad(padding))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) if layout.bounds().contains(cursor_position) => {
+                self.state.is_open = !self.state.is_open;
+
+                let selected = self.selected.as_ref();
+
+                self.state.hovered_option = self
+                    .options
+                    .iter()
+                    .position(|option| Some(option) == selected);
+            }
+            _ => {}
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let selected = self.selected.as_ref();
+
+        let menu = Menu {
+            state: &mut *self.state,
+            selected_option: self
+                .options
+                .iter()
+                .position(|option| Some(option) == selected),
+            options: &self.options,
+            on_selected: &*self.on_selected,
+            target_size: Size::new(bounds.width, bounds.height),
+            padding: self.padding,
+            text_size: self.text_size,
+            font: self.font,
+            style: &self.style,
+        };
+
+        Some(overlay::Element::new(
+            Point::new(bounds.x, bounds.y),
+            Box::new(menu),
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let text_size = self
+            .text_size
+            .unwrap_or(text::Renderer::default_size(renderer));
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.selected.as_ref().map(ToString::to_string),
+            self.padding,
+            text_size,
+            self.font,
+            self.state.is_open,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<State>().hash(state);
+
+        for option in self.options.iter() {
+            option.to_string().hash(state);
+        }
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The list of options of an open [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+struct Menu<'a, T, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    options: &'a [T],
+    selected_option: Option<usize>,
+    on_selected: &'a dyn Fn(T) -> Message,
+    target_size: Size,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Font,
+    style: &'a Renderer::Style,
+}
+
+impl<'a, T, Message, Renderer> Menu<'a, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: self::Renderer,
+{
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size = self
+            .text_size
+            .unwrap_or(text::Renderer::default_size(renderer));
+
+        f32::from(text_size + self.padding * 2)
+    }
+
+    fn option_at(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        renderer: &Renderer,
+    ) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let index = ((cursor_position.y - bounds.y)
+            / self.option_height(renderer)) as usize;
+
+        Some(index.min(self.options.len().saturating_sub(1)))
+    }
+
+    fn select(&mut self, index: usize, messages: &mut Vec<Message>) {
+        if let Some(option) = self.options.get(index) {
+            messages.push((self.on_selected)(option.clone()));
+        }
+
+        self.state.is_open = false;
+    }
+}
+
+impl<'a, T, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Menu<'a, T, Message, Renderer>
+where
+    T: Clone + ToString,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let height = self.option_height(renderer) * self.options.len() as f32;
+
+        let space_above = position.y;
+        let space_below = bounds.height - position.y - self.target_size.height;
+
+        // Open upwards only when the list does not fit below the target and
+        // there is more room above it
+        let is_above = height > space_below && space_above > space_below;
+
+        // The target is kept as a child, so clicking it is not considered
+        // clicking away from the list
+        let mut target = layout::Node::new(self.target_size);
+
+        target.move_to(Point::new(
+            0.0,
+            if is_above {
+                height
+            } else {
+                -self.target_size.height
+            },
+        ));
+
+        let mut node = layout::Node::with_children(
+            Size::new(self.target_size.width, height),
+            vec![target],
+        );
+
+        node.move_to(Point::new(
+            position.x,
+            if is_above {
+                position.y - height
+            } else {
+                position.y + self.target_size.height
+            },
+        ));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                if let Some(index) =
+                    self.option_at(bounds, Point::new(x, y), renderer)
+                {
+                    self.state.hovered_option = Some(index);
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if let Some(index) =
+                    self.option_at(bounds, cursor_position, renderer)
+                {
+                    self.select(index, messages);
+                } else {
+                    let target = layout.children().next().unwrap().bounds();
+
+                    // The target toggles the list by itself
+                    if !target.contains(cursor_position) {
+                        self.state.is_open = false;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                let last = self.options.len().saturating_sub(1);
+
+                match key_code {
+                    keyboard::KeyCode::Up => {
+                        self.state.hovered_option = Some(
+                            self.state
+                                .hovered_option
+                                .map(|index| index.saturating_sub(1))
+                                .unwrap_or(last),
+                        );
+                    }
+                    keyboard::KeyCode::Down => {
+                        self.state.hovered_option = Some(
+                            self.state
+                                .hovered_option
+                                .map(|index| (index + 1).min(last))
+                                .unwrap_or(0),
+                        );
+                    }
+                    keyboard::KeyCode::Enter => {
+                        if let Some(index) = self.state.hovered_option {
+                            self.select(index, messages);
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.state.is_open = false;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let text_size = self
+            .text_size
+            .unwrap_or(text::Renderer::default_size(renderer));
+
+        let options: Vec<String> =
+            self.options.iter().map(ToString::to_string).collect();
+
+        renderer.draw_menu(
+            layout.bounds(),
+            cursor_position,
+            &options,
+            self.selected_option,
+            self.state.hovered_option,
+            self.padding,
+            text_size,
+            self.font,
+            self.style,
+        )
+    }
+}
+
+/// The renderer of a [`PickList`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`PickList`] in your user interface.
+///
+/// [`PickList`]: struct.PickList.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`PickList`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`PickList`]
+    ///   * the cursor position
+    ///   * the label of the selected option, if any
+    ///   * the padding of the [`PickList`]
+    ///   * the text size of the [`PickList`]
+    ///   * the [`Font`] of the [`PickList`]
+    ///   * whether the list of options is open or not
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`Font`]: ../../struct.Font.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        selected: Option<String>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        is_open: bool,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+
+    /// Draws the open list of options of a [`PickList`].
+    ///
+    /// It receives:
+    ///   * the bounds of the list
+    ///   * the cursor position
+    ///   * the labels of the options, stacked vertically
+    ///   * the index of the selected option, if any
+    ///   * the index of the highlighted option, if any
+    ///   * the padding of every option
+    ///   * the text size of the [`PickList`]
+    ///   * the [`Font`] of the [`PickList`]
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`Font`]: ../../struct.Font.html
+    fn draw_menu(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        options: &[String],
+        selected_option: Option<usize>,
+        hovered_option: Option<usize>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<PickList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + Eq + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        pick_list: PickList<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(pick_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Cache, UserInterface};
+
+    static OPTIONS: [&str; 3] = ["A", "B", "C"];
+
+    fn on_events(state: &mut State, events: Vec<Event>) -> Vec<&'static str> {
+        let mut renderer = Null::new();
+        let pick_list = PickList::<_, _, Null>::new(
+            state,
+            &OPTIONS[..],
+            Some("A"),
+            |option| option,
+        );
+
+        let mut user_interface = UserInterface::build(
+            pick_list,
+            Size::new(400.0, 300.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        user_interface.update(events, None, &renderer)
+    }
+
+    fn click(x: f32, y: f32) -> Vec<Event> {
+        vec![
+            Event::Mouse(mouse::Event::CursorMoved { x, y }),
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                button: mouse::Button::Left,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+        ]
+    }
+
+    fn key_press(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::Input {
+            state: ButtonState::Pressed,
+            key_code,
+            modifiers: keyboard::ModifiersState::default(),
+        })
+    }
+
+    // The pick list is 40x30 and every option below it is 30 units tall
+    fn open() -> Vec<Event> {
+        click(20.0, 15.0)
+    }
+
+    #[test]
+    fn clicking_the_pick_list_opens_it() {
+        let mut state = State::new();
+
+        assert!(on_events(&mut state, open()).is_empty());
+        assert!(state.is_open());
+    }
+
+    #[test]
+    fn clicking_an_option_selects_it_and_closes_the_list() {
+        let mut state = State::new();
+        let events = [open(), click(20.0, 75.0)].concat();
+
+        assert_eq!(on_events(&mut state, events), vec!["B"]);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn enter_selects_the_option_highlighted_with_the_arrows() {
+        let mut state = State::new();
+        let events = [
+            open(),
+            vec![
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Down),
+                key_press(keyboard::KeyCode::Up),
+                key_press(keyboard::KeyCode::Enter),
+            ],
+        ]
+        .concat();
+
+        assert_eq!(on_events(&mut state, events), vec!["B"]);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn clicking_away_closes_the_list() {
+        let mut state = State::new();
+        let events = [open(), click(300.0, 200.0)].concat();
+
+        assert!(on_events(&mut state, events).is_empty());
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn escape_closes_the_list() {
+        let mut state = State::new();
+        let events =
+            [open(), vec![key_press(keyboard::KeyCode::Escape)]].concat();
+
+        assert!(on_events(&mut state, events).is_empty());
+        assert!(!state.is_open());
+    }
+}
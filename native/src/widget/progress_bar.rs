@@ -166,3 +166,32 @@ where
         Element::new(progress_bar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn layout(progress_bar: &ProgressBar<Null>) -> layout::Node {
+        Widget::<(), Null>::layout(
+            progress_bar,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        )
+    }
+
+    #[test]
+    fn progress_bar_fills_the_width() {
+        let progress_bar = ProgressBar::new(0.0..=100.0, 50.0);
+
+        assert_eq!(layout(&progress_bar).size(), Size::new(200.0, 30.0));
+    }
+
+    #[test]
+    fn progress_bar_has_the_requested_height() {
+        let progress_bar =
+            ProgressBar::new(0.0..=100.0, 50.0).height(Length::Units(10));
+
+        assert_eq!(layout(&progress_bar).size(), Size::new(200.0, 10.0));
+    }
+}
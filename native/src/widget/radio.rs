@@ -1,8 +1,8 @@
 //! Create choices using radio buttons.
 use crate::{
-    input::{mouse, ButtonState},
-    layout, row, text, Align, Clipboard, Element, Event, Font, Hasher,
-    HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
+    input::{keyboard, mouse, ButtonState},
+    layout, row, text, Clipboard, Element, Event, Font, Hasher,
+    HorizontalAlignment, Layout, Length, Point, Rectangle, Size, Text,
     VerticalAlignment, Widget,
 };
 
@@ -34,14 +34,69 @@ use std::hash::Hash;
 /// ```
 ///
 /// ![Radio buttons drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/radio.png?raw=true)
+///
+/// Alternatively, a [`Radio`] can keep its value and map it to a `Message`
+/// only when selected, avoiding a closure allocation per [`Radio`]:
+///
+/// ```
+/// # type Radio<Message, Value> =
+/// #     iced_native::Radio<Message, iced_native::renderer::Null, Value>;
+/// #
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// # pub enum Choice {
+/// #     A,
+/// #     B,
+/// # }
+/// #
+/// # #[derive(Debug, Clone, Copy)]
+/// # pub enum Message {
+/// #     RadioSelected(Choice),
+/// # }
+/// #
+/// # let selected_choice = Some(Choice::A);
+/// #
+/// Radio::with_value(Choice::A, "This is A", selected_choice)
+///     .on_select(Message::RadioSelected);
+/// ```
+///
+/// [`Radio`]: struct.Radio.html
 #[allow(missing_debug_implementations)]
-pub struct Radio<Message, Renderer: self::Renderer> {
+pub struct Radio<Message, Renderer: self::Renderer, Value = ()> {
+    value: Value,
     is_selected: bool,
-    on_click: Box<dyn Fn() -> Message>,
+    on_click: Option<Box<dyn Fn(Value) -> Message>>,
     label: String,
+    width: Length,
+    size: Option<u32>,
+    spacing: u16,
+    text_size: Option<u16>,
+    font: Font,
+    is_label_clickable: bool,
+    min_touch_target: u16,
+    is_focused: bool,
+    is_enabled: bool,
+    on_tab: Option<Box<dyn Fn(FocusDirection) -> Message>>,
+    is_hovered: bool,
+    on_hover: Option<Box<dyn Fn(bool) -> Message>>,
     style: Renderer::Style,
 }
 
+/// The direction in which keyboard focus should move from a [`Radio`].
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    /// Focus the next [`Radio`] (i.e. Tab was pressed).
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Next,
+
+    /// Focus the previous [`Radio`] (i.e. Shift+Tab was pressed).
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Previous,
+}
+
 impl<Message, Renderer: self::Renderer> Radio<Message, Renderer> {
     /// Creates a new [`Radio`] button.
     ///
@@ -60,11 +115,193 @@ impl<Message, Renderer: self::Renderer> Radio<Message, Renderer> {
     {
         Radio {
             is_selected: Some(value) == selected,
-            on_click: Box::new(move || f(value)),
+            ..Radio::with_value((), label, None).on_select(move |()| f(value))
+        }
+    }
+}
+
+impl<Message, Renderer, Value> Radio<Message, Renderer, Value>
+where
+    Renderer: self::Renderer,
+    Value: Eq + Copy,
+{
+    /// Creates a new [`Radio`] button that keeps its value.
+    ///
+    /// It expects:
+    ///   * the value related to the [`Radio`] button
+    ///   * the label of the [`Radio`] button
+    ///   * the current selected value
+    ///
+    /// Use [`on_select`] to produce a `Message` when the [`Radio`] is
+    /// selected.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`on_select`]: #method.on_select
+    pub fn with_value(
+        value: Value,
+        label: &str,
+        selected: Option<Value>,
+    ) -> Self {
+        Radio {
+            value,
+            is_selected: Some(value) == selected,
+            on_click: None,
             label: String::from(label),
+            width: Length::Fill,
+            size: None,
+            spacing: 15,
+            text_size: None,
+            font: Font::Default,
+            is_label_clickable: true,
+            min_touch_target: 0,
+            is_focused: false,
+            is_enabled: true,
+            on_tab: None,
+            is_hovered: false,
+            on_hover: None,
             style: Renderer::Style::default(),
         }
     }
+}
+
+impl<Message, Renderer, Value> Radio<Message, Renderer, Value>
+where
+    Renderer: self::Renderer,
+{
+    /// Sets the function that will be called when the [`Radio`] is selected.
+    ///
+    /// It receives the value of the [`Radio`] and must produce a `Message`.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Value) -> Message,
+    {
+        self.on_click = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Radio`] button, including its label.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the diameter of the [`Radio`] button.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the spacing between the [`Radio`] button and its label.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Radio`] button.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the [`Font`] of the label of the [`Radio`] button.
+    ///
+    /// [`Font`]: ../../struct.Font.html
+    /// [`Radio`]: struct.Radio.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets whether clicking the label of the [`Radio`] button toggles it.
+    ///
+    /// When disabled, only the radio itself reacts to the mouse. By default,
+    /// the label is clickable.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn label_clickable(mut self, is_label_clickable: bool) -> Self {
+        self.is_label_clickable = is_label_clickable;
+        self
+    }
+
+    /// Sets the minimum size of the area of the [`Radio`] that reacts to the
+    /// mouse.
+    ///
+    /// The area is expanded around the center of the [`Radio`] button when it is
+    /// smaller than the given size, while its drawn size stays the same. This
+    /// is useful to meet accessibility guidelines for touch targets.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn min_touch_target(mut self, size: u16) -> Self {
+        self.min_touch_target = size;
+        self
+    }
+
+    /// Sets whether the [`Radio`] button has keyboard focus.
+    ///
+    /// A focused [`Radio`] is selected when Space or Enter is pressed.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Sets whether the [`Radio`] button can be selected.
+    ///
+    /// A disabled [`Radio`] ignores clicks and key presses, and it is drawn
+    /// dimmed. By default, a [`Radio`] is enabled.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    /// Sets the function that will be called when Tab is pressed while the
+    /// [`Radio`] button is focused.
+    ///
+    /// It receives the [`FocusDirection`] and must produce a `Message`. This
+    /// can be used to move the focus within a group of radio buttons.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`FocusDirection`]: enum.FocusDirection.html
+    pub fn on_tab<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(FocusDirection) -> Message,
+    {
+        self.on_tab = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function that will be called when the mouse cursor enters or
+    /// leaves the [`Radio`] button.
+    ///
+    /// A [`Radio`] does not keep state between frames. Therefore, you must
+    /// provide whether it was hovered, as last notified by the function, so
+    /// the message is only produced when the cursor enters or leaves it.
+    ///
+    /// The function receives whether the [`Radio`] is now hovered and must
+    /// produce a `Message`.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn on_hover<F>(mut self, is_hovered: bool, f: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.is_hovered = is_hovered;
+        self.on_hover = Some(Box::new(f));
+        self
+    }
 
     /// Sets the style of the [`Radio`] button.
     ///
@@ -75,12 +312,14 @@ impl<Message, Renderer: self::Renderer> Radio<Message, Renderer> {
     }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
+impl<Message, Renderer, Value> Widget<Message, Renderer>
+    for Radio<Message, Renderer, Value>
 where
+    Value: Copy,
     Renderer: self::Renderer + text::Renderer + row::Renderer,
 {
     fn width(&self) -> Length {
-        Length::Fill
+        self.width
     }
 
     fn height(&self) -> Length {
@@ -92,19 +331,46 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let size = self::Renderer::default_size(renderer);
+        let size =
+            self.size.unwrap_or(self::Renderer::default_size(renderer)) as f32;
+        let text_size = self
+            .text_size
+            .unwrap_or(text::Renderer::default_size(renderer));
+        let line_height = f32::from(text_size);
+        let spacing = f32::from(self.spacing);
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let label_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (limits.max().width - size - spacing).max(0.0),
+                limits.max().height,
+            ),
+        );
+
+        let mut label = Widget::<(), Renderer>::layout(
+            &Text::new(&self.label).size(text_size).font(self.font),
+            renderer,
+            &label_limits,
+        );
+        let mut radio = layout::Node::new(Size::new(size, size));
+
+        // Align the radio with the first line of the label
+        let radio_y = ((line_height - size) / 2.0).max(0.0);
+        let label_y = ((size - line_height) / 2.0).max(0.0);
 
-        Row::<(), Renderer>::new()
-            .width(Length::Fill)
-            .spacing(15)
-            .align_items(Align::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(size as u16))
-                    .height(Length::Units(size as u16)),
-            )
-            .push(Text::new(&self.label))
-            .layout(renderer, limits)
+        radio.move_to(Point::new(0.0, radio_y));
+        label.move_to(Point::new(size + spacing, label_y));
+
+        let label_size = label.size();
+
+        let size = limits.resolve(Size::new(
+            size + spacing + label_size.width,
+            (radio_y + size).max(label_y + label_size.height),
+        ));
+
+        layout::Node::with_children(size, vec![radio, label])
     }
 
     fn on_event(
@@ -120,15 +386,87 @@ where
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
-            }) => {
-                if layout.bounds().contains(cursor_position) {
-                    messages.push((self.on_click)());
+                ..
+            }) if self.is_enabled => {
+                let bounds = if self.is_label_clickable {
+                    layout.bounds()
+                } else {
+                    layout.children().next().unwrap().bounds()
+                };
+
+                let min_size = f32::from(self.min_touch_target);
+                let bounds = bounds.expand_to(min_size, min_size);
+
+                if bounds.contains(cursor_position) {
+                    if let Some(on_click) = &self.on_click {
+                        messages.push(on_click(self.value));
+                    }
                 }
             }
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                let bounds = if self.is_label_clickable {
+                    layout.bounds()
+                } else {
+                    layout.children().next().unwrap().bounds()
+                };
+
+                let min_size = f32::from(self.min_touch_target);
+                let bounds = bounds.expand_to(min_size, min_size);
+
+                let is_mouse_over = bounds.contains(Point::new(x, y));
+
+                if is_mouse_over != self.is_hovered {
+                    self.is_hovered = is_mouse_over;
+
+                    if let Some(on_hover) = &self.on_hover {
+                        messages.push(on_hover(is_mouse_over));
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                if self.is_hovered {
+                    self.is_hovered = false;
+
+                    if let Some(on_hover) = &self.on_hover {
+                        messages.push(on_hover(false));
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) if self.is_focused => match key_code {
+                keyboard::KeyCode::Space | keyboard::KeyCode::Enter
+                    if self.is_enabled =>
+                {
+                    if let Some(on_click) = &self.on_click {
+                        messages.push(on_click(self.value));
+                    }
+                }
+                keyboard::KeyCode::Tab => {
+                    if let Some(on_tab) = &self.on_tab {
+                        messages.push(on_tab(if modifiers.shift {
+                            FocusDirection::Previous
+                        } else {
+                            FocusDirection::Next
+                        }));
+                    }
+                }
+                _ => {}
+            },
             _ => {}
         }
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.is_focused {
+            Some(layout.bounds())
+        } else {
+            None
+        }
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -148,20 +486,28 @@ where
             defaults,
             label_layout.bounds(),
             &self.label,
-            text::Renderer::default_size(renderer),
-            Font::Default,
+            self.text_size
+                .unwrap_or(text::Renderer::default_size(renderer)),
+            self.font,
+            0.0,
             None,
             HorizontalAlignment::Left,
             VerticalAlignment::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = if self.is_label_clickable {
+            bounds.contains(cursor_position)
+        } else {
+            radio_bounds.contains(cursor_position)
+        };
 
         self::Renderer::draw(
             renderer,
             radio_bounds,
             self.is_selected,
             is_mouse_over,
+            self.is_focused,
+            self.is_enabled,
             label,
             &self.style,
         )
@@ -169,6 +515,15 @@ where
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.label.hash(state);
+        self.width.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
+
+        match self.font {
+            Font::Default => 0.hash(state),
+            Font::External { name, .. } => name.hash(state),
+        }
     }
 }
 
@@ -194,6 +549,8 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
     ///   * whether the mouse is over the [`Radio`] or not
+    ///   * whether the [`Radio`] has keyboard focus or not
+    ///   * whether the [`Radio`] is enabled or not
     ///   * the drawn label of the [`Radio`]
     ///
     /// [`Radio`]: struct.Radio.html
@@ -202,18 +559,299 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        is_focused: bool,
+        is_enabled: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
+impl<'a, Message, Renderer, Value> From<Radio<Message, Renderer, Value>>
     for Element<'a, Message, Renderer>
 where
+    Value: 'static + Copy,
     Renderer: 'static + self::Renderer + row::Renderer + text::Renderer,
     Message: 'static,
 {
-    fn from(radio: Radio<Message, Renderer>) -> Element<'a, Message, Renderer> {
+    fn from(
+        radio: Radio<Message, Renderer, Value>,
+    ) -> Element<'a, Message, Renderer> {
         Element::new(radio)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn layout<Message, Value: Copy>(
+        radio: &Radio<Message, Null, Value>,
+        max_width: f32,
+    ) -> layout::Node {
+        Widget::<Message, Null>::layout(
+            radio,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(max_width, 200.0)),
+        )
+    }
+
+    fn on_events<Message, Value: Copy>(
+        radio: &mut Radio<Message, Null, Value>,
+        events: &[(Event, Point)],
+    ) -> Vec<Message> {
+        let node = layout(radio, 200.0);
+        let mut messages = Vec::new();
+
+        for (event, cursor_position) in events {
+            radio.on_event(
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                &mut messages,
+                &Null::new(),
+                None,
+            );
+        }
+
+        messages
+    }
+
+    fn hash_layout<Message, Value: Copy>(
+        radio: &Radio<Message, Null, Value>,
+    ) -> u64 {
+        use std::hash::Hasher as _;
+
+        let mut hasher = Hasher::default();
+        Widget::<Message, Null>::hash_layout(radio, &mut hasher);
+
+        hasher.finish()
+    }
+
+    fn click(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                button: mouse::Button::Left,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(x, y),
+        )
+    }
+
+    fn key_press(key_code: keyboard::KeyCode) -> (Event, Point) {
+        (
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(-1.0, -1.0),
+        )
+    }
+
+    fn cursor_moved(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::CursorMoved { x, y }),
+            Point::new(x, y),
+        )
+    }
+
+    #[test]
+    fn focused_radio_is_selected_with_the_keyboard() {
+        let mut radio = Radio::new(1, "Radio", None, |value| value);
+
+        assert!(
+            on_events(&mut radio, &[key_press(keyboard::KeyCode::Space)])
+                .is_empty()
+        );
+
+        let mut radio = radio.focused(true);
+
+        assert_eq!(
+            on_events(
+                &mut radio,
+                &[
+                    key_press(keyboard::KeyCode::Space),
+                    key_press(keyboard::KeyCode::Enter),
+                    key_press(keyboard::KeyCode::A),
+                ]
+            ),
+            vec![1, 1]
+        );
+    }
+
+    #[test]
+    fn disabled_radio_ignores_clicks() {
+        let mut radio = Radio::new(1, "Radio", None, |value| value);
+
+        assert_eq!(on_events(&mut radio, &[click(5.0, 5.0)]), vec![1]);
+
+        let mut radio = radio.enabled(false).focused(true);
+
+        assert!(on_events(
+            &mut radio,
+            &[click(5.0, 5.0), key_press(keyboard::KeyCode::Space)]
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn long_label_wraps_in_a_narrow_radio() {
+        let radio = Radio::new(
+            1,
+            "A label that does not fit in a single line",
+            None,
+            |value| value,
+        );
+
+        let default_size = Renderer::default_size(&Null::new()) as f32;
+
+        assert_eq!(layout(&radio, 1000.0).size().height, default_size);
+        assert!(layout(&radio, 100.0).size().height > default_size);
+    }
+
+    #[test]
+    fn label_grows_with_the_text_size() {
+        let radio = || Radio::new(1, "Radio", None, |value| value);
+
+        let small = layout(&radio().text_size(10), 200.0);
+        let large = layout(&radio().text_size(30), 200.0);
+
+        let label = |node: &layout::Node| node.children()[1].size();
+
+        assert!(label(&large).width > label(&small).width);
+        assert!(label(&large).height > label(&small).height);
+        assert_ne!(
+            hash_layout(&radio().text_size(10)),
+            hash_layout(&radio().text_size(30))
+        );
+    }
+
+    #[test]
+    fn spacing_moves_the_label() {
+        let radio = || Radio::new(1, "Radio", None, |value| value);
+
+        let narrow = layout(&radio().spacing(5), 200.0);
+        let wide = layout(&radio().spacing(25), 200.0);
+
+        let label_x = |node: &layout::Node| node.children()[1].bounds().x;
+
+        assert_eq!(label_x(&wide) - label_x(&narrow), 20.0);
+    }
+
+    #[test]
+    fn radio_has_the_requested_size() {
+        let radio = || Radio::new(1, "Radio", None, |value| value);
+
+        let node = layout(&radio().size(32), 200.0);
+
+        assert_eq!(node.children()[0].size(), Size::new(32.0, 32.0));
+        assert_ne!(hash_layout(&radio()), hash_layout(&radio().size(32)));
+    }
+
+    #[test]
+    fn on_select_produces_the_same_messages_as_new() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Choice {
+            A,
+        }
+
+        let events = [click(5.0, 5.0), click(300.0, 300.0)];
+
+        let mut radio = Radio::new(Choice::A, "A", None, Some);
+        let mut with_value =
+            Radio::with_value(Choice::A, "A", None).on_select(Some);
+
+        assert_eq!(on_events(&mut radio, &events), vec![Some(Choice::A)]);
+        assert_eq!(
+            on_events(&mut with_value, &events),
+            on_events(&mut radio, &events)
+        );
+    }
+
+    #[test]
+    fn shrinking_radio_fits_its_contents() {
+        let radio = || Radio::new(1, "Radio", None, |value| value);
+
+        let node = layout(&radio().width(Length::Shrink), 200.0);
+        let children = node.children();
+
+        assert_eq!(
+            node.size().width,
+            children[0].size().width + 15.0 + children[1].size().width
+        );
+        assert_eq!(layout(&radio(), 200.0).size().width, 200.0);
+    }
+
+    fn hover_radio(is_hovered: bool) -> Radio<bool, Null> {
+        Radio::new((), "Radio", None, |()| false)
+            .on_hover(is_hovered, |is_hovered| is_hovered)
+    }
+
+    #[test]
+    fn notifies_when_the_cursor_enters() {
+        assert_eq!(
+            on_events(&mut hover_radio(false), &[cursor_moved(5.0, 5.0)]),
+            vec![true]
+        );
+    }
+
+    #[test]
+    fn does_not_notify_while_the_cursor_stays_inside() {
+        assert_eq!(
+            on_events(
+                &mut hover_radio(false),
+                &[
+                    cursor_moved(5.0, 5.0),
+                    cursor_moved(6.0, 6.0),
+                    cursor_moved(7.0, 7.0)
+                ]
+            ),
+            vec![true]
+        );
+
+        assert!(on_events(&mut hover_radio(true), &[cursor_moved(8.0, 8.0)])
+            .is_empty());
+    }
+
+    #[test]
+    fn notifies_when_the_cursor_leaves() {
+        assert_eq!(
+            on_events(
+                &mut hover_radio(false),
+                &[cursor_moved(5.0, 5.0), cursor_moved(300.0, 300.0)]
+            ),
+            vec![true, false]
+        );
+
+        assert_eq!(
+            on_events(&mut hover_radio(true), &[cursor_moved(300.0, 300.0)]),
+            vec![false]
+        );
+
+        assert!(on_events(
+            &mut hover_radio(false),
+            &[cursor_moved(300.0, 300.0)]
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn focused_radio_reports_its_bounds() {
+        let radio = Radio::new(1, "Radio", None, |value| value);
+        let node = layout(&radio, 200.0);
+
+        assert_eq!(
+            Widget::<i32, Null>::focused_bounds(&radio, Layout::new(&node)),
+            None
+        );
+
+        let radio = radio.focused(true);
+
+        assert_eq!(
+            Widget::<i32, Null>::focused_bounds(&radio, Layout::new(&node)),
+            Some(node.bounds())
+        );
+    }
+}
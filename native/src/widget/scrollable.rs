@@ -2,11 +2,14 @@
 use crate::{
     column,
     input::{mouse, ButtonState},
-    layout, Align, Clipboard, Column, Element, Event, Hasher, Layout, Length,
-    Point, Rectangle, Size, Widget,
+    layout, overlay, Align, Clipboard, Column, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Vector, Widget,
 };
 
-use std::{f32, hash::Hash, u32};
+use std::{f32, hash::Hash, time::Instant, u32};
+
+/// The amount of pixels scrolled by a single line of a mouse wheel.
+const LINE_HEIGHT: f32 = 60.0;
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
@@ -15,6 +18,9 @@ pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     height: Length,
     max_height: u32,
+    scroll_speed: f32,
+    invert_scrolling: bool,
+    scrollbar_width: u16,
     content: Column<'a, Message, Renderer>,
     style: Renderer::Style,
 }
@@ -29,6 +35,9 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
             state,
             height: Length::Shrink,
             max_height: u32::MAX,
+            scroll_speed: 1.0,
+            invert_scrolling: false,
+            scrollbar_width: 10,
             content: Column::new(),
             style: Renderer::Style::default(),
         }
@@ -84,6 +93,37 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the multiplier applied to the mouse wheel deltas of the
+    /// [`Scrollable`].
+    ///
+    /// By default, a line of a mouse wheel scrolls 60 pixels and trackpad
+    /// deltas are applied as they are.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_speed(mut self, scroll_speed: f32) -> Self {
+        self.scroll_speed = scroll_speed;
+        self
+    }
+
+    /// Sets whether the direction of the mouse wheel deltas should be
+    /// inverted in the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn invert_scrolling(mut self, invert_scrolling: bool) -> Self {
+        self.invert_scrolling = invert_scrolling;
+        self
+    }
+
+    /// Sets the width of the scrollbar of the [`Scrollable`], in units.
+    ///
+    /// By default, it is 10 units wide.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
+        self.scrollbar_width = scrollbar_width;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -110,6 +150,19 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self.content = self.content.push(child);
         self
     }
+
+    fn scroll_delta(&self, delta_y: f32) -> f32 {
+        let direction = if self.invert_scrolling { -1.0 } else { 1.0 };
+
+        delta_y * self.scroll_speed * direction
+    }
+}
+
+fn wheel_delta(delta: mouse::ScrollDelta) -> f32 {
+    match delta {
+        mouse::ScrollDelta::Lines { y, .. } => y * LINE_HEIGHT,
+        mouse::ScrollDelta::Pixels { y, .. } => y,
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -161,18 +214,44 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        // TODO: Event capture. Nested scrollables should capture scroll events.
+        let offset = self.state.offset(bounds, content_bounds);
+        let scrollbar = renderer.scrollbar(
+            bounds,
+            content_bounds,
+            offset,
+            self.scrollbar_width,
+        );
+        let is_mouse_over_scrollbar = scrollbar
+            .as_ref()
+            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+            .unwrap_or(false);
+
         if is_mouse_over {
             match event {
                 Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
+                    let delta_y = wheel_delta(delta);
+
+                    let content_cursor_position = Point::new(
+                        cursor_position.x,
+                        cursor_position.y + offset as f32,
+                    );
+
+                    // Nested scrollables take the wheel events until they
+                    // reach one of their ends.
+                    let is_nested_scrollable_scrolling =
+                        !is_mouse_over_scrollbar
+                            && self.content.can_scroll(
+                                content,
+                                content_cursor_position,
+                                delta_y,
+                            );
+
+                    if !is_nested_scrollable_scrolling {
+                        self.state.scroll(
+                            self.scroll_delta(delta_y),
+                            bounds,
+                            content_bounds,
+                        );
                     }
                 }
                 _ => {}
@@ -180,17 +259,19 @@ where
         }
 
         let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let scrollbar = renderer.scrollbar(
+            bounds,
+            content_bounds,
+            offset,
+            self.scrollbar_width,
+        );
 
         if self.state.is_scroller_grabbed() {
             match event {
                 Event::Mouse(mouse::Event::Input {
                     button: mouse::Button::Left,
                     state: ButtonState::Released,
+                    ..
                 }) => {
                     self.state.scroller_grabbed_at = None;
                 }
@@ -215,6 +296,7 @@ where
                 Event::Mouse(mouse::Event::Input {
                     button: mouse::Button::Left,
                     state: ButtonState::Pressed,
+                    ..
                 }) => {
                     if let Some(scrollbar) = scrollbar {
                         if let Some(scroller_grabbed_at) =
@@ -259,7 +341,79 @@ where
             messages,
             renderer,
             clipboard,
-        )
+        );
+
+        let focused_bounds = self.content.focused_bounds(content);
+
+        if focused_bounds != self.state.focused_bounds {
+            if let Some(focused_bounds) = focused_bounds {
+                self.state.scroll_into_view(
+                    focused_bounds,
+                    bounds,
+                    content_bounds,
+                );
+            }
+
+            self.state.focused_bounds = focused_bounds;
+        }
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        let bounds = layout.bounds();
+
+        if !bounds.contains(cursor_position) {
+            return false;
+        }
+
+        let content = layout.children().next().unwrap();
+        let content_bounds = content.bounds();
+        let offset = self.state.offset(bounds, content_bounds);
+
+        let content_cursor_position =
+            Point::new(cursor_position.x, cursor_position.y + offset as f32);
+
+        self.content
+            .can_scroll(content, content_cursor_position, delta_y)
+            || self.state.can_scroll(
+                self.scroll_delta(delta_y),
+                bounds,
+                content_bounds,
+            )
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let offset = self.state.offset(bounds, content.bounds());
+
+        self.content
+            .focused_bounds(content)
+            .map(|focused_bounds| Rectangle {
+                y: focused_bounds.y - offset as f32,
+                ..focused_bounds
+            })
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let offset = self.state.offset(bounds, content.bounds());
+
+        self.content.overlay(content).map(|overlay| {
+            overlay.translate(Vector::new(0.0, -(offset as f32)))
+        })
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.content.redraw_at()
     }
 
     fn draw(
@@ -273,7 +427,12 @@ where
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
         let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let scrollbar = renderer.scrollbar(
+            bounds,
+            content_bounds,
+            offset,
+            self.scrollbar_width,
+        );
 
         let is_mouse_over = bounds.contains(cursor_position);
         let is_mouse_over_scrollbar = scrollbar
@@ -327,6 +486,7 @@ where
 pub struct State {
     scroller_grabbed_at: Option<f32>,
     offset: f32,
+    focused_bounds: Option<Rectangle>,
 }
 
 impl State {
@@ -371,8 +531,60 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        self.offset =
-            ((content_bounds.height - bounds.height) * percentage).max(0.0);
+        self.offset = ((content_bounds.height - bounds.height)
+            * percentage.max(0.0).min(1.0))
+        .max(0.0);
+    }
+
+    /// Returns whether the [`State`] can still scroll by the given delta,
+    /// given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// It is `false` when the offset is already at the end the delta points
+    /// towards.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn can_scroll(
+        &self,
+        delta_y: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        let max_offset = (content_bounds.height - bounds.height).max(0.0);
+        let offset = self.offset.min(max_offset);
+
+        if delta_y > 0.0 {
+            offset > 0.0
+        } else if delta_y < 0.0 {
+            offset < max_offset
+        } else {
+            false
+        }
+    }
+
+    /// Scrolls the minimum amount needed to make the given `target` visible,
+    /// given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// The `target` should be contained in the bounds of the contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_into_view(
+        &mut self,
+        target: Rectangle,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let offset = self.offset(bounds, content_bounds) as f32;
+        let top = target.y - content_bounds.y;
+        let bottom = top + target.height;
+
+        if top < offset {
+            self.offset = top.max(0.0);
+        } else if bottom > offset + bounds.height {
+            self.offset = (bottom - bounds.height)
+                .min(top)
+                .min((content_bounds.height - bounds.height).max(0.0));
+        }
     }
 
     /// Returns the current scrolling offset of the [`State`], given the bounds
@@ -464,6 +676,12 @@ pub trait Renderer: crate::Renderer + Sized {
     /// Returns the [`Scrollbar`] given the bounds and content bounds of a
     /// [`Scrollable`].
     ///
+    /// It receives:
+    ///   * the bounds of the [`Scrollable`] widget
+    ///   * the bounds of the [`Scrollable`] content
+    ///   * the scrolling offset
+    ///   * the width of the [`Scrollbar`]
+    ///
     /// [`Scrollbar`]: struct.Scrollbar.html
     /// [`Scrollable`]: struct.Scrollable.html
     fn scrollbar(
@@ -471,6 +689,7 @@ pub trait Renderer: crate::Renderer + Sized {
         bounds: Rectangle,
         content_bounds: Rectangle,
         offset: u32,
+        scrollbar_width: u16,
     ) -> Option<Scrollbar>;
 
     /// Draws the [`Scrollable`].
@@ -514,3 +733,100 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Text};
+
+    fn wheel(
+        scrollable: &mut Scrollable<'_, (), Null>,
+        lines: &[f32],
+        cursor_position: Point,
+    ) {
+        let node = Widget::<(), Null>::layout(
+            scrollable,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 500.0)),
+        );
+
+        for y in lines {
+            scrollable.on_event(
+                Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: mouse::ScrollDelta::Lines { x: 0.0, y: *y },
+                }),
+                Layout::new(&node),
+                cursor_position,
+                &mut Vec::new(),
+                &Null::new(),
+                None,
+            );
+        }
+    }
+
+    fn tall_content(height: u16) -> Text {
+        Text::new("Content").height(Length::Units(height))
+    }
+
+    #[test]
+    fn wheel_scrolls_the_content() {
+        let mut state = State::new();
+
+        wheel(
+            &mut Scrollable::new(&mut state)
+                .height(Length::Units(100))
+                .push(tall_content(300)),
+            &[-1.0],
+            Point::new(50.0, 50.0),
+        );
+
+        assert_eq!(state.offset, 60.0);
+    }
+
+    #[test]
+    fn wheel_is_clamped_to_the_content() {
+        let mut state = State::new();
+
+        wheel(
+            &mut Scrollable::new(&mut state)
+                .height(Length::Units(100))
+                .push(tall_content(300)),
+            &[-1.0, -1.0, -1.0, -1.0],
+            Point::new(50.0, 50.0),
+        );
+
+        assert_eq!(state.offset, 200.0);
+
+        wheel(
+            &mut Scrollable::new(&mut state)
+                .height(Length::Units(100))
+                .push(tall_content(300)),
+            &[10.0],
+            Point::new(50.0, 50.0),
+        );
+
+        assert_eq!(state.offset, 0.0);
+    }
+
+    #[test]
+    fn nested_scrollable_takes_the_wheel_until_its_end() {
+        let mut outer = State::new();
+        let mut inner = State::new();
+
+        wheel(
+            &mut Scrollable::new(&mut outer)
+                .height(Length::Units(100))
+                .push(
+                    Scrollable::new(&mut inner)
+                        .height(Length::Units(100))
+                        .push(tall_content(200)),
+                )
+                .push(tall_content(200)),
+            &[-1.0, -1.0, -1.0],
+            Point::new(50.0, 50.0),
+        );
+
+        assert_eq!(inner.offset, 100.0);
+        assert_eq!(outer.offset, 60.0);
+    }
+}
@@ -40,6 +40,7 @@ pub struct Slider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     range: RangeInclusive<f32>,
     value: f32,
+    step: Option<f32>,
     on_change: Box<dyn Fn(f32) -> Message>,
     width: Length,
     style: Renderer::Style,
@@ -71,6 +72,7 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
             state,
             value: value.max(*range.start()).min(*range.end()),
             range,
+            step: None,
             on_change: Box::new(on_change),
             width: Length::Fill,
             style: Renderer::Style::default(),
@@ -85,6 +87,18 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the step size of the [`Slider`].
+    ///
+    /// The values produced while dragging will be snapped to the closest
+    /// multiple of the step, counting from the start of the range. By default,
+    /// any value in the range can be produced.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -94,12 +108,36 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
     }
 }
 
+impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
+    /// Returns the value at `x`, where `start` and `end` are the positions of
+    /// the start and the end of the range.
+    fn value_at(&self, x: f32, start: f32, end: f32) -> f32 {
+        let (min, max) = (*self.range.start(), *self.range.end());
+
+        let percent = if end > start {
+            ((x - start) / (end - start)).max(0.0).min(1.0)
+        } else {
+            0.0
+        };
+
+        let value = min + (max - min) * percent;
+
+        match self.step {
+            Some(step) if step > 0.0 => {
+                (min + ((value - min) / step).round() * step).min(max)
+            }
+            _ => value,
+        }
+    }
+}
+
 /// The local state of a [`Slider`].
 ///
 /// [`Slider`]: struct.Slider.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    grab_offset: Option<i32>,
 }
 
 impl State {
@@ -144,33 +182,50 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
-        let mut change = || {
-            let bounds = layout.bounds();
-
-            if cursor_position.x <= bounds.x {
-                messages.push((self.on_change)(*self.range.start()));
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                messages.push((self.on_change)(*self.range.end()));
-            } else {
-                let percent = (cursor_position.x - bounds.x) / bounds.width;
-                let value = (self.range.end() - self.range.start()) * percent
-                    + self.range.start();
-
-                messages.push((self.on_change)(value));
-            }
+        let bounds = layout.bounds();
+
+        let (range, style) = (&self.range, &self.style);
+
+        let handle_bounds = |value, is_dragging| {
+            renderer.handle_bounds(
+                bounds,
+                range.clone(),
+                value,
+                is_dragging,
+                style,
+            )
         };
 
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state,
+                ..
             }) => match state {
                 ButtonState::Pressed => {
-                    if layout.bounds().contains(cursor_position) {
-                        change();
+                    if bounds.contains(cursor_position) {
+                        let handle = handle_bounds(self.value, false);
+
+                        // Grabbing the handle keeps the value where it is,
+                        // while clicking the track jumps to the cursor.
+                        if handle.contains(cursor_position) {
+                            let grab_offset = cursor_position.x - handle.x;
+
+                            self.state.grab_offset =
+                                Some(grab_offset.round() as i32);
+                        } else {
+                            self.state.grab_offset = None;
+
+                            messages.push((self.on_change)(self.value_at(
+                                cursor_position.x,
+                                bounds.x,
+                                bounds.x + bounds.width,
+                            )));
+                        }
+
                         self.state.is_dragging = true;
                     }
                 }
@@ -180,7 +235,25 @@ where
             },
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if self.state.is_dragging {
-                    change();
+                    let value = match self.state.grab_offset {
+                        Some(grab_offset) => {
+                            let start = handle_bounds(*range.start(), true);
+                            let end = handle_bounds(*range.end(), true);
+
+                            self.value_at(
+                                cursor_position.x - grab_offset as f32,
+                                start.x,
+                                end.x,
+                            )
+                        }
+                        None => self.value_at(
+                            cursor_position.x,
+                            bounds.x,
+                            bounds.x + bounds.width,
+                        ),
+                    };
+
+                    messages.push((self.on_change)(value));
                 }
             }
             _ => {}
@@ -225,6 +298,22 @@ pub trait Renderer: crate::Renderer {
     /// [`Slider`]: struct.Slider.html
     fn height(&self) -> u32;
 
+    /// Returns the bounds of the handle of a [`Slider`], as drawn by
+    /// [`draw`].
+    ///
+    /// It is used to tell whether the handle or the rail is pressed.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`draw`]: #tymethod.draw
+    fn handle_bounds(
+        &self,
+        bounds: Rectangle,
+        range: RangeInclusive<f32>,
+        value: f32,
+        is_dragging: bool,
+        style: &Self::Style,
+    ) -> Rectangle;
+
     /// Draws a [`Slider`].
     ///
     /// It receives:
@@ -260,3 +349,94 @@ where
         Element::new(slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::keyboard, renderer::Null};
+
+    fn on_events(
+        slider: &mut Slider<'_, f32, Null>,
+        events: &[(Event, Point)],
+    ) -> Vec<f32> {
+        let node = Widget::<f32, Null>::layout(
+            slider,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut messages = Vec::new();
+
+        for (event, cursor_position) in events {
+            slider.on_event(
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                &mut messages,
+                &Null::new(),
+                None,
+            );
+        }
+
+        messages
+    }
+
+    fn press(x: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                button: mouse::Button::Left,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(x, 15.0),
+        )
+    }
+
+    fn move_to(x: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::CursorMoved { x, y: 15.0 }),
+            Point::new(x, 15.0),
+        )
+    }
+
+    #[test]
+    fn clicking_the_track_maps_the_cursor_to_a_value() {
+        let mut state = State::new();
+        let mut slider = Slider::new(&mut state, 0.0..=100.0, 0.0, |v| v);
+
+        assert_eq!(on_events(&mut slider, &[press(100.0)]), vec![50.0]);
+        assert_eq!(on_events(&mut slider, &[move_to(150.0)]), vec![75.0]);
+    }
+
+    #[test]
+    fn dragging_past_the_ends_clamps_to_the_range() {
+        let mut state = State::new();
+        let mut slider = Slider::new(&mut state, 0.0..=100.0, 0.0, |v| v);
+
+        assert_eq!(
+            on_events(
+                &mut slider,
+                &[press(150.0), move_to(400.0), move_to(-50.0)]
+            ),
+            vec![75.0, 100.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn values_snap_to_the_step() {
+        let mut state = State::new();
+        let mut slider =
+            Slider::new(&mut state, 0.0..=100.0, 0.0, |v| v).step(10.0);
+
+        assert_eq!(on_events(&mut slider, &[press(113.0)]), vec![60.0]);
+    }
+
+    #[test]
+    fn grabbing_the_handle_keeps_the_value() {
+        let mut state = State::new();
+        let mut slider = Slider::new(&mut state, 0.0..=100.0, 0.0, |v| v);
+
+        // The handle is 30 units wide, so it travels 170 units
+        assert_eq!(on_events(&mut slider, &[press(10.0)]), vec![]);
+        assert_eq!(on_events(&mut slider, &[move_to(95.0)]), vec![50.0]);
+    }
+}
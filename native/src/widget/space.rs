@@ -1,4 +1,4 @@
-//! Distribute content vertically.
+//! Add some explicit spacing between elements.
 use std::hash::Hash;
 
 use crate::{
@@ -103,3 +103,29 @@ where
         Element::new(space)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Row, Text};
+
+    #[test]
+    fn filling_space_consumes_the_remaining_width() {
+        let row: Row<'_, (), Null> = Row::new()
+            .width(Length::Fill)
+            .push(Text::new("AB"))
+            .push(Space::with_width(Length::Fill))
+            .push(Text::new("CD"));
+
+        let node = Widget::<(), Null>::layout(
+            &row,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(300.0, 200.0)),
+        );
+
+        let children = node.children();
+
+        assert_eq!(children[1].size().width, 260.0);
+        assert_eq!(children[2].bounds().x, 280.0);
+    }
+}
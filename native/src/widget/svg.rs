@@ -1,9 +1,14 @@
 //! Display vector graphics in your application.
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+use crate::{
+    layout, Color, Element, Hasher, Layout, Length, Point, Size, Widget,
+};
+
+pub use crate::ContentFit;
 
 use std::{
-    hash::Hash,
-    path::{Path, PathBuf},
+    hash::{Hash, Hasher as _},
+    path::PathBuf,
+    sync::{Arc, Mutex},
 };
 
 /// A vector graphics image.
@@ -13,12 +18,22 @@ use std::{
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
 ///
+/// A dimension set with [`width`] or [`height`] is kept as requested, while a
+/// `Shrink` one follows the aspect ratio of the image. When the bounds of the
+/// [`Svg`] do not match its aspect ratio, its [`ContentFit`] decides how the
+/// image is scaled into them.
+///
 /// [`Svg`]: struct.Svg.html
+/// [`ContentFit`]: enum.ContentFit.html
+/// [`width`]: #method.width
+/// [`height`]: #method.height
 #[derive(Debug, Clone)]
 pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    color: Option<Color>,
 }
 
 impl Svg {
@@ -31,6 +46,8 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            color: None,
         }
     }
 
@@ -49,6 +66,30 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Svg`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    ///
+    /// [`ContentFit`]: enum.ContentFit.html
+    /// [`ContentFit::Contain`]: enum.ContentFit.html#variant.Contain
+    /// [`Svg`]: struct.Svg.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the tint [`Color`] of the [`Svg`].
+    ///
+    /// Every pixel of the [`Svg`] is multiplied by the tint, which is useful
+    /// to reuse monochrome icons with different colors.
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Svg`]: struct.Svg.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -69,20 +110,38 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
-
-        let aspect_ratio = width as f32 / height as f32;
+        let intrinsic_size = Size::new(width as f32, height as f32);
 
         let mut size = limits
             .width(self.width)
             .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
+            .resolve(intrinsic_size);
 
-        let viewport_aspect_ratio = size.width / size.height;
+        if width == 0 || height == 0 {
+            return layout::Node::new(size);
+        }
+
+        let aspect_ratio = intrinsic_size.width / intrinsic_size.height;
 
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
+        // Only the dimensions that follow the image are adjusted, explicit
+        // ones are kept as requested and the content is fitted into them
+        match (self.width, self.height) {
+            (Length::Shrink, Length::Shrink) => {
+                let viewport_aspect_ratio = size.width / size.height;
+
+                if viewport_aspect_ratio > aspect_ratio {
+                    size.width = size.height * aspect_ratio;
+                } else {
+                    size.height = size.width / aspect_ratio;
+                }
+            }
+            (Length::Shrink, _) => {
+                size.width = (size.height * aspect_ratio).min(size.width);
+            }
+            (_, Length::Shrink) => {
+                size.height = (size.width / aspect_ratio).min(size.height);
+            }
+            _ => {}
         }
 
         layout::Node::new(size)
@@ -95,22 +154,36 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        renderer.draw(self.handle.clone(), self.content_fit, self.color, layout)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
 /// An [`Svg`] handle.
 ///
+/// A [`Handle`] and its clones share the loading [`Status`] reported by the
+/// renderer. Keep it in your application state to find out whether its
+/// [`Svg`] is ready to be drawn, and show a placeholder otherwise.
+///
 /// [`Svg`]: struct.Svg.html
+/// [`Handle`]: struct.Handle.html
+/// [`Status`]: enum.Status.html
 #[derive(Debug, Clone)]
 pub struct Handle {
     id: u64,
-    path: PathBuf,
+    data: Arc<Data>,
+    report: Arc<Mutex<Report>>,
+}
+
+#[derive(Debug)]
+struct Report {
+    status: Status,
+    error: Option<String>,
 }
 
 impl Handle {
@@ -119,16 +192,30 @@ impl Handle {
     ///
     /// [`Handle`]: struct.Handle.html
     pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
-        use std::hash::Hasher as _;
+        Self::from_data(Data::Path(path.into()))
+    }
 
-        let path = path.into();
+    /// Creates an SVG [`Handle`] from its raw bytes.
+    ///
+    /// This is useful if you want to embed your SVGs in your binary with
+    /// `include_bytes!`, or if you generate them procedurally.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_memory(bytes: Vec<u8>) -> Handle {
+        Self::from_data(Data::Bytes(bytes))
+    }
 
+    fn from_data(data: Data) -> Handle {
         let mut hasher = Hasher::default();
-        path.hash(&mut hasher);
+        data.hash(&mut hasher);
 
         Handle {
             id: hasher.finish(),
-            path,
+            data: Arc::new(data),
+            report: Arc::new(Mutex::new(Report {
+                status: Status::Loading,
+                error: None,
+            })),
         }
     }
 
@@ -139,12 +226,71 @@ impl Handle {
         self.id
     }
 
-    /// Returns a reference to the path of the [`Handle`].
+    /// Returns a reference to the SVG [`Data`].
     ///
-    /// [`Handle`]: enum.Handle.html
-    pub fn path(&self) -> &Path {
-        &self.path
+    /// [`Data`]: enum.Data.html
+    pub fn data(&self) -> &Data {
+        &self.data
     }
+
+    /// Returns the loading [`Status`] of the [`Handle`], as last reported by
+    /// the renderer.
+    ///
+    /// It is [`Status::Loading`] until the [`Handle`] is drawn for the first
+    /// time.
+    ///
+    /// [`Status`]: enum.Status.html
+    /// [`Status::Loading`]: enum.Status.html#variant.Loading
+    /// [`Handle`]: struct.Handle.html
+    pub fn status(&self) -> Status {
+        self.report
+            .lock()
+            .map(|report| report.status)
+            .unwrap_or(Status::Loading)
+    }
+
+    /// Returns the error that made the SVG of the [`Handle`] fail to load,
+    /// if any.
+    ///
+    /// An SVG that fails to load is drawn as nothing. This method lets you
+    /// find out why.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn error(&self) -> Option<String> {
+        self.report
+            .lock()
+            .ok()
+            .and_then(|report| report.error.clone())
+    }
+
+    /// Reports the loading [`Status`] of the [`Handle`] and the error that
+    /// made it fail to load, if any.
+    ///
+    /// Renderers should call this every time the [`Status`] changes.
+    ///
+    /// [`Status`]: enum.Status.html
+    /// [`Handle`]: struct.Handle.html
+    pub fn report(&self, status: Status, error: Option<String>) {
+        if let Ok(mut report) = self.report.lock() {
+            report.status = status;
+            report.error = error;
+        }
+    }
+}
+
+/// The loading status of the SVG of a [`Handle`].
+///
+/// [`Handle`]: struct.Handle.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The SVG is not ready to be drawn yet.
+    Loading,
+
+    /// The SVG is ready to be drawn.
+    Loaded,
+
+    /// The SVG could not be found or parsed.
+    NotFound,
 }
 
 impl From<String> for Handle {
@@ -159,6 +305,27 @@ impl From<&str> for Handle {
     }
 }
 
+/// The data of an [`Svg`].
+///
+/// [`Svg`]: struct.Svg.html
+#[derive(Clone, Hash)]
+pub enum Data {
+    /// File data
+    Path(PathBuf),
+
+    /// In-memory data
+    Bytes(Vec<u8>),
+}
+
+impl std::fmt::Debug for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Data::Path(path) => write!(f, "Path({:?})", path),
+            Data::Bytes(_) => write!(f, "Bytes(...)"),
+        }
+    }
+}
+
 /// The renderer of an [`Svg`].
 ///
 /// Your [renderer] will need to implement this trait before being able to use
@@ -174,8 +341,24 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws an [`Svg`].
     ///
+    /// It receives:
+    ///   * the [`Handle`] of the [`Svg`]
+    ///   * the [`ContentFit`] of the [`Svg`]
+    ///   * the tint [`Color`] of the [`Svg`], if any
+    ///   * the [`Layout`] of the [`Svg`]
+    ///
     /// [`Svg`]: struct.Svg.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// [`Handle`]: struct.Handle.html
+    /// [`ContentFit`]: enum.ContentFit.html
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        content_fit: ContentFit,
+        color: Option<Color>,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Svg> for Element<'a, Message, Renderer>
@@ -1,10 +1,19 @@
 //! Write some text for your users to read.
+//!
+//! A [`Text`] can be made selectable with some local [`State`].
+//!
+//! [`Text`]: struct.Text.html
+//! [`State`]: struct.State.html
 use crate::{
-    layout, Color, Element, Font, Hasher, HorizontalAlignment, Layout, Length,
-    Point, Rectangle, Size, VerticalAlignment, Widget,
+    input::{keyboard, mouse, ButtonState},
+    layout, Clipboard, Color, Element, Event, Font, Hasher,
+    HorizontalAlignment, Layout, Length, Point, Rectangle, Size,
+    VerticalAlignment, Widget,
 };
 
-use std::hash::Hash;
+use std::{borrow::Cow, hash::Hash};
+
+const ELLIPSIS: &str = "…";
 
 /// A paragraph of text.
 ///
@@ -25,10 +34,12 @@ pub struct Text {
     size: Option<u16>,
     color: Option<Color>,
     font: Font,
+    letter_spacing: Option<f32>,
     width: Length,
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    truncate: bool,
 }
 
 impl Text {
@@ -41,10 +52,12 @@ impl Text {
             size: None,
             color: None,
             font: Font::Default,
+            letter_spacing: None,
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            truncate: false,
         }
     }
 
@@ -74,6 +87,17 @@ impl Text {
         self
     }
 
+    /// Sets the letter spacing of the [`Text`], in pixels.
+    ///
+    /// The spacing is added between every pair of characters in a line. It
+    /// can be negative to tighten the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = Some(letter_spacing);
+        self
+    }
+
     /// Sets the width of the [`Text`] boundaries.
     ///
     /// [`Text`]: struct.Text.html
@@ -110,6 +134,31 @@ impl Text {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets whether the [`Text`] should be truncated with an ellipsis when it
+    /// does not fit its boundaries.
+    ///
+    /// A truncated [`Text`] is laid out in a single line. An ellipsis is
+    /// always shown when the content overflows, even if no character fits.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Makes the [`Text`] selectable with the given [`State`].
+    ///
+    /// A selectable [`Text`] can be selected by dragging the mouse over it, and
+    /// its selection can be copied to the [`Clipboard`] with Ctrl+C (Cmd+C on
+    /// macOS).
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`State`]: struct.State.html
+    /// [`Clipboard`]: ../../trait.Clipboard.html
+    pub fn selectable(self, state: &mut State) -> Selectable<'_> {
+        Selectable { text: self, state }
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text
@@ -133,14 +182,32 @@ where
 
         let size = self.size.unwrap_or(renderer.default_size());
 
-        let bounds = limits.max();
+        let bounds = if self.truncate {
+            Size::new(f32::INFINITY, limits.max().height)
+        } else {
+            limits.max()
+        };
+
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font,
+            self.letter_spacing.unwrap_or(0.0),
+            bounds,
+        );
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+        let content = Size::new(width, height);
+        let size = limits.resolve(content);
 
-        let size = limits.resolve(Size::new(width, height));
+        // Truncated text is shortened to fit when drawn
+        let content = if self.truncate {
+            Size::new(width.min(size.width), height)
+        } else {
+            content
+        };
 
         layout::Node::new(size)
+            .with_overflow(layout::Overflow::between(content, size))
     }
 
     fn draw(
@@ -150,12 +217,30 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or(renderer.default_size());
+        let letter_spacing = self.letter_spacing.unwrap_or(0.0);
+
+        let content = if self.truncate {
+            truncated(
+                renderer,
+                &self.content,
+                size,
+                self.font,
+                letter_spacing,
+                bounds.width,
+            )
+        } else {
+            Cow::Borrowed(self.content.as_str())
+        };
+
         renderer.draw(
             defaults,
-            layout.bounds(),
-            &self.content,
-            self.size.unwrap_or(renderer.default_size()),
+            bounds,
+            &content,
+            size,
             self.font,
+            letter_spacing,
             self.color,
             self.horizontal_alignment,
             self.vertical_alignment,
@@ -165,11 +250,402 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.content.hash(state);
         self.size.hash(state);
+        self.letter_spacing.map(f32::to_bits).hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.truncate.hash(state);
+    }
+}
+
+/// A [`Text`] that can be selected and copied.
+///
+/// It is created with [`Text::selectable`].
+///
+/// [`Text`]: struct.Text.html
+/// [`Text::selectable`]: struct.Text.html#method.selectable
+#[derive(Debug)]
+pub struct Selectable<'a> {
+    text: Text,
+    state: &'a mut State,
+}
+
+/// The local state of a selectable [`Text`].
+///
+/// [`Text`]: struct.Text.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    anchor: Option<usize>,
+    cursor: usize,
+    is_dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], without any selection.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the range of characters currently selected, if any.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.anchor?;
+
+        if anchor == self.cursor {
+            None
+        } else {
+            Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+        }
+    }
+
+    /// Clears the selection of the [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn clear(&mut self) {
+        self.anchor = None;
+        self.is_dragging = false;
+    }
+}
+
+impl<'a> Selectable<'a> {
+    fn lines(&self) -> Vec<&str> {
+        self.text.content.split('\n').collect()
+    }
+
+    fn width_of<Renderer>(
+        &self,
+        renderer: &Renderer,
+        line: &str,
+        characters: usize,
+    ) -> f32
+    where
+        Renderer: self::Renderer,
+    {
+        let end = line
+            .char_indices()
+            .nth(characters)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len());
+
+        let (width, _) = renderer.measure(
+            &line[..end],
+            self.text.size.unwrap_or(renderer.default_size()),
+            self.text.font,
+            self.text.letter_spacing.unwrap_or(0.0),
+            Size::INFINITY,
+        );
+
+        width
+    }
+
+    fn line_left<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        line: &str,
+    ) -> f32
+    where
+        Renderer: self::Renderer,
+    {
+        let width = self.width_of(renderer, line, line.chars().count());
+
+        match self.text.horizontal_alignment {
+            HorizontalAlignment::Left => bounds.x,
+            HorizontalAlignment::Center => {
+                bounds.x + (bounds.width - width) / 2.0
+            }
+            HorizontalAlignment::Right => bounds.x + bounds.width - width,
+        }
+    }
+
+    // Returns the top of the first line and the height of every line
+    fn line_metrics<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        lines: usize,
+    ) -> (f32, f32)
+    where
+        Renderer: self::Renderer,
+    {
+        let (_, height) = renderer.measure(
+            &self.text.content,
+            self.text.size.unwrap_or(renderer.default_size()),
+            self.text.font,
+            self.text.letter_spacing.unwrap_or(0.0),
+            Size::INFINITY,
+        );
+
+        let top = match self.text.vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => {
+                bounds.y + (bounds.height - height) / 2.0
+            }
+            VerticalAlignment::Bottom => bounds.y + bounds.height - height,
+        };
+
+        (top, height / lines.max(1) as f32)
+    }
+
+    // Returns the index of the character boundary closest to the given point
+    fn hit<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        point: Point,
+    ) -> usize
+    where
+        Renderer: self::Renderer,
+    {
+        let lines = self.lines();
+        let (top, line_height) =
+            self.line_metrics(renderer, bounds, lines.len());
+
+        let line_index = if line_height > 0.0 {
+            ((point.y - top) / line_height).max(0.0) as usize
+        } else {
+            0
+        }
+        .min(lines.len() - 1);
+
+        let offset: usize = lines[..line_index]
+            .iter()
+            .map(|line| line.chars().count() + 1)
+            .sum();
+
+        let line = lines[line_index];
+        let characters = line.chars().count();
+        let x = point.x - self.line_left(renderer, bounds, line);
+
+        let mut previous = 0.0;
+
+        for i in 0..characters {
+            let next = self.width_of(renderer, line, i + 1);
+
+            if x < (previous + next) / 2.0 {
+                return offset + i;
+            }
+
+            previous = next;
+        }
+
+        offset + characters
+    }
+
+    fn selection_bounds<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        (start, end): (usize, usize),
+    ) -> Vec<Rectangle>
+    where
+        Renderer: self::Renderer,
+    {
+        let lines = self.lines();
+        let (top, line_height) =
+            self.line_metrics(renderer, bounds, lines.len());
+
+        let mut offset = 0;
+        let mut selection = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            let characters = line.chars().count();
+            let line_start = start.max(offset);
+            let line_end = end.min(offset + characters);
+
+            if line_start < line_end {
+                let left = self.line_left(renderer, bounds, line);
+                let x_start =
+                    self.width_of(renderer, line, line_start - offset);
+                let x_end = self.width_of(renderer, line, line_end - offset);
+
+                selection.push(Rectangle {
+                    x: left + x_start,
+                    y: top + i as f32 * line_height,
+                    width: x_end - x_start,
+                    height: line_height,
+                });
+            }
+
+            offset += characters + 1;
+        }
+
+        selection
     }
 }
 
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Selectable<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.text.width
+    }
+
+    fn height(&self) -> Length {
+        self.text.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::<Message, Renderer>::layout(&self.text, renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if bounds.contains(cursor_position) {
+                    let index = self.hit(renderer, bounds, cursor_position);
+
+                    self.state.anchor = Some(index);
+                    self.state.cursor = index;
+                    self.state.is_dragging = true;
+                } else {
+                    self.state.clear();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.state.is_dragging =>
+            {
+                self.state.cursor =
+                    self.hit(renderer, bounds, cursor_position);
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+                ..
+            }) => {
+                self.state.is_dragging = false;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::C,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) if super::text_input::platform::is_copy_paste_modifier_pressed(
+                modifiers,
+            ) =>
+            {
+                if let (Some((start, end)), Some(clipboard)) =
+                    (self.state.selection(), clipboard)
+                {
+                    let _ = clipboard.set_content(
+                        self.text
+                            .content
+                            .chars()
+                            .skip(start)
+                            .take(end - start)
+                            .collect(),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let selection = self
+            .state
+            .selection()
+            .map(|selection| self.selection_bounds(renderer, bounds, selection))
+            .unwrap_or_default();
+
+        let text = Widget::<Message, Renderer>::draw(
+            &self.text,
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+        );
+
+        renderer.draw_selection(
+            &selection,
+            bounds.contains(cursor_position),
+            text,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        Widget::<Message, Renderer>::hash_layout(&self.text, state)
+    }
+}
+
+fn truncated<'a, Renderer>(
+    renderer: &Renderer,
+    content: &'a str,
+    size: u16,
+    font: Font,
+    letter_spacing: f32,
+    width: f32,
+) -> Cow<'a, str>
+where
+    Renderer: self::Renderer,
+{
+    let fits = |text: &str| {
+        let (text_width, _) =
+            renderer.measure(text, size, font, letter_spacing, Size::INFINITY);
+
+        text_width <= width
+    };
+
+    if fits(content) {
+        return Cow::Borrowed(content);
+    }
+
+    let boundaries: Vec<usize> =
+        content.char_indices().map(|(i, _)| i).collect();
+
+    let with_ellipsis = |characters: usize| {
+        format!(
+            "{}{}",
+            content[..boundaries[characters]].trim_end(),
+            ELLIPSIS
+        )
+    };
+
+    // Find the longest prefix that fits along with the ellipsis
+    let mut low = 0;
+    let mut high = boundaries.len().saturating_sub(1);
+
+    while low < high {
+        let middle = (low + high + 1) / 2;
+
+        if fits(&with_ellipsis(middle)) {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    Cow::Owned(with_ellipsis(low))
+}
+
 /// The renderer of a [`Text`] fragment.
 ///
 /// Your [renderer] will need to implement this trait before being
@@ -187,12 +663,16 @@ pub trait Renderer: crate::Renderer {
     /// Measures the [`Text`] in the given bounds and returns the minimum
     /// boundaries that can fit the contents.
     ///
+    /// The `letter_spacing` is added between every pair of characters in a
+    /// line.
+    ///
     /// [`Text`]: struct.Text.html
     fn measure(
         &self,
         content: &str,
         size: u16,
         font: Font,
+        letter_spacing: f32,
         bounds: Size,
     ) -> (f32, f32);
 
@@ -202,6 +682,8 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Text`]
     ///   * the contents of the [`Text`]
     ///   * the size of the [`Text`]
+    ///   * the font of the [`Text`]
+    ///   * the letter spacing of the [`Text`]
     ///   * the color of the [`Text`]
     ///   * the [`HorizontalAlignment`] of the [`Text`]
     ///   * the [`VerticalAlignment`] of the [`Text`]
@@ -216,10 +698,30 @@ pub trait Renderer: crate::Renderer {
         content: &str,
         size: u16,
         font: Font,
+        letter_spacing: f32,
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     ) -> Self::Output;
+
+    /// Draws the selection of a selectable [`Text`] behind its output.
+    ///
+    /// It receives:
+    ///   * the bounds of every selected region of the [`Text`]
+    ///   * whether the mouse is over the [`Text`] or not
+    ///   * the output of the [`Text`]
+    ///
+    /// By default, the selection is not drawn.
+    ///
+    /// [`Text`]: struct.Text.html
+    fn draw_selection(
+        &mut self,
+        _selection: &[Rectangle],
+        _is_mouse_over: bool,
+        text: Self::Output,
+    ) -> Self::Output {
+        text
+    }
 }
 
 impl<'a, Message, Renderer> From<Text> for Element<'a, Message, Renderer>
@@ -230,3 +732,309 @@ where
         Element::new(text)
     }
 }
+
+impl<'a, Message, Renderer> From<Selectable<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(selectable: Selectable<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(selectable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    /// Measures like the `Null` renderer and records how text is drawn.
+    #[derive(Debug)]
+    struct Recorder;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Drawn {
+        bounds: Rectangle,
+        content: String,
+        color: Option<Color>,
+        vertical_alignment: VerticalAlignment,
+    }
+
+    impl crate::Renderer for Recorder {
+        type Output = Drawn;
+        type Defaults = ();
+
+        fn overlay(
+            &mut self,
+            base: Drawn,
+            _overlay: Drawn,
+            _overlay_bounds: Rectangle,
+        ) -> Drawn {
+            base
+        }
+    }
+
+    impl Renderer for Recorder {
+        fn default_size(&self) -> u16 {
+            Null::new().default_size()
+        }
+
+        fn measure(
+            &self,
+            content: &str,
+            size: u16,
+            font: Font,
+            letter_spacing: f32,
+            bounds: Size,
+        ) -> (f32, f32) {
+            Null::new().measure(content, size, font, letter_spacing, bounds)
+        }
+
+        fn draw(
+            &mut self,
+            _defaults: &(),
+            bounds: Rectangle,
+            content: &str,
+            _size: u16,
+            _font: Font,
+            _letter_spacing: f32,
+            color: Option<Color>,
+            _horizontal_alignment: HorizontalAlignment,
+            vertical_alignment: VerticalAlignment,
+        ) -> Drawn {
+            Drawn {
+                bounds,
+                content: content.to_owned(),
+                color,
+                vertical_alignment,
+            }
+        }
+    }
+
+    fn draw(text: &Text, width: f32) -> Drawn {
+        let node = Widget::<(), Recorder>::layout(
+            text,
+            &Recorder,
+            &layout::Limits::new(Size::ZERO, Size::new(width, 200.0)),
+        );
+
+        Widget::<(), Recorder>::draw(
+            text,
+            &mut Recorder,
+            &(),
+            Layout::new(&node),
+            Point::new(0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn text_is_drawn_with_its_color() {
+        let color = Color::from_rgb(1.0, 0.0, 0.0);
+
+        assert_eq!(
+            draw(&Text::new("00:00").color(color), 200.0).color,
+            Some(color)
+        );
+        assert_eq!(draw(&Text::new("00:00"), 200.0).color, None);
+    }
+
+    #[test]
+    fn color_does_not_affect_the_layout() {
+        use std::hash::Hasher as _;
+
+        let hash = |text: &Text| {
+            let mut hasher = Hasher::default();
+            Widget::<(), Null>::hash_layout(text, &mut hasher);
+
+            hasher.finish()
+        };
+
+        assert_eq!(
+            hash(&Text::new("00:00")),
+            hash(&Text::new("00:00").color(Color::WHITE))
+        );
+    }
+
+    #[test]
+    fn text_is_drawn_with_its_vertical_alignment() {
+        assert_eq!(
+            draw(&Text::new("00:00"), 200.0).vertical_alignment,
+            VerticalAlignment::Top
+        );
+        assert_eq!(
+            draw(
+                &Text::new("00:00")
+                    .height(Length::Fill)
+                    .vertical_alignment(VerticalAlignment::Center),
+                200.0
+            )
+            .vertical_alignment,
+            VerticalAlignment::Center
+        );
+    }
+
+    #[test]
+    fn overflowing_text_is_truncated_with_an_ellipsis() {
+        // Every character is 10 pixels wide, so 6 of them fit along with the
+        // ellipsis
+        let text = Text::new("Hello, world!")
+            .width(Length::Units(75))
+            .truncate(true);
+
+        assert_eq!(draw(&text, 200.0).content, "Hello,…");
+    }
+
+    #[test]
+    fn text_that_fits_is_not_truncated() {
+        let text = Text::new("Hello").truncate(true);
+
+        assert_eq!(draw(&text, 200.0).content, "Hello");
+    }
+
+    #[test]
+    fn very_narrow_text_shows_the_ellipsis() {
+        let text = Text::new("Hello, world!")
+            .width(Length::Units(5))
+            .truncate(true);
+
+        assert_eq!(draw(&text, 200.0).content, "…");
+    }
+
+    #[test]
+    fn positive_letter_spacing_widens_the_text() {
+        let layout = |text: &Text| {
+            Widget::<(), Null>::layout(
+                text,
+                &Null::new(),
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+            )
+        };
+
+        let default = layout(&Text::new("00:00"));
+        let spaced = layout(&Text::new("00:00").letter_spacing(2.0));
+
+        assert_eq!(spaced.size().width, default.size().width + 4.0 * 2.0);
+    }
+
+    /// Stores the content written to it.
+    #[derive(Debug, Default)]
+    struct MockClipboard(std::cell::RefCell<Option<String>>);
+
+    impl Clipboard for MockClipboard {
+        fn content(&self) -> Option<String> {
+            self.0.borrow().clone()
+        }
+
+        fn set_content(&self, content: String) -> bool {
+            *self.0.borrow_mut() = Some(content);
+            true
+        }
+    }
+
+    fn mouse_input(state: ButtonState, x: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::Input {
+                state,
+                button: mouse::Button::Left,
+                modifiers: keyboard::ModifiersState::default(),
+            }),
+            Point::new(x, 10.0),
+        )
+    }
+
+    fn drag(from: f32, to: f32) -> Vec<(Event, Point)> {
+        vec![
+            mouse_input(ButtonState::Pressed, from),
+            (
+                Event::Mouse(mouse::Event::CursorMoved { x: to, y: 10.0 }),
+                Point::new(to, 10.0),
+            ),
+            mouse_input(ButtonState::Released, to),
+        ]
+    }
+
+    fn copy() -> (Event, Point) {
+        (
+            Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code: keyboard::KeyCode::C,
+                modifiers: keyboard::ModifiersState {
+                    control: true,
+                    logo: true,
+                    ..keyboard::ModifiersState::default()
+                },
+            }),
+            Point::new(0.0, 0.0),
+        )
+    }
+
+    fn on_events(
+        selectable: &mut Selectable<'_>,
+        events: &[(Event, Point)],
+        clipboard: &dyn Clipboard,
+    ) {
+        let node = Widget::<(), Null>::layout(
+            selectable,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        for (event, cursor_position) in events {
+            Widget::<(), Null>::on_event(
+                selectable,
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                &mut Vec::new(),
+                &Null::new(),
+                Some(clipboard),
+            );
+        }
+    }
+
+    #[test]
+    fn dragging_selects_a_range() {
+        let mut state = State::new();
+        let clipboard = MockClipboard::default();
+
+        // Every character is 10 pixels wide
+        on_events(
+            &mut Text::new("Hello, world!").selectable(&mut state),
+            &drag(0.0, 50.0),
+            &clipboard,
+        );
+
+        assert_eq!(state.selection(), Some((0, 5)));
+    }
+
+    #[test]
+    fn copying_writes_the_selection_to_the_clipboard() {
+        let mut state = State::new();
+        let clipboard = MockClipboard::default();
+
+        let mut events = drag(70.0, 130.0);
+        events.push(copy());
+
+        on_events(
+            &mut Text::new("Hello, world!").selectable(&mut state),
+            &events,
+            &clipboard,
+        );
+
+        assert_eq!(clipboard.content(), Some(String::from("world!")));
+    }
+
+    #[test]
+    fn copying_without_a_selection_keeps_the_clipboard() {
+        let mut state = State::new();
+        let clipboard = MockClipboard::default();
+
+        on_events(
+            &mut Text::new("Hello, world!").selectable(&mut state),
+            &[copy()],
+            &clipboard,
+        );
+
+        assert_eq!(clipboard.content(), None);
+    }
+}
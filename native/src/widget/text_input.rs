@@ -10,7 +10,10 @@ use crate::{
     Rectangle, Size, Widget,
 };
 
-use std::u32;
+use std::{
+    time::{Duration, Instant},
+    u32,
+};
 use unicode_segmentation::UnicodeSegmentation;
 
 /// A field that can be filled with text.
@@ -50,6 +53,8 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Box<dyn Fn() -> Message>,>,
+    leading: Option<Element<'a, Message, Renderer>>,
+    trailing: Option<Element<'a, Message, Renderer>>,
     style: Renderer::Style,
 }
 
@@ -85,6 +90,8 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
             size: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            leading: None,
+            trailing: None,
             style: Renderer::Style::default(),
         }
     }
@@ -148,6 +155,37 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self
     }
 
+    /// Sets an element to show inside the [`TextInput`], before its value.
+    ///
+    /// This is useful to display an icon, like a magnifier in a search field.
+    /// Space is reserved for the element, so the value never overlaps it.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn leading<E>(mut self, leading: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.leading = Some(leading.into());
+        self
+    }
+
+    /// Sets an element to show inside the [`TextInput`], after its value.
+    ///
+    /// The element receives events and can produce its own messages. For
+    /// instance, it can be a [`Button`] that clears the value or shows a
+    /// password. Space is reserved for the element, so the value never
+    /// overlaps it.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Button`]: ../button/struct.Button.html
+    pub fn trailing<E>(mut self, trailing: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.trailing = Some(trailing.into());
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -155,6 +193,60 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self.style = style.into();
         self
     }
+
+    fn find_cursor_index(
+        &self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        x: f32,
+    ) -> usize {
+        let target = x - text_bounds.x;
+
+        if target <= 0.0 {
+            return 0;
+        }
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let offset =
+            renderer.offset(text_bounds, size, &value, &self.state, self.font);
+
+        find_cursor_position(
+            renderer,
+            target + offset,
+            &value,
+            size,
+            0,
+            self.value.len(),
+            self.font,
+        )
+    }
+
+    /// Removes the selected graphemes from the value, placing the cursor
+    /// where they started.
+    ///
+    /// It returns whether there was a selection to remove.
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.state.selection(&self.value);
+
+        self.state.selection_anchor = None;
+
+        match selection {
+            Some((start, end)) => {
+                self.value.remove_many(start, end);
+                self.state.cursor_position = start;
+
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -185,10 +277,60 @@ where
             .max_width(self.max_width)
             .height(Length::Units(text_size));
 
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-        text.move_to(Point::new(padding, padding));
+        let inner = limits.resolve(Size::ZERO);
+        let size = inner.pad(padding);
+
+        let icon_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(inner.width, size.height),
+        );
+
+        let mut leading = self
+            .leading
+            .as_ref()
+            .map(|leading| leading.layout(renderer, &icon_limits));
 
-        layout::Node::with_children(text.size().pad(padding), vec![text])
+        let mut trailing = self
+            .trailing
+            .as_ref()
+            .map(|trailing| trailing.layout(renderer, &icon_limits));
+
+        let center_y = |node: &layout::Node| {
+            (size.height - node.size().height).max(0.0) / 2.0
+        };
+
+        let mut text_x = padding;
+        let mut text_width = inner.width;
+
+        if let Some(leading) = &mut leading {
+            let width = leading.size().width;
+            let y = center_y(leading);
+
+            leading.move_to(Point::new(padding, y));
+
+            text_x += width + padding;
+            text_width -= width + padding;
+        }
+
+        if let Some(trailing) = &mut trailing {
+            let width = trailing.size().width;
+            let y = center_y(trailing);
+
+            trailing.move_to(Point::new(padding + inner.width - width, y));
+
+            text_width -= width + padding;
+        }
+
+        let mut text =
+            layout::Node::new(Size::new(text_width.max(0.0), inner.height));
+        text.move_to(Point::new(text_x, padding));
+
+        let children = std::iter::once(text)
+            .chain(leading)
+            .chain(trailing)
+            .collect();
+
+        layout::Node::with_children(size, children)
     }
 
     fn on_event(
@@ -200,55 +342,82 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
+        let mut icon_layouts = layout.children().skip(1);
+
+        for icon in self.leading.iter_mut().chain(self.trailing.iter_mut()) {
+            if let Some(icon_layout) = icon_layouts.next() {
+                icon.widget.on_event(
+                    event.clone(),
+                    icon_layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                );
+            }
+        }
+
+        if let Event::Keyboard(_) = event {
+            if self.state.is_focused {
+                self.state.restart_blink();
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
+                modifiers,
             }) => {
                 let is_clicked = layout.bounds().contains(cursor_position);
 
                 if is_clicked {
-                    let text_layout = layout.children().next().unwrap();
-                    let target = cursor_position.x - text_layout.bounds().x;
-
-                    if target > 0.0 {
-                        let value = if self.is_secure {
-                            self.value.secure()
-                        } else {
-                            self.value.clone()
-                        };
-
-                        let size = self.size.unwrap_or(renderer.default_size());
-
-                        let offset = renderer.offset(
-                            text_layout.bounds(),
-                            size,
-                            &value,
-                            &self.state,
-                            self.font,
-                        );
+                    self.state.restart_blink();
 
-                        self.state.cursor_position = find_cursor_position(
-                            renderer,
-                            target + offset,
-                            &value,
-                            size,
-                            0,
-                            self.value.len(),
-                            self.font,
-                        );
+                    let text_layout = layout.children().next().unwrap();
+                    let position = self.find_cursor_index(
+                        renderer,
+                        text_layout.bounds(),
+                        cursor_position.x,
+                    );
+
+                    if modifiers.shift && self.state.is_focused {
+                        self.state.update_selection(&self.value, true);
                     } else {
-                        self.state.cursor_position = 0;
+                        self.state.selection_anchor = Some(position);
                     }
+
+                    self.state.cursor_position = position;
                 }
 
                 self.state.is_focused = is_clicked;
+                self.state.is_dragging = is_clicked;
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+                ..
+            }) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.state.is_dragging =>
+            {
+                let text_layout = layout.children().next().unwrap();
+
+                self.state.cursor_position = self.find_cursor_index(
+                    renderer,
+                    text_layout.bounds(),
+                    cursor_position.x,
+                );
             }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
                 if self.state.is_focused
                     && self.state.is_pasting.is_none()
                     && !c.is_control() =>
             {
+                let _ = self.delete_selection();
+
                 let cursor_position = self.state.cursor_position(&self.value);
 
                 self.value.insert(cursor_position, c);
@@ -271,7 +440,10 @@ where
                     let cursor_position =
                         self.state.cursor_position(&self.value);
 
-                    if cursor_position > 0 {
+                    if self.delete_selection() {
+                        let message = (self.on_change)(self.value.to_string());
+                        messages.push(message);
+                    } else if cursor_position > 0 {
                         self.state.move_cursor_left(&self.value);
 
                         let _ = self.value.remove(cursor_position - 1);
@@ -284,7 +456,10 @@ where
                     let cursor_position =
                         self.state.cursor_position(&self.value);
 
-                    if cursor_position < self.value.len() {
+                    if self.delete_selection() {
+                        let message = (self.on_change)(self.value.to_string());
+                        messages.push(message);
+                    } else if cursor_position < self.value.len() {
                         let _ = self.value.remove(cursor_position);
 
                         let message = (self.on_change)(self.value.to_string());
@@ -292,29 +467,81 @@ where
                     }
                 }
                 keyboard::KeyCode::Left => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && !self.is_secure
-                    {
-                        self.state.move_cursor_left_by_words(&self.value);
-                    } else {
-                        self.state.move_cursor_left(&self.value);
+                    let selection = self.state.selection(&self.value);
+
+                    self.state.update_selection(&self.value, modifiers.shift);
+
+                    match selection {
+                        Some((start, _)) if !modifiers.shift => {
+                            self.state.cursor_position = start;
+                        }
+                        _ => {
+                            if platform::is_jump_modifier_pressed(modifiers)
+                                && !self.is_secure
+                            {
+                                self.state
+                                    .move_cursor_left_by_words(&self.value);
+                            } else {
+                                self.state.move_cursor_left(&self.value);
+                            }
+                        }
                     }
                 }
                 keyboard::KeyCode::Right => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && !self.is_secure
-                    {
-                        self.state.move_cursor_right_by_words(&self.value);
-                    } else {
-                        self.state.move_cursor_right(&self.value);
+                    let selection = self.state.selection(&self.value);
+
+                    self.state.update_selection(&self.value, modifiers.shift);
+
+                    match selection {
+                        Some((_, end)) if !modifiers.shift => {
+                            self.state.cursor_position = end;
+                        }
+                        _ => {
+                            if platform::is_jump_modifier_pressed(modifiers)
+                                && !self.is_secure
+                            {
+                                self.state
+                                    .move_cursor_right_by_words(&self.value);
+                            } else {
+                                self.state.move_cursor_right(&self.value);
+                            }
+                        }
                     }
                 }
                 keyboard::KeyCode::Home => {
+                    self.state.update_selection(&self.value, modifiers.shift);
                     self.state.cursor_position = 0;
                 }
                 keyboard::KeyCode::End => {
+                    self.state.update_selection(&self.value, modifiers.shift);
+                    self.state.move_cursor_to_end(&self.value);
+                }
+                keyboard::KeyCode::A
+                    if platform::is_copy_paste_modifier_pressed(modifiers) =>
+                {
+                    self.state.selection_anchor = Some(0);
                     self.state.move_cursor_to_end(&self.value);
                 }
+                keyboard::KeyCode::C | keyboard::KeyCode::X
+                    if platform::is_copy_paste_modifier_pressed(modifiers)
+                        && !self.is_secure =>
+                {
+                    if let (Some((start, end)), Some(clipboard)) =
+                        (self.state.selection(&self.value), clipboard)
+                    {
+                        let is_copied = clipboard.set_content(
+                            self.value.select(start, end).to_string(),
+                        );
+
+                        if key_code == keyboard::KeyCode::X && is_copied {
+                            let _ = self.delete_selection();
+
+                            let message =
+                                (self.on_change)(self.value.to_string());
+                            messages.push(message);
+                        }
+                    }
+                }
                 keyboard::KeyCode::V => {
                     if platform::is_copy_paste_modifier_pressed(modifiers) {
                         if let Some(clipboard) = clipboard {
@@ -332,6 +559,8 @@ where
                                 }
                             };
 
+                            let _ = self.delete_selection();
+
                             let cursor_position =
                                 self.state.cursor_position(&self.value);
 
@@ -368,16 +597,44 @@ where
         }
     }
 
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        if self.state.is_focused {
+            Some(layout.bounds())
+        } else {
+            None
+        }
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        let icons = self
+            .leading
+            .iter()
+            .chain(self.trailing.iter())
+            .filter_map(|icon| icon.widget.redraw_at());
+
+        self.state.next_blink().into_iter().chain(icons).min()
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _defaults: &Renderer::Defaults,
+        defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
         let bounds = layout.bounds();
         let text_bounds = layout.children().next().unwrap().bounds();
 
+        let icons = self
+            .leading
+            .iter()
+            .chain(self.trailing.iter())
+            .zip(layout.children().skip(1))
+            .map(|(icon, icon_layout)| {
+                icon.draw(renderer, defaults, icon_layout, cursor_position)
+            })
+            .collect();
+
         if self.is_secure {
             renderer.draw(
                 bounds,
@@ -389,6 +646,7 @@ where
                 &self.value.secure(),
                 &self.state,
                 &self.style,
+                icons,
             )
         } else {
             renderer.draw(
@@ -401,6 +659,7 @@ where
                 &self.value,
                 &self.state,
                 &self.style,
+                icons,
             )
         }
     }
@@ -414,6 +673,14 @@ where
         self.max_width.hash(state);
         self.padding.hash(state);
         self.size.hash(state);
+
+        if let Some(leading) = &self.leading {
+            leading.hash_layout(state);
+        }
+
+        if let Some(trailing) = &self.trailing {
+            trailing.hash_layout(state);
+        }
     }
 }
 
@@ -464,6 +731,7 @@ pub trait Renderer: crate::Renderer + Sized {
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
     /// - the current [`State`]
+    /// - the drawn leading and trailing elements, if any
     ///
     /// [`TextInput`]: struct.TextInput.html
     /// [`Value`]: struct.Value.html
@@ -479,6 +747,7 @@ pub trait Renderer: crate::Renderer + Sized {
         value: &Value,
         state: &State,
         style: &Self::Style,
+        icons: Vec<Self::Output>,
     ) -> Self::Output;
 }
 
@@ -495,14 +764,19 @@ where
     }
 }
 
+const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+
 /// The state of a [`TextInput`].
 ///
 /// [`TextInput`]: struct.TextInput.html
 #[derive(Debug, Default, Clone)]
 pub struct State {
     is_focused: bool,
+    is_dragging: bool,
     is_pasting: Option<Value>,
     cursor_position: usize,
+    selection_anchor: Option<usize>,
+    blink_start: Option<Instant>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -522,8 +796,11 @@ impl State {
 
         Self {
             is_focused: true,
+            is_dragging: false,
             is_pasting: None,
             cursor_position: usize::MAX,
+            selection_anchor: None,
+            blink_start: Some(Instant::now()),
         }
     }
 
@@ -541,6 +818,85 @@ impl State {
         self.cursor_position.min(value.len())
     }
 
+    /// Returns whether the blinking cursor of a [`TextInput`] should be
+    /// drawn at this moment.
+    ///
+    /// The cursor stays visible right after any input and then blinks while
+    /// the [`TextInput`] is focused.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn is_cursor_visible(&self) -> bool {
+        match self.blink_start {
+            Some(start) => {
+                let blinks =
+                    start.elapsed().as_millis() / CURSOR_BLINK_INTERVAL_MILLIS;
+
+                blinks % 2 == 0
+            }
+            None => true,
+        }
+    }
+
+    /// Restarts the blinking of the cursor of a [`TextInput`], making it
+    /// visible.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn restart_blink(&mut self) {
+        self.blink_start = Some(Instant::now());
+    }
+
+    /// Returns the moment the cursor of a focused [`TextInput`] will toggle
+    /// its visibility.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn next_blink(&self) -> Option<Instant> {
+        if !self.is_focused {
+            return None;
+        }
+
+        let start = self.blink_start?;
+        let blinks =
+            start.elapsed().as_millis() / CURSOR_BLINK_INTERVAL_MILLIS + 1;
+
+        Some(
+            start
+                + Duration::from_millis(
+                    (blinks * CURSOR_BLINK_INTERVAL_MILLIS) as u64,
+                ),
+        )
+    }
+
+    /// Returns the selected range of a [`TextInput`], if any.
+    ///
+    /// The range is given as a pair of grapheme indices, with the start
+    /// always before the end.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn selection(&self, value: &Value) -> Option<(usize, usize)> {
+        let cursor = self.cursor_position(value);
+        let anchor = self.selection_anchor?.min(value.len());
+
+        if anchor == cursor {
+            None
+        } else {
+            Some((anchor.min(cursor), anchor.max(cursor)))
+        }
+    }
+
+    /// Prepares the selection of a [`TextInput`] before the cursor moves.
+    ///
+    /// When extending, the selection is anchored at the current cursor
+    /// position, unless it is already anchored. Otherwise, it is cleared.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn update_selection(&mut self, value: &Value, extend: bool) {
+        if !extend {
+            self.selection_anchor = None;
+        } else if self.selection_anchor.is_none() {
+            self.selection_anchor = Some(self.cursor_position(value));
+        }
+    }
+
     /// Moves the cursor of a [`TextInput`] to the left.
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -706,6 +1062,26 @@ impl Value {
             .splice(index..index, value.graphemes.drain(..));
     }
 
+    /// Returns a new [`Value`] containing the graphemes from `start` until
+    /// `end`.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn select(&self, start: usize, end: usize) -> Self {
+        let end = end.min(self.len());
+        let graphemes = self.graphemes[start.min(end)..end].to_vec();
+
+        Self { graphemes }
+    }
+
+    /// Removes the graphemes from `start` until `end`.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn remove_many(&mut self, start: usize, end: usize) {
+        let end = end.min(self.len());
+
+        let _ = self.graphemes.drain(start.min(end)..end);
+    }
+
     /// Removes the grapheme at the given `index`.
     ///
     /// [`Value`]: struct.Value.html
@@ -782,7 +1158,7 @@ fn find_cursor_position<Renderer: self::Renderer>(
     }
 }
 
-mod platform {
+pub(crate) mod platform {
     use crate::input::keyboard;
 
     pub fn is_jump_modifier_pressed(
@@ -805,3 +1181,169 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn on_events(
+        state: &mut State,
+        value: &str,
+        events: &[Event],
+    ) -> Vec<String> {
+        let mut text_input = TextInput::new(state, "", value, String::from);
+
+        let node = Widget::<String, Null>::layout(
+            &text_input,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        for event in events {
+            text_input.on_event(
+                event.clone(),
+                Layout::new(&node),
+                Point::new(-1.0, -1.0),
+                &mut messages,
+                &Null::new(),
+                None,
+            );
+        }
+
+        messages
+    }
+
+    fn character(c: char) -> Event {
+        Event::Keyboard(keyboard::Event::CharacterReceived(c))
+    }
+
+    fn key_press(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::Input {
+            state: ButtonState::Pressed,
+            key_code,
+            modifiers: keyboard::ModifiersState::default(),
+        })
+    }
+
+    #[test]
+    fn typing_updates_the_value() {
+        let mut state = State::focused();
+
+        let messages =
+            on_events(&mut state, "", &[character('h'), character('i')]);
+
+        assert_eq!(messages, vec![String::from("h"), String::from("hi")]);
+    }
+
+    #[test]
+    fn typing_is_ignored_while_unfocused() {
+        let mut state = State::new();
+
+        let messages = on_events(&mut state, "", &[character('h')]);
+
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn cursor_moves_over_multi_byte_characters() {
+        let mut state = State::focused();
+
+        let messages = on_events(
+            &mut state,
+            "héllo",
+            &[
+                key_press(keyboard::KeyCode::Home),
+                key_press(keyboard::KeyCode::Right),
+                key_press(keyboard::KeyCode::Right),
+                character('X'),
+            ],
+        );
+
+        assert_eq!(messages, vec![String::from("héXllo")]);
+    }
+
+    #[test]
+    fn cursor_moves_over_whole_graphemes() {
+        let mut state = State::focused();
+
+        let messages = on_events(
+            &mut state,
+            "ae\u{301}b",
+            &[
+                key_press(keyboard::KeyCode::End),
+                key_press(keyboard::KeyCode::Left),
+                character('X'),
+                key_press(keyboard::KeyCode::Backspace),
+                key_press(keyboard::KeyCode::Backspace),
+            ],
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                String::from("ae\u{301}Xb"),
+                String::from("ae\u{301}b"),
+                String::from("ab"),
+            ]
+        );
+    }
+
+    #[test]
+    fn cursor_is_clamped_to_the_value() {
+        let value = Value::new("héllo");
+        let mut state = State::focused();
+
+        assert_eq!(state.cursor_position(&value), 5);
+
+        let _ = on_events(
+            &mut state,
+            "héllo",
+            &[
+                key_press(keyboard::KeyCode::Right),
+                key_press(keyboard::KeyCode::Right),
+            ],
+        );
+
+        assert_eq!(state.cursor_position(&value), 5);
+
+        let _ = on_events(
+            &mut state,
+            "héllo",
+            &[
+                key_press(keyboard::KeyCode::Home),
+                key_press(keyboard::KeyCode::Left),
+            ],
+        );
+
+        assert_eq!(state.cursor_position(&value), 0);
+    }
+
+    #[test]
+    fn enter_submits() {
+        let mut state = State::focused();
+        let mut text_input = TextInput::new(&mut state, "", "", String::from)
+            .on_submit(|| String::from("submitted"));
+
+        let node = Widget::<String, Null>::layout(
+            &text_input,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        text_input.on_event(
+            key_press(keyboard::KeyCode::Enter),
+            Layout::new(&node),
+            Point::new(-1.0, -1.0),
+            &mut messages,
+            &Null::new(),
+            None,
+        );
+
+        assert_eq!(messages, vec![String::from("submitted")]);
+    }
+}
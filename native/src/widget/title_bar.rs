@@ -0,0 +1,165 @@
+//! Drag the window of your application using your own title bar.
+use crate::{
+    input::{mouse, ButtonState},
+    layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Widget,
+};
+
+use std::{hash::Hash, time::Instant};
+
+/// A region of the user interface that can be dragged to move the window.
+///
+/// A [`TitleBar`] produces a message when the left mouse button is pressed
+/// over it, unless one of its contents handles the press and produces its
+/// own message (like a close button). The application should then request a
+/// [`window::Action::Drag`] to the runtime.
+///
+/// Combined with a window without decorations, this allows you to draw your
+/// own title bar with custom controls.
+///
+/// [`TitleBar`]: struct.TitleBar.html
+/// [`window::Action::Drag`]: ../../window/enum.Action.html#variant.Drag
+#[allow(missing_debug_implementations)]
+pub struct TitleBar<'a, Message, Renderer> {
+    on_drag: Option<Message>,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> TitleBar<'a, Message, Renderer> {
+    /// Creates a new [`TitleBar`] with the given contents.
+    ///
+    /// [`TitleBar`]: struct.TitleBar.html
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        TitleBar {
+            on_drag: None,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the message that will be produced when the [`TitleBar`] starts
+    /// being dragged.
+    ///
+    /// [`TitleBar`]: struct.TitleBar.html
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TitleBar<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let produced = messages.len();
+
+        self.content.widget.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                ..
+            }) if messages.len() == produced
+                && layout.bounds().contains(cursor_position) =>
+            {
+                if let Some(on_drag) = self.on_drag.clone() {
+                    messages.push(on_drag);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.content
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.widget.overlay(layout)
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        self.content.widget.redraw_at()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<TitleBar<'static, (), Renderer>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<TitleBar<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        title_bar: TitleBar<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(title_bar)
+    }
+}
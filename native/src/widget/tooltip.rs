@@ -0,0 +1,576 @@
+//! Display a floating label when hovering over some content.
+//!
+//! A [`Tooltip`] has some local [`State`].
+//!
+//! [`Tooltip`]: struct.Tooltip.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::mouse, layout, overlay, text, Align, Clipboard, Element, Event,
+    Font, Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// A widget that draws a floating label near its content after the cursor
+/// has hovered over it for a while.
+///
+/// The label is drawn as an overlay, on top of every other widget and
+/// without being clipped by the parents of the [`Tooltip`]. It is moved as
+/// needed to stay inside the window.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// # use iced_native::tooltip::{Position, State};
+/// #
+/// # pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Null>;
+/// let mut state = State::new();
+///
+/// let tooltip = Tooltip::<()>::new(
+///     &mut state,
+///     Text::new("Save"),
+///     "Save the current file",
+///     Position::Bottom,
+/// );
+/// ```
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    tip: String,
+    position: Position,
+    delay: Duration,
+    size: Option<u16>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Tooltip`] with the given [`State`], content, label
+    /// and [`Position`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`State`]: struct.State.html
+    /// [`Position`]: enum.Position.html
+    pub fn new<T>(
+        state: &'a mut State,
+        content: T,
+        tip: impl Into<String>,
+        position: Position,
+    ) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Tooltip {
+            state,
+            content: content.into(),
+            tip: tip.into(),
+            position,
+            delay: Duration::from_millis(500),
+            size: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets how long the cursor needs to hover over the [`Tooltip`] before
+    /// its label is shown.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    hovered_since: Option<Instant>,
+    cursor_offset: Vector,
+}
+
+impl State {
+    /// Creates a new [`State`], with the cursor outside of the [`Tooltip`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the cursor is currently hovering over the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn is_hovered(&self) -> bool {
+        self.hovered_since.is_some()
+    }
+}
+
+/// The position of the label of a [`Tooltip`], relative to its content.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The label follows the cursor.
+    FollowCursor,
+
+    /// The label is placed above the content.
+    Top,
+
+    /// The label is placed below the content.
+    Bottom,
+
+    /// The label is placed to the left of the content.
+    Left,
+
+    /// The label is placed to the right of the content.
+    Right,
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn shows_at(&self) -> Option<Instant> {
+        self.state.hovered_since.map(|since| since + self.delay)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Mouse(mouse::Event::CursorLeft) => {
+                let bounds = layout.bounds();
+
+                if bounds.contains(cursor_position) {
+                    if self.state.hovered_since.is_none() {
+                        self.state.hovered_since = Some(Instant::now());
+                    }
+
+                    self.state.cursor_offset = Vector::new(
+                        cursor_position.x - bounds.x,
+                        cursor_position.y - bounds.y,
+                    );
+                } else {
+                    self.state.hovered_since = None;
+                }
+            }
+            _ => {}
+        }
+
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn focused_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.content.widget.focused_bounds(layout)
+    }
+
+    fn can_scroll(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        delta_y: f32,
+    ) -> bool {
+        self.content
+            .widget
+            .can_scroll(layout, cursor_position, delta_y)
+    }
+
+    fn align_self(&self) -> Option<Align> {
+        self.content.widget.align_self()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let is_shown = self
+            .shows_at()
+            .map(|at| at <= Instant::now())
+            .unwrap_or(false);
+
+        let bounds = layout.bounds();
+        let tip = &self.tip;
+        let position = self.position;
+        let size = self.size;
+        let style = &self.style;
+        let cursor_offset = self.state.cursor_offset;
+
+        // The overlays of the content, like an open menu, take precedence
+        self.content.widget.overlay(layout).or_else(|| {
+            if !is_shown {
+                return None;
+            }
+
+            let label = Label {
+                tip,
+                position,
+                size,
+                style,
+                target_size: Size::new(bounds.width, bounds.height),
+                cursor_offset,
+            };
+
+            Some(overlay::Element::new(
+                Point::new(bounds.x, bounds.y),
+                Box::new(label),
+            ))
+        })
+    }
+
+    fn redraw_at(&self) -> Option<Instant> {
+        // Wake up to show the label once the delay elapses
+        let shows_at = self.shows_at().filter(|at| *at > Instant::now());
+
+        match (shows_at, self.content.widget.redraw_at()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Tooltip<'static, (), Renderer>>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+/// The floating label of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+struct Label<'a, Renderer: self::Renderer> {
+    tip: &'a str,
+    position: Position,
+    size: Option<u16>,
+    style: &'a Renderer::Style,
+    target_size: Size,
+    cursor_offset: Vector,
+}
+
+const PADDING: f32 = 6.0;
+const GAP: f32 = 8.0;
+const CURSOR_GAP: Vector = Vector { x: 12.0, y: 16.0 };
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Label<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size =
+            self.size.unwrap_or(text::Renderer::default_size(renderer));
+
+        let (text_width, text_height) = text::Renderer::measure(
+            renderer,
+            self.tip,
+            text_size,
+            Font::Default,
+            0.0,
+            Size::INFINITY,
+        );
+
+        let width = text_width + PADDING * 2.0;
+        let height = text_height + PADDING * 2.0;
+
+        let target = Rectangle {
+            x: position.x,
+            y: position.y,
+            width: self.target_size.width,
+            height: self.target_size.height,
+        };
+
+        let above = target.y - height - GAP;
+        let below = target.y + target.height + GAP;
+        let before = target.x - width - GAP;
+        let after = target.x + target.width + GAP;
+        let center_x = target.x + (target.width - width) / 2.0;
+        let center_y = target.y + (target.height - height) / 2.0;
+
+        // Flip the label to the opposite side when it does not fit
+        let (x, y) = match self.position {
+            Position::FollowCursor => {
+                let cursor = position + self.cursor_offset;
+
+                (cursor.x + CURSOR_GAP.x, cursor.y + CURSOR_GAP.y)
+            }
+            Position::Top if above < 0.0 => (center_x, below),
+            Position::Top => (center_x, above),
+            Position::Bottom if below + height > bounds.height => {
+                (center_x, above)
+            }
+            Position::Bottom => (center_x, below),
+            Position::Left if before < 0.0 => (after, center_y),
+            Position::Left => (before, center_y),
+            Position::Right if after + width > bounds.width => {
+                (before, center_y)
+            }
+            Position::Right => (after, center_y),
+        };
+
+        // Keep the label inside the viewport, favoring its top-left corner
+        let x = x.min(bounds.width - width).max(0.0);
+        let y = y.min(bounds.height - height).max(0.0);
+
+        let mut node = layout::Node::new(Size::new(width, height));
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let text_size =
+            self.size.unwrap_or(text::Renderer::default_size(renderer));
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            self.tip,
+            text_size,
+            self.style,
+        )
+    }
+}
+
+/// The renderer of a [`Tooltip`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Tooltip`] in your user interface.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the label of a [`Tooltip`].
+    ///
+    /// It receives:
+    ///   * the bounds of the label, already placed next to the content
+    ///   * the label of the [`Tooltip`]
+    ///   * the text size of the label
+    ///   * the style of the [`Tooltip`]
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        tip: &str,
+        text_size: u16,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tooltip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Space};
+
+    const WINDOW: Size = Size {
+        width: 400.0,
+        height: 300.0,
+    };
+
+    fn tooltip(state: &mut State, position: Position) -> Tooltip<'_, (), Null> {
+        Tooltip::new(
+            state,
+            Space::new(Length::Units(100), Length::Units(20)),
+            "Save",
+            position,
+        )
+        .delay(Duration::from_millis(0))
+    }
+
+    fn layout(tooltip: &Tooltip<'_, (), Null>) -> layout::Node {
+        Widget::<(), Null>::layout(
+            tooltip,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, WINDOW),
+        )
+    }
+
+    fn move_cursor(tooltip: &mut Tooltip<'_, (), Null>, x: f32, y: f32) {
+        let node = layout(tooltip);
+
+        tooltip.on_event(
+            Event::Mouse(mouse::Event::CursorMoved { x, y }),
+            Layout::new(&node),
+            Point::new(x, y),
+            &mut Vec::new(),
+            &Null::new(),
+            None,
+        );
+    }
+
+    fn label_bounds(
+        tooltip: &mut Tooltip<'_, (), Null>,
+        window: Size,
+    ) -> Option<Rectangle> {
+        let node = layout(tooltip);
+
+        tooltip
+            .overlay(Layout::new(&node))
+            .map(|overlay| overlay.layout(&Null::new(), window).bounds())
+    }
+
+    #[test]
+    fn hovering_past_the_delay_shows_the_label() {
+        let mut state = State::new();
+        let mut tooltip = tooltip(&mut state, Position::Bottom);
+
+        assert_eq!(label_bounds(&mut tooltip, WINDOW), None);
+
+        move_cursor(&mut tooltip, 50.0, 10.0);
+
+        assert!(label_bounds(&mut tooltip, WINDOW).is_some());
+    }
+
+    #[test]
+    fn label_waits_for_the_delay() {
+        let mut state = State::new();
+        let mut tooltip = tooltip(&mut state, Position::Bottom)
+            .delay(Duration::from_secs(60));
+
+        move_cursor(&mut tooltip, 50.0, 10.0);
+
+        assert_eq!(label_bounds(&mut tooltip, WINDOW), None);
+        assert!(tooltip.redraw_at().is_some());
+    }
+
+    #[test]
+    fn moving_away_hides_the_label() {
+        let mut state = State::new();
+        let mut tooltip = tooltip(&mut state, Position::Bottom);
+
+        move_cursor(&mut tooltip, 50.0, 10.0);
+        move_cursor(&mut tooltip, 200.0, 100.0);
+
+        assert_eq!(label_bounds(&mut tooltip, WINDOW), None);
+
+        drop(tooltip);
+        assert!(!state.is_hovered());
+    }
+
+    #[test]
+    fn label_flips_below_at_the_top_edge() {
+        let mut state = State::new();
+        let mut tooltip = tooltip(&mut state, Position::Top);
+
+        move_cursor(&mut tooltip, 50.0, 10.0);
+
+        let bounds = label_bounds(&mut tooltip, WINDOW).expect("Label");
+
+        assert_eq!(bounds.y, 20.0 + GAP);
+    }
+
+    #[test]
+    fn label_stays_inside_a_narrow_window() {
+        let mut state = State::new();
+        let mut tooltip = tooltip(&mut state, Position::Right);
+
+        move_cursor(&mut tooltip, 50.0, 10.0);
+
+        let window = Size::new(120.0, 100.0);
+        let bounds = label_bounds(&mut tooltip, window).expect("Label");
+
+        assert!(bounds.x >= 0.0);
+        assert!(bounds.x + bounds.width <= window.width);
+        assert!(bounds.y >= 0.0);
+        assert!(bounds.y + bounds.height <= window.height);
+    }
+}
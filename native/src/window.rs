@@ -1,6 +1,12 @@
 //! Build window-based GUI applications.
+mod action;
 mod backend;
+mod close_behavior;
+mod error;
 mod event;
 
+pub use action::Action;
 pub use backend::Backend;
+pub use close_behavior::CloseBehavior;
+pub use error::Error;
 pub use event::Event;
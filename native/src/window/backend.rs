@@ -1,4 +1,4 @@
-use crate::MouseCursor;
+use crate::{window::Error, MouseCursor};
 
 use raw_window_handle::HasRawWindowHandle;
 
@@ -18,8 +18,12 @@ pub trait Backend: Sized {
 
     /// Creates a new [`Backend`] and an associated iced renderer.
     ///
+    /// It returns an [`Error`] if the graphics of the [`Backend`] cannot be
+    /// initialized.
+    ///
     /// [`Backend`]: trait.Backend.html
-    fn new(settings: Self::Settings) -> (Self, Self::Renderer);
+    /// [`Error`]: enum.Error.html
+    fn new(settings: Self::Settings) -> Result<(Self, Self::Renderer), Error>;
 
     /// Crates a new [`Surface`] for the given window.
     ///
@@ -52,4 +56,20 @@ pub trait Backend: Sized {
         scale_factor: f64,
         overlay: &[T],
     ) -> MouseCursor;
+
+    /// Sets the function that wakes up the event loop to draw the user
+    /// interface again.
+    ///
+    /// The [`Backend`] may call it from any thread once some work done in
+    /// the background, like rasterizing an SVG, is ready to be drawn.
+    ///
+    /// By default, the function is never called.
+    ///
+    /// [`Backend`]: trait.Backend.html
+    fn set_waker(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _waker: Box<dyn Fn() + Send + Sync>,
+    ) {
+    }
 }
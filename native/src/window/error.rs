@@ -0,0 +1,22 @@
+/// An error that prevents a window [`Backend`] from being created.
+///
+/// [`Backend`]: trait.Backend.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// No graphics adapter could be found, not even a fallback one.
+    AdapterNotFound,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::AdapterNotFound => write!(
+                f,
+                "No graphics adapter found. Make sure that your graphics \
+                drivers are installed and up to date."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -1,7 +1,8 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Instant};
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A window was resized
     Resized {
@@ -12,6 +13,16 @@ pub enum Event {
         height: u32,
     },
 
+    /// The window gained focus.
+    Focused,
+
+    /// The window lost focus.
+    Unfocused,
+
+    /// The window was requested to close, like when its close button is
+    /// pressed.
+    CloseRequested,
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
@@ -29,4 +40,14 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// A frame of the window was rendered.
+    ///
+    /// It holds the moment the frame was presented. The runtime only produces
+    /// it for the [`frames`] subscription, and not while the window is
+    /// minimized.
+    ///
+    /// [`frames`]: ../subscription/fn.frames.html
+    #[cfg_attr(feature = "serde", serde(skip))]
+    RedrawRequested(Instant),
 }
@@ -111,6 +111,9 @@ pub trait Application: Sized {
     /// This title can be dynamic! The runtime will automatically update the
     /// title of your application when necessary.
     ///
+    /// The runtime queries the title after every update, and it only changes
+    /// the title of the window when the returned title is different.
+    ///
     /// [`Application`]: trait.Application.html
     fn title(&self) -> String;
 
@@ -161,6 +164,53 @@ pub trait Application: Sized {
         window::Mode::Windowed
     }
 
+    /// Returns the scale factor of the [`Application`].
+    ///
+    /// It multiplies the scale factor given by the operating system, which
+    /// can be used to zoom the whole user interface.
+    ///
+    /// Currently, the scale factor only has an effect in native platforms.
+    ///
+    /// By default, it returns `1.0`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn scale_factor(&self) -> f64 {
+        1.0
+    }
+
+    /// Returns an [`Action`] to perform on the window of the [`Application`],
+    /// if any.
+    ///
+    /// The runtime calls this method after every update. It can be used to
+    /// implement custom window decorations.
+    ///
+    /// Currently, window actions only have an effect in native platforms.
+    ///
+    /// By default, no action is performed.
+    ///
+    /// [`Action`]: window/enum.Action.html
+    /// [`Application`]: trait.Application.html
+    fn window_action(&mut self) -> Option<window::Action> {
+        None
+    }
+
+    /// Decides what happens when the window of the [`Application`] is
+    /// requested to close, like when its close button is pressed.
+    ///
+    /// Returning [`CloseBehavior::Cancel`] keeps the application running,
+    /// which is useful to ask for confirmation when there are unsaved
+    /// changes. Every following request is handled the same way.
+    ///
+    /// Currently, this only has an effect in native platforms.
+    ///
+    /// By default, the window is closed.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`CloseBehavior::Cancel`]: window/enum.CloseBehavior.html#variant.Cancel
+    fn on_close(&mut self) -> window::CloseBehavior {
+        window::CloseBehavior::Close
+    }
+
     /// Runs the [`Application`].
     ///
     /// This method will take control of the current thread and __will NOT
@@ -168,18 +218,27 @@ pub trait Application: Sized {
     ///
     /// It should probably be that last thing you call in your `main` function.
     ///
+    /// # Panics
+    ///
+    /// It panics if no graphics adapter can be found, not even a fallback one.
+    ///
     /// [`Application`]: trait.Application.html
     fn run(_settings: Settings)
     where
         Self: 'static,
     {
         #[cfg(not(target_arch = "wasm32"))]
-        <Instance<Self> as iced_winit::Application>::run(
-            _settings.into(),
-            iced_wgpu::Settings {
+        {
+            let wgpu_settings = iced_wgpu::Settings {
                 default_font: _settings.default_font,
-            },
-        );
+                ..iced_wgpu::Settings::default()
+            };
+
+            <Instance<Self> as iced_winit::Application>::run(
+                _settings.into(),
+                wgpu_settings,
+            );
+        }
 
         #[cfg(target_arch = "wasm32")]
         <Instance<Self> as iced_web::Application>::run();
@@ -214,6 +273,32 @@ where
         }
     }
 
+    fn scale_factor(&self) -> f64 {
+        self.0.scale_factor()
+    }
+
+    fn on_close(&mut self) -> iced_winit::window::CloseBehavior {
+        match self.0.on_close() {
+            window::CloseBehavior::Close => {
+                iced_winit::window::CloseBehavior::Close
+            }
+            window::CloseBehavior::Cancel => {
+                iced_winit::window::CloseBehavior::Cancel
+            }
+        }
+    }
+
+    fn window_action(&mut self) -> Option<iced_winit::window::Action> {
+        self.0.window_action().map(|action| match action {
+            window::Action::Drag => iced_winit::window::Action::Drag,
+            window::Action::Minimize => iced_winit::window::Action::Minimize,
+            window::Action::ToggleMaximize => {
+                iced_winit::window::Action::ToggleMaximize
+            }
+            window::Action::Close => iced_winit::window::Action::Close,
+        })
+    }
+
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
         self.0.update(message)
     }
@@ -257,3 +342,54 @@ where
         self.0.view()
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::{executor, Text};
+
+    struct Editor {
+        close_requests: usize,
+    }
+
+    impl Application for Editor {
+        type Executor = executor::Null;
+        type Message = ();
+
+        fn new() -> (Editor, Command<()>) {
+            (Editor { close_requests: 0 }, Command::none())
+        }
+
+        fn title(&self) -> String {
+            String::from("Editor")
+        }
+
+        fn update(&mut self, _message: ()) -> Command<()> {
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, ()> {
+            Text::new("Unsaved changes").into()
+        }
+
+        fn on_close(&mut self) -> window::CloseBehavior {
+            self.close_requests += 1;
+
+            window::CloseBehavior::Cancel
+        }
+    }
+
+    #[test]
+    fn vetoing_application_keeps_running() {
+        let (mut instance, _) =
+            <Instance<Editor> as iced_winit::Application>::new();
+
+        for close_requests in 1..=2 {
+            assert_eq!(
+                iced_winit::Application::on_close(&mut instance),
+                iced_winit::window::CloseBehavior::Cancel
+            );
+            assert_eq!(instance.0.close_requests, close_requests);
+        }
+    }
+}
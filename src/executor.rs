@@ -13,7 +13,17 @@ mod platform {
     #[cfg(all(not(feature = "tokio"), feature = "async-std"))]
     type Executor = executor::AsyncStd;
 
-    #[cfg(not(any(feature = "tokio", feature = "async-std")))]
+    #[cfg(all(
+        not(any(feature = "tokio", feature = "async-std")),
+        feature = "smol"
+    ))]
+    type Executor = executor::Smol;
+
+    #[cfg(not(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    )))]
     type Executor = executor::ThreadPool;
 
     /// A default cross-platform executor.
@@ -22,6 +32,7 @@ mod platform {
     ///   - `iced_futures::executor::Tokio` when the `tokio` feature is enabled.
     ///   - `iced_futures::executor::AsyncStd` when the `async-std` feature is
     ///     enabled.
+    ///   - `iced_futures::executor::Smol` when the `smol` feature is enabled.
     ///   - `iced_futures::executor::ThreadPool` otherwise.
     /// - On the Web, it will use `iced_futures::executor::WasmBindgen`.
     #[derive(Debug)]
@@ -202,7 +202,14 @@ use iced_winit as common;
 #[cfg(target_arch = "wasm32")]
 use iced_web as common;
 
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "tokio", feature = "async-std", feature = "smol")
+))]
+pub use iced_futures::time;
+
 pub use common::{
-    futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Space, Subscription, Vector, VerticalAlignment,
+    futures, Align, Background, BorderRadius, BorderStyle, Color, Command,
+    Font, HorizontalAlignment, Length, LinearGradient, Padding, Space,
+    Subscription, Vector, VerticalAlignment,
 };
@@ -95,6 +95,9 @@ pub trait Sandbox {
     /// This title can be dynamic! The runtime will automatically update the
     /// title of your application when necessary.
     ///
+    /// The runtime queries the title after every update, and it only changes
+    /// the title of the window when the returned title is different.
+    ///
     /// [`Sandbox`]: trait.Sandbox.html
     fn title(&self) -> String;
 
@@ -2,7 +2,7 @@
 use crate::window;
 
 /// The settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Settings {
     /// The window settings.
     ///
@@ -24,8 +24,20 @@ impl From<Settings> for iced_winit::Settings {
         iced_winit::Settings {
             window: iced_winit::settings::Window {
                 size: settings.window.size,
+                min_size: settings.window.min_size,
+                max_size: settings.window.max_size,
                 resizable: settings.window.resizable,
                 decorations: settings.window.decorations,
+                icon: settings.window.icon.and_then(|icon| {
+                    let (width, height) = icon.size();
+
+                    iced_winit::winit::window::Icon::from_rgba(
+                        icon.rgba().to_vec(),
+                        width,
+                        height,
+                    )
+                    .ok()
+                }),
                 platform_specific: Default::default(),
             },
         }
@@ -22,23 +22,33 @@ mod platform {
 
     pub mod image {
         //! Display images in your user interface.
-        pub use iced_winit::image::{Handle, Image};
+        pub use iced_winit::image::{ContentFit, Handle, Image, NineSlice};
     }
 
     pub mod svg {
         //! Display vector graphics in your user interface.
-        pub use iced_winit::svg::{Handle, Svg};
+        pub use iced_winit::svg::{ContentFit, Data, Handle, Status, Svg};
     }
 
-    pub use iced_winit::Text;
+    pub mod text {
+        //! Write some text for your users to read.
+        pub use iced_winit::text::{Selectable, State, Text};
+    }
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        progress_bar::ProgressBar, radio::Radio, scrollable::Scrollable,
-        slider::Slider, svg::Svg, text_input::TextInput,
+        animated::Animated, badge::Badge, button::Button, cached::Cached,
+        checkbox::Checkbox, container::Container, image::Image,
+        pick_list::PickList, progress_bar::ProgressBar, radio::Radio,
+        rule::Rule, scrollable::Scrollable, slider::Slider, svg::Svg,
+        text::Text, text_input::TextInput, title_bar::TitleBar,
+        tooltip::Tooltip,
     };
 
+    #[cfg(feature = "canvas")]
+    #[doc(no_inline)]
+    pub use canvas::Canvas;
+
     /// A container that distributes its contents vertically.
     ///
     /// This is an alias of an `iced_native` column with a default `Renderer`.
@@ -1,6 +1,12 @@
 //! Configure the window of your application in native platforms.
+mod action;
+mod close_behavior;
+mod icon;
 mod mode;
 mod settings;
 
+pub use action::Action;
+pub use close_behavior::CloseBehavior;
+pub use icon::{Icon, IconError};
 pub use mode::Mode;
 pub use settings::Settings;
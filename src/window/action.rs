@@ -0,0 +1,21 @@
+/// An operation that an application can request on its window.
+///
+/// It is mostly useful to implement custom window decorations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Move the window following the mouse cursor, until the left mouse
+    /// button is released.
+    ///
+    /// It should be requested while the left mouse button is pressed, like
+    /// when a `TitleBar` is dragged.
+    Drag,
+
+    /// Minimize the window.
+    Minimize,
+
+    /// Maximize the window, or restore it if it is already maximized.
+    ToggleMaximize,
+
+    /// Close the window.
+    Close,
+}
@@ -0,0 +1,9 @@
+/// What an application does when its window is requested to close.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseBehavior {
+    /// Close the window and exit.
+    Close,
+
+    /// Keep the window open and keep running.
+    Cancel,
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+
+/// The icon of a window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icon {
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl Icon {
+    /// Creates an [`Icon`] from its pixels in RGBA format, row by row.
+    ///
+    /// It fails if the amount of bytes does not match the given `width` and
+    /// `height`.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn from_rgba(
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+    ) -> Result<Icon, IconError> {
+        let expected = width as usize * height as usize * 4;
+
+        if rgba.len() != expected {
+            return Err(IconError::InvalidLength {
+                expected,
+                actual: rgba.len(),
+            });
+        }
+
+        Ok(Icon {
+            rgba,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the pixels of the [`Icon`] in RGBA format.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Returns the width and height of the [`Icon`].
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+/// An error produced when creating an invalid [`Icon`].
+///
+/// [`Icon`]: struct.Icon.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconError {
+    /// The amount of bytes of the pixels does not match the size of the
+    /// [`Icon`].
+    ///
+    /// [`Icon`]: struct.Icon.html
+    InvalidLength {
+        /// The amount of bytes expected for the size of the [`Icon`]
+        ///
+        /// [`Icon`]: struct.Icon.html
+        expected: usize,
+
+        /// The amount of bytes provided
+        actual: usize,
+    },
+}
+
+impl fmt::Display for IconError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IconError::InvalidLength { expected, actual } => write!(
+                f,
+                "the icon has {} bytes of pixels, but its size needs {}",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IconError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mismatched_buffer_is_rejected() {
+        assert_eq!(
+            Icon::from_rgba(vec![0; 4 * 4 * 3], 4, 4),
+            Err(IconError::InvalidLength {
+                expected: 64,
+                actual: 48,
+            })
+        );
+    }
+
+    #[test]
+    fn matching_buffer_is_accepted() {
+        let icon =
+            Icon::from_rgba(vec![255; 2 * 3 * 4], 2, 3).expect("Valid icon");
+
+        assert_eq!(icon.size(), (2, 3));
+        assert_eq!(icon.rgba().len(), 24);
+    }
+}
@@ -1,22 +1,36 @@
+use super::Icon;
+
 /// The window settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Settings {
     /// The size of the window.
     pub size: (u32, u32),
 
+    /// The minimum size of the window, if any.
+    pub min_size: Option<(u32, u32)>,
+
+    /// The maximum size of the window, if any.
+    pub max_size: Option<(u32, u32)>,
+
     /// Whether the window should be resizable or not.
     pub resizable: bool,
 
     /// Whether the window should have a border, a title bar, etc. or not.
     pub decorations: bool,
+
+    /// The icon of the window, if any.
+    pub icon: Option<Icon>,
 }
 
 impl Default for Settings {
     fn default() -> Settings {
         Settings {
             size: (1024, 768),
+            min_size: None,
+            max_size: None,
             resizable: true,
             decorations: true,
+            icon: None,
         }
     }
 }
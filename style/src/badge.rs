@@ -0,0 +1,45 @@
+//! Display a small count on top of some content.
+use iced_core::{Background, Color};
+
+/// The appearance of a badge.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub text_color: Color,
+    pub border_width: u16,
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a badge.
+pub trait StyleSheet {
+    /// Produces the style of a badge.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            background: Background::Color(Color::from_rgb(0.85, 0.2, 0.2)),
+            text_color: Color::WHITE,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
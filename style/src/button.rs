@@ -1,14 +1,23 @@
 //! Allow your users to perform actions by pressing a button.
-use iced_core::{Background, Color, Vector};
+use iced_core::{Background, BorderRadius, BorderStyle, Color, Vector};
 
 /// The appearance of a button.
+///
+/// The shadow is cast at the `shadow_offset` and blurred over the
+/// `shadow_blur_radius`. No shadow is drawn when both are zero.
+///
+/// The `border_radius` can round every corner uniformly, like `12.into()`, or
+/// each corner differently, like `[8.0, 8.0, 0.0, 0.0].into()`.
 #[derive(Debug)]
 pub struct Style {
     pub shadow_offset: Vector,
+    pub shadow_blur_radius: f32,
+    pub shadow_color: Color,
     pub background: Option<Background>,
-    pub border_radius: u16,
+    pub border_radius: BorderRadius,
     pub border_width: u16,
     pub border_color: Color,
+    pub border_style: BorderStyle,
     pub text_color: Color,
 }
 
@@ -16,10 +25,13 @@ impl std::default::Default for Style {
     fn default() -> Self {
         Self {
             shadow_offset: Vector::default(),
+            shadow_blur_radius: 0.0,
+            shadow_color: [0.0, 0.0, 0.0, 0.5].into(),
             background: None,
-            border_radius: 0,
+            border_radius: BorderRadius::ZERO,
             border_width: 0,
             border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
             text_color: Color::BLACK,
         }
     }
@@ -27,8 +39,14 @@ impl std::default::Default for Style {
 
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
+    /// Produces the style of an idle button.
     fn active(&self) -> Style;
 
+    /// Produces the style of a button with the mouse over it.
+    ///
+    /// By default, it is the [`active`] style with its shadow lowered.
+    ///
+    /// [`active`]: #tymethod.active
     fn hovered(&self) -> Style {
         let active = self.active();
 
@@ -38,24 +56,34 @@ pub trait StyleSheet {
         }
     }
 
+    /// Produces the style of a button while it is being pressed.
+    ///
+    /// It is used while the mouse button is held down and the mouse is over
+    /// the button. By default, it is the [`active`] style without a shadow.
+    ///
+    /// [`active`]: #tymethod.active
     fn pressed(&self) -> Style {
         Style {
             shadow_offset: Vector::default(),
+            shadow_blur_radius: 0.0,
             ..self.active()
         }
     }
 
+    /// Produces the style of a button without an `on_press` message.
+    ///
+    /// By default, it is the [`active`] style faded out.
+    ///
+    /// [`active`]: #tymethod.active
     fn disabled(&self) -> Style {
         let active = self.active();
 
         Style {
             shadow_offset: Vector::default(),
-            background: active.background.map(|background| match background {
-                Background::Color(color) => Background::Color(Color {
-                    a: color.a * 0.5,
-                    ..color
-                }),
-            }),
+            shadow_blur_radius: 0.0,
+            background: active
+                .background
+                .map(|background| background.scale_alpha(0.5)),
             text_color: Color {
                 a: active.text_color.a * 0.5,
                 ..active.text_color
@@ -65,16 +93,55 @@ pub trait StyleSheet {
     }
 }
 
+/// The interaction status of a button.
+///
+/// It is used to style a button with a closure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    Disabled,
+}
+
+/// Styles a button with a closure that produces a style for every status.
+///
+/// This is a shorthand for simple styles. Implement `StyleSheet` on your own
+/// type for anything more complex.
+impl<F> StyleSheet for F
+where
+    F: Fn(Status) -> Style,
+{
+    fn active(&self) -> Style {
+        self(Status::Active)
+    }
+
+    fn hovered(&self) -> Style {
+        self(Status::Hovered)
+    }
+
+    fn pressed(&self) -> Style {
+        self(Status::Pressed)
+    }
+
+    fn disabled(&self) -> Style {
+        self(Status::Disabled)
+    }
+}
+
 struct Default;
 
 impl StyleSheet for Default {
     fn active(&self) -> Style {
         Style {
             shadow_offset: Vector::new(0.0, 0.0),
+            shadow_blur_radius: 0.0,
+            shadow_color: [0.0, 0.0, 0.0, 0.5].into(),
             background: Some(Background::Color([0.87, 0.87, 0.87].into())),
-            border_radius: 2,
+            border_radius: 2.into(),
             border_width: 1,
             border_color: [0.7, 0.7, 0.7].into(),
+            border_style: BorderStyle::Solid,
             text_color: Color::BLACK,
         }
     }
@@ -16,6 +16,11 @@ pub trait StyleSheet {
     fn active(&self, is_checked: bool) -> Style;
 
     fn hovered(&self, is_checked: bool) -> Style;
+
+    /// Produces the style of a checkbox with keyboard focus.
+    fn focused(&self, is_checked: bool) -> Style {
+        self.hovered(is_checked)
+    }
 }
 
 struct Default;
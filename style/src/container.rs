@@ -1,5 +1,5 @@
 //! Decorate content and apply alignment.
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderStyle, Color};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
@@ -9,6 +9,7 @@ pub struct Style {
     pub border_radius: u16,
     pub border_width: u16,
     pub border_color: Color,
+    pub border_style: BorderStyle,
 }
 
 impl std::default::Default for Style {
@@ -19,6 +20,7 @@ impl std::default::Default for Style {
             border_radius: 0,
             border_width: 0,
             border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
         }
     }
 }
@@ -29,6 +31,19 @@ pub trait StyleSheet {
     fn style(&self) -> Style;
 }
 
+/// Styles a container with a closure that produces its style.
+///
+/// This is a shorthand for simple styles. Implement `StyleSheet` on your own
+/// type for anything more complex.
+impl<F> StyleSheet for F
+where
+    F: Fn() -> Style,
+{
+    fn style(&self) -> Style {
+        self()
+    }
+}
+
 struct Default;
 
 impl StyleSheet for Default {
@@ -39,6 +54,7 @@ impl StyleSheet for Default {
             border_radius: 0,
             border_width: 0,
             border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
         }
     }
 }
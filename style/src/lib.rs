@@ -2,11 +2,15 @@
 //!
 //! It contains a set of styles and stylesheets for most of the built-in
 //! widgets.
+pub mod badge;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+pub mod tooltip;
@@ -0,0 +1,99 @@
+//! Let your users choose a single value from a dropdown list of options.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a pick list.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub background: Background,
+    pub border_radius: BorderRadius,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub icon_size: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: BorderRadius::ZERO,
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+            icon_size: 0.7,
+        }
+    }
+}
+
+/// The appearance of the list of options of a pick list.
+#[derive(Debug, Clone, Copy)]
+pub struct Menu {
+    pub text_color: Color,
+    pub background: Background,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub selected_text_color: Color,
+    pub selected_background: Background,
+    pub hovered_background: Background,
+}
+
+impl std::default::Default for Menu {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+            selected_text_color: Color::WHITE,
+            selected_background: Background::Color([0.4, 0.4, 0.8].into()),
+            hovered_background: Background::Color([0.8, 0.8, 0.9].into()),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a pick list.
+pub trait StyleSheet {
+    /// Produces the style of a closed pick list.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a pick list with the cursor over it or with its
+    /// list of options open.
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of the list of options of a pick list.
+    fn menu(&self) -> Menu;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+
+    fn menu(&self) -> Menu {
+        Menu::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -15,6 +15,29 @@ pub trait StyleSheet {
     fn active(&self) -> Style;
 
     fn hovered(&self) -> Style;
+
+    /// Produces the style of a radio button with keyboard focus.
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// Produces the style of a disabled radio button.
+    fn disabled(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            background: active.background.scale_alpha(0.5),
+            dot_color: Color {
+                a: active.dot_color.a * 0.5,
+                ..active.dot_color
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
@@ -33,6 +33,11 @@ pub trait StyleSheet {
 
     fn value_color(&self) -> Color;
 
+    /// Produces the color of the selected text highlight of a text input.
+    fn selection_color(&self) -> Color {
+        Color::from_rgb(0.8, 0.8, 1.0)
+    }
+
     /// Produces the style of an hovered text input.
     fn hovered(&self) -> Style {
         self.focused()
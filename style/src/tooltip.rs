@@ -0,0 +1,47 @@
+//! Display a floating label when hovering over some content.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a tooltip.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub text_color: Color,
+    pub border_radius: BorderRadius,
+    pub border_width: u16,
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a tooltip.
+pub trait StyleSheet {
+    /// Produces the style of a tooltip.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            background: Background::Color(Color::from_rgb(0.2, 0.2, 0.2)),
+            text_color: Color::WHITE,
+            border_radius: BorderRadius::new(4.0),
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,5 +1,8 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length};
+use crate::{
+    bumpalo, Align, Background, BorderRadius, BorderStyle, Color, Length,
+    Padding,
+};
 
 use std::collections::BTreeMap;
 
@@ -13,7 +16,7 @@ pub enum Rule {
     Row,
 
     /// Padding of the container
-    Padding(u16),
+    Padding(Padding),
 
     /// Spacing between elements
     Spacing(u16),
@@ -27,7 +30,10 @@ impl Rule {
         match self {
             Rule::Column => String::from("c"),
             Rule::Row => String::from("r"),
-            Rule::Padding(padding) => format!("p-{}", padding),
+            Rule::Padding(padding) => format!(
+                "p-{}-{}-{}-{}",
+                padding.top, padding.right, padding.bottom, padding.left
+            ),
             Rule::Spacing(spacing) => format!("s-{}", spacing),
         }
     }
@@ -51,9 +57,12 @@ impl Rule {
             }
             Rule::Padding(padding) => bumpalo::format!(
                 in bump,
-                ".{} {{ box-sizing: border-box; padding: {}px }}",
+                ".{} {{ box-sizing: border-box; padding: {}px {}px {}px {}px }}",
                 class,
-                padding
+                padding.top,
+                padding.right,
+                padding.bottom,
+                padding.left
             )
             .into_bump_str(),
             Rule::Spacing(spacing) => bumpalo::format!(
@@ -174,8 +183,43 @@ pub fn color(Color { r, g, b, a }: Color) -> String {
 ///
 /// [`Background`]: ../struct.Background.html
 pub fn background(background: Background) -> String {
-    match background {
+    match background.normalize() {
         Background::Color(c) => color(c),
+        Background::LinearGradient(gradient) => {
+            let (start, end) = (gradient.start, gradient.end);
+            let angle = (end.x - start.x).atan2(start.y - end.y).to_degrees();
+
+            let stops: Vec<String> = gradient
+                .stops()
+                .map(|(offset, c)| format!("{} {}%", color(c), offset * 100.0))
+                .collect();
+
+            format!("linear-gradient({}deg, {})", angle, stops.join(", "))
+        }
+    }
+}
+
+/// Returns the style value for the given [`BorderRadius`].
+///
+/// [`BorderRadius`]: ../struct.BorderRadius.html
+pub fn border_radius(border_radius: BorderRadius) -> String {
+    let [top_left, top_right, bottom_right, bottom_left] =
+        border_radius.corners();
+
+    format!(
+        "{}px {}px {}px {}px",
+        top_left, top_right, bottom_right, bottom_left
+    )
+}
+
+/// Returns the style value for the given [`BorderStyle`].
+///
+/// [`BorderStyle`]: ../enum.BorderStyle.html
+pub fn border_style(border_style: BorderStyle) -> &'static str {
+    match border_style {
+        BorderStyle::Solid => "solid",
+        BorderStyle::Dashed { .. } => "dashed",
+        BorderStyle::Dotted => "dotted",
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::{Bus, Color, Css, Widget};
+use crate::{css, Align, Bus, Color, Css, Widget};
 
 use dodrio::bumpalo;
 use std::rc::Rc;
@@ -52,6 +52,22 @@ impl<'a, Message> Element<'a, Message> {
         self
     }
 
+    /// Overrides the cross-axis alignment of the [`Element`] inside its parent
+    /// `Row` or `Column`.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn align_self(self, align: Align) -> Element<'a, Message>
+    where
+        Message: 'a,
+    {
+        Element {
+            widget: Box::new(AlignSelf {
+                widget: self.widget,
+                align,
+            }),
+        }
+    }
+
     /// Produces a VDOM node for the [`Element`].
     pub fn node<'b>(
         &self,
@@ -95,3 +111,32 @@ where
             .node(bump, &bus.map(self.mapper.clone()), style_sheet)
     }
 }
+
+struct AlignSelf<'a, Message> {
+    widget: Box<dyn Widget<Message> + 'a>,
+    align: Align,
+}
+
+impl<'a, Message> Widget<Message> for AlignSelf<'a, Message> {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: flex; align-self: {}",
+                    css::align(self.align)
+                )
+                .into_bump_str(),
+            )
+            .children(vec![self.widget.node(bump, bus, style_sheet)])
+            .finish()
+    }
+}
@@ -4,7 +4,7 @@
 //!
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
-use crate::{css, Background, Bus, Css, Element, Length, Widget};
+use crate::{css, Bus, Css, Element, Length, Widget};
 
 pub use iced_style::button::{Style, StyleSheet};
 
@@ -32,6 +32,7 @@ pub struct Button<'a, Message> {
     width: Length,
     height: Length,
     min_width: u32,
+    max_width: Option<u32>,
     min_height: u32,
     padding: u16,
     style: Box<dyn StyleSheet>,
@@ -53,6 +54,7 @@ impl<'a, Message> Button<'a, Message> {
             width: Length::Shrink,
             height: Length::Shrink,
             min_width: 0,
+            max_width: None,
             min_height: 0,
             padding: 5,
             style: Default::default(),
@@ -83,6 +85,17 @@ impl<'a, Message> Button<'a, Message> {
         self
     }
 
+    /// Sets the maximum width of the [`Button`].
+    ///
+    /// The maximum width takes precedence over the minimum width when they
+    /// conflict.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
     /// Sets the minimum height of the [`Button`].
     ///
     /// [`Button`]: struct.Button.html
@@ -116,6 +129,19 @@ impl<'a, Message> Button<'a, Message> {
         self.on_press = Some(Rc::new(on_press));
         self
     }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed.
+    ///
+    /// The message is cloned on every press.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn on_press_message(mut self, message: Message) -> Self
+    where
+        Message: 'static + Clone,
+    {
+        self.on_press = Some(Rc::new(move || message.clone()));
+        self
+    }
 }
 
 /// The local state of a [`Button`].
@@ -148,15 +174,15 @@ impl<'a, Message> Widget<Message> for Button<'a, Message>
         let style = self.style.active();
 
         let padding_class =
-            style_sheet.insert(bump, css::Rule::Padding(self.padding));
+            style_sheet.insert(bump, css::Rule::Padding(self.padding.into()));
 
         let background = match style.background {
             None => String::from("none"),
-            Some(background) => match background {
-                Background::Color(color) => css::color(color),
-            },
+            Some(background) => css::background(background),
         };
 
+        let max_width = self.max_width.unwrap_or(std::u32::MAX);
+
         let mut node = button(bump)
             .attr(
                 "class",
@@ -166,11 +192,12 @@ impl<'a, Message> Widget<Message> for Button<'a, Message>
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "background: {}; border-radius: {}px; width:{}; min-width: {}; color: {}",
+                    "background: {}; border-radius: {}; width:{}; min-width: {}; max-width: {}; color: {}",
                     background,
-                    style.border_radius,
+                    css::border_radius(style.border_radius),
                     css::length(self.width),
-                    css::min_length(self.min_width),
+                    css::min_length(self.min_width.min(max_width)),
+                    css::max_length(max_width),
                     css::color(style.text_color)
                 )
                 .into_bump_str(),
@@ -1,4 +1,4 @@
-use crate::{css, Align, Bus, Css, Element, Length, Widget};
+use crate::{css, Align, Bus, Css, Element, Length, Padding, Widget};
 
 use dodrio::bumpalo;
 use std::u32;
@@ -11,7 +11,7 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message> {
     spacing: u16,
-    padding: u16,
+    padding: Padding,
     width: Length,
     height: Length,
     max_width: u32,
@@ -25,15 +25,22 @@ impl<'a, Message> Column<'a, Message> {
     ///
     /// [`Column`]: struct.Column.html
     pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Column`] with the given elements.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn with_children(children: Vec<Element<'a, Message>>) -> Self {
         Column {
             spacing: 0,
-            padding: 0,
+            padding: Padding::ZERO,
             width: Length::Fill,
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
             align_items: Align::Start,
-            children: Vec::new(),
+            children,
         }
     }
 
@@ -47,11 +54,15 @@ impl<'a, Message> Column<'a, Message> {
         self
     }
 
-    /// Sets the padding of the [`Column`].
+    /// Sets the [`Padding`] of the [`Column`].
+    ///
+    /// You can pass a single `u16` to pad every side uniformly, or a
+    /// `(top, right, bottom, left)` tuple to pad each side separately.
     ///
+    /// [`Padding`]: ../struct.Padding.html
     /// [`Column`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -107,6 +118,17 @@ impl<'a, Message> Column<'a, Message> {
     }
 }
 
+impl<'a, Message> std::iter::FromIterator<Element<'a, Message>>
+    for Column<'a, Message>
+{
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Element<'a, Message>>,
+    {
+        Self::with_children(iter.into_iter().collect())
+    }
+}
+
 impl<'a, Message> Widget<Message> for Column<'a, Message> {
     fn node<'b>(
         &self,
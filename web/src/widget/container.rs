@@ -12,6 +12,7 @@ pub struct Container<'a, Message> {
     height: Length,
     max_width: u32,
     max_height: u32,
+    padding: u16,
     horizontal_alignment: Align,
     vertical_alignment: Align,
     style_sheet: Box<dyn StyleSheet>,
@@ -33,6 +34,7 @@ impl<'a, Message> Container<'a, Message> {
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            padding: 0,
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
             style_sheet: Default::default(),
@@ -56,7 +58,10 @@ impl<'a, Message> Container<'a, Message> {
         self
     }
 
-    /// Sets the maximum width of the [`Container`].
+    /// Sets the maximum width of the contents of the [`Container`].
+    ///
+    /// The [`Container`] keeps its own width, and the contents are aligned
+    /// within it.
     ///
     /// [`Container`]: struct.Container.html
     pub fn max_width(mut self, max_width: u32) -> Self {
@@ -64,7 +69,11 @@ impl<'a, Message> Container<'a, Message> {
         self
     }
 
-    /// Sets the maximum height of the [`Container`] in pixels.
+    /// Sets the maximum height of the contents of the [`Container`] in
+    /// pixels.
+    ///
+    /// The [`Container`] keeps its own height, and the contents are aligned
+    /// within it.
     ///
     /// [`Container`]: struct.Container.html
     pub fn max_height(mut self, max_height: u32) -> Self {
@@ -72,6 +81,16 @@ impl<'a, Message> Container<'a, Message> {
         self
     }
 
+    /// Sets the padding of the [`Container`].
+    ///
+    /// The contents are inset by the padding on all four sides.
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
     /// Centers the contents in the horizontal axis of the [`Container`].
     ///
     /// [`Container`]: struct.Container.html
@@ -115,6 +134,28 @@ where
 
         let style = self.style_sheet.style();
 
+        // The contents are capped by a box that fills the container, so they
+        // are still aligned within the whole container
+        let content = div(bump)
+            .attr(
+                "class",
+                bumpalo::format!(in bump, "{}", column_class).into_bump_str(),
+            )
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "width: 100%; height: 100%; max-width: {}; max-height: {}; align-items: {}; justify-content: {}",
+                    css::max_length(self.max_width),
+                    css::max_length(self.max_height),
+                    css::align(self.horizontal_alignment),
+                    css::align(self.vertical_alignment)
+                )
+                .into_bump_str(),
+            )
+            .children(vec![self.content.node(bump, bus, style_sheet)])
+            .finish();
+
         let node = div(bump)
             .attr(
                 "class",
@@ -124,21 +165,22 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px",
+                    "width: {}; height: {}; padding: {}px; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-style: {}; border-radius: {}px",
                     css::length(self.width),
                     css::length(self.height),
-                    css::max_length(self.max_width),
+                    self.padding,
                     css::align(self.horizontal_alignment),
                     css::align(self.vertical_alignment),
                     style.background.map(css::background).unwrap_or(String::from("initial")),
                     style.text_color.map(css::color).unwrap_or(String::from("inherit")),
                     style.border_width,
                     css::color(style.border_color),
+                    css::border_style(style.border_style),
                     style.border_radius
                 )
                 .into_bump_str(),
             )
-            .children(vec![self.content.node(bump, bus, style_sheet)]);
+            .children(vec![content]);
 
         // TODO: Complete styling
 
@@ -79,8 +79,13 @@ impl<Message> Widget<Message> for ProgressBar {
         use dodrio::builder::*;
 
         let (range_start, range_end) = self.range.clone().into_inner();
-        let amount_filled =
-            (self.value - range_start) / (range_end - range_start).max(1.0);
+        let amount_filled = if range_end > range_start {
+            ((self.value - range_start) / (range_end - range_start))
+                .max(0.0)
+                .min(1.0)
+        } else {
+            0.0
+        };
 
         let style = self.style.style();
 
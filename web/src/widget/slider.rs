@@ -38,6 +38,7 @@ pub struct Slider<'a, Message> {
     _state: &'a mut State,
     range: RangeInclusive<f32>,
     value: f32,
+    step: Option<f32>,
     on_change: Rc<Box<dyn Fn(f32) -> Message>>,
     width: Length,
     style: Box<dyn StyleSheet>,
@@ -69,6 +70,7 @@ impl<'a, Message> Slider<'a, Message> {
             _state: state,
             value: value.max(*range.start()).min(*range.end()),
             range,
+            step: None,
             on_change: Rc::new(Box::new(on_change)),
             width: Length::Fill,
             style: Default::default(),
@@ -83,6 +85,16 @@ impl<'a, Message> Slider<'a, Message> {
         self
     }
 
+    /// Sets the step size of the [`Slider`].
+    ///
+    /// By default, the step is `0.01`.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -110,15 +122,15 @@ where
         let min = bumpalo::format!(in bump, "{}", start);
         let max = bumpalo::format!(in bump, "{}", end);
         let value = bumpalo::format!(in bump, "{}", self.value);
+        let step = bumpalo::format!(in bump, "{}", self.step.unwrap_or(0.01));
 
         let on_change = self.on_change.clone();
         let event_bus = bus.clone();
 
-        // TODO: Make `step` configurable
         // TODO: Styling
         input(bump)
             .attr("type", "range")
-            .attr("step", "0.01")
+            .attr("step", step.into_bump_str())
             .attr("min", min.into_bump_str())
             .attr("max", max.into_bump_str())
             .attr("value", value.into_bump_str())
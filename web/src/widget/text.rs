@@ -20,6 +20,7 @@ pub struct Text {
     size: Option<u16>,
     color: Option<Color>,
     font: Font,
+    letter_spacing: Option<f32>,
     width: Length,
     height: Length,
     horizontal_alignment: HorizontalAlignment,
@@ -36,6 +37,7 @@ impl Text {
             size: None,
             color: None,
             font: Font::Default,
+            letter_spacing: None,
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
@@ -69,6 +71,14 @@ impl Text {
         self
     }
 
+    /// Sets the letter spacing of the [`Text`], in pixels.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = Some(letter_spacing);
+        self
+    }
+
     /// Sets the width of the [`Text`] boundaries.
     ///
     /// [`Text`]: struct.Text.html
@@ -131,14 +141,22 @@ impl<'a, Message> Widget<Message> for Text {
             HorizontalAlignment::Right => "right",
         };
 
+        let justify_content = match self.vertical_alignment {
+            VerticalAlignment::Top => "flex-start",
+            VerticalAlignment::Center => "center",
+            VerticalAlignment::Bottom => "flex-end",
+        };
+
         let style = bumpalo::format!(
             in bump,
-            "width: {}; height: {}; font-size: {}px; color: {}; text-align: {}; font-family: {}",
+            "width: {}; height: {}; font-size: {}px; color: {}; text-align: {}; display: flex; flex-direction: column; justify-content: {}; letter-spacing: {}px; font-family: {}",
             width,
             height,
             self.size.unwrap_or(20),
             color,
             text_align,
+            justify_content,
+            self.letter_spacing.unwrap_or(0.0),
             match self.font {
                 Font::Default => "inherit",
                 Font::External { name, .. } => name,
@@ -154,7 +154,7 @@ where
         use wasm_bindgen::JsCast;
 
         let padding_class =
-            style_sheet.insert(bump, css::Rule::Padding(self.padding));
+            style_sheet.insert(bump, css::Rule::Padding(self.padding.into()));
 
         let on_change = self.on_change.clone();
         let on_submit = self.on_submit.clone();
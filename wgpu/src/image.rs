@@ -4,13 +4,17 @@ mod raster;
 mod vector;
 
 use crate::Transformation;
-use iced_native::{image, svg, Rectangle};
+use iced_native::{image, svg, Color, Rectangle};
 
 use std::mem;
+use std::rc::Rc;
 
 #[cfg(any(feature = "image", feature = "svg"))]
 use std::cell::RefCell;
 
+#[cfg(feature = "image")]
+use iced_native::Vector;
+
 #[derive(Debug)]
 pub struct Pipeline {
     #[cfg(feature = "image")]
@@ -19,6 +23,7 @@ pub struct Pipeline {
     vector_cache: RefCell<vector::Cache>,
 
     pipeline: wgpu::RenderPipeline,
+    premultiplied_pipeline: wgpu::RenderPipeline,
     uniforms: wgpu::Buffer,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
@@ -28,7 +33,15 @@ pub struct Pipeline {
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device) -> Self {
+    #[cfg_attr(not(feature = "svg"), allow(unused_variables))]
+    pub fn new(
+        device: &wgpu::Device,
+        prerasterize_svg_scales: bool,
+        svg_cache_capacity: Option<usize>,
+        asynchronous_svg_rasterization: bool,
+        svg_dpi: Option<u16>,
+        svg_font_family: Option<&'static str>,
+    ) -> Self {
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: wgpu::AddressMode::ClampToEdge,
             address_mode_v: wgpu::AddressMode::ClampToEdge,
@@ -115,7 +128,7 @@ impl Pipeline {
                 .expect("Read image fragment shader as SPIR-V"),
         );
 
-        let pipeline =
+        let create_pipeline = |color_blend| {
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                 layout: &layout,
                 vertex_stage: wgpu::ProgrammableStageDescriptor {
@@ -136,11 +149,7 @@ impl Pipeline {
                 primitive_topology: wgpu::PrimitiveTopology::TriangleList,
                 color_states: &[wgpu::ColorStateDescriptor {
                     format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
+                    color_blend,
                     alpha_blend: wgpu::BlendDescriptor {
                         src_factor: wgpu::BlendFactor::One,
                         dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
@@ -174,13 +183,33 @@ impl Pipeline {
                                 format: wgpu::VertexFormat::Float2,
                                 offset: 4 * 2,
                             },
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 3,
+                                format: wgpu::VertexFormat::Float,
+                                offset: 4 * (2 + 2),
+                            },
                         ],
                     },
                 ],
                 sample_count: 1,
                 sample_mask: !0,
                 alpha_to_coverage_enabled: false,
-            });
+            })
+        };
+
+        let pipeline = create_pipeline(wgpu::BlendDescriptor {
+            src_factor: wgpu::BlendFactor::SrcAlpha,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        });
+
+        // Offscreen textures hold premultiplied colors, so their opacity is
+        // applied through the blend color instead
+        let premultiplied_pipeline = create_pipeline(wgpu::BlendDescriptor {
+            src_factor: wgpu::BlendFactor::BlendColor,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        });
 
         let vertices = device
             .create_buffer_mapped(QUAD_VERTS.len(), wgpu::BufferUsage::VERTEX)
@@ -199,9 +228,17 @@ impl Pipeline {
             #[cfg(feature = "image")]
             raster_cache: RefCell::new(raster::Cache::new()),
             #[cfg(feature = "svg")]
-            vector_cache: RefCell::new(vector::Cache::new()),
+            vector_cache: RefCell::new(
+                vector::Cache::with_capacity(
+                    prerasterize_svg_scales,
+                    svg_cache_capacity.unwrap_or(usize::MAX),
+                )
+                .asynchronous(asynchronous_svg_rasterization)
+                .options(svg_options(svg_dpi, svg_font_family)),
+            ),
 
             pipeline,
+            premultiplied_pipeline,
             uniforms: uniforms_buffer,
             vertices,
             indices,
@@ -211,6 +248,10 @@ impl Pipeline {
         }
     }
 
+    pub fn texture_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.texture_layout
+    }
+
     #[cfg(feature = "image")]
     pub fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
         let mut cache = self.raster_cache.borrow_mut();
@@ -227,6 +268,18 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    #[cfg(feature = "svg")]
+    pub fn scaled_viewport_dimensions(
+        &self,
+        handle: &svg::Handle,
+        scale_factor: f32,
+    ) -> (u32, u32) {
+        let mut cache = self.vector_cache.borrow_mut();
+        let svg = cache.load(&handle);
+
+        svg.scaled_dimensions(scale_factor)
+    }
+
     pub fn draw(
         &mut self,
         device: &mut wgpu::Device,
@@ -235,7 +288,7 @@ impl Pipeline {
         transformation: Transformation,
         bounds: Rectangle<u32>,
         target: &wgpu::TextureView,
-        _scale: f32,
+        scale: f32,
     ) {
         let uniforms_buffer = device
             .create_buffer_mapped(1, wgpu::BufferUsage::COPY_SRC)
@@ -269,15 +322,16 @@ impl Pipeline {
                     #[cfg(not(feature = "image"))]
                     None
                 }
-                Handle::Vector(_handle) => {
+                Handle::Vector(_handle, _color) => {
                     #[cfg(feature = "svg")]
                     {
                         let mut cache = self.vector_cache.borrow_mut();
 
                         cache.upload(
                             _handle,
+                            *_color,
                             image.scale,
-                            _scale,
+                            scale,
                             device,
                             encoder,
                             &self.texture_layout,
@@ -287,14 +341,25 @@ impl Pipeline {
                     #[cfg(not(feature = "svg"))]
                     None
                 }
+                Handle::Texture(bind_group) => Some(bind_group.clone()),
+            };
+
+            let scissor = match &image.clip {
+                Some(clip) => scissor(clip, bounds, scale),
+                None => bounds,
             };
 
+            if scissor.width == 0 || scissor.height == 0 {
+                continue;
+            }
+
             if let Some(texture) = uploaded_texture {
                 let instance_buffer = device
                     .create_buffer_mapped(1, wgpu::BufferUsage::COPY_SRC)
                     .fill_from_slice(&[Instance {
                         _position: image.position,
                         _scale: image.scale,
+                        _opacity: image.opacity,
                     }]);
 
                 encoder.copy_buffer_to_buffer(
@@ -326,7 +391,20 @@ impl Pipeline {
                         },
                     );
 
-                    render_pass.set_pipeline(&self.pipeline);
+                    if let Handle::Texture(_) = image.handle {
+                        let opacity = f64::from(image.opacity);
+
+                        render_pass.set_pipeline(&self.premultiplied_pipeline);
+                        render_pass.set_blend_color(wgpu::Color {
+                            r: opacity,
+                            g: opacity,
+                            b: opacity,
+                            a: opacity,
+                        });
+                    } else {
+                        render_pass.set_pipeline(&self.pipeline);
+                    }
+
                     render_pass.set_bind_group(0, &self.constants, &[]);
                     render_pass.set_bind_group(1, &texture, &[]);
                     render_pass.set_index_buffer(&self.indices, 0);
@@ -335,10 +413,10 @@ impl Pipeline {
                         &[(&self.vertices, 0), (&self.instances, 0)],
                     );
                     render_pass.set_scissor_rect(
-                        bounds.x,
-                        bounds.y,
-                        bounds.width,
-                        bounds.height,
+                        scissor.x,
+                        scissor.y,
+                        scissor.width,
+                        scissor.height,
                     );
 
                     render_pass.draw_indexed(
@@ -358,17 +436,160 @@ impl Pipeline {
         #[cfg(feature = "svg")]
         self.vector_cache.borrow_mut().trim();
     }
+
+    #[cfg_attr(not(feature = "svg"), allow(unused_variables))]
+    pub fn set_waker(&mut self, waker: Box<dyn Fn() + Send + Sync>) {
+        #[cfg(feature = "svg")]
+        self.vector_cache.borrow_mut().set_waker(waker);
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn clear_svg_cache(&mut self) {
+        self.vector_cache.borrow_mut().clear();
+    }
 }
 
 pub struct Image {
     pub handle: Handle,
     pub position: [f32; 2],
     pub scale: [f32; 2],
+    pub opacity: f32,
+    pub clip: Option<Rectangle>,
+}
+
+#[cfg(feature = "image")]
+impl Image {
+    /// Splits a raster image into the nine instances of a nine-slice image.
+    ///
+    /// Every instance is scaled so the matching slice of the texture fills its
+    /// destination, which is then isolated by the `clip` rectangle. The `clip`
+    /// is relative to the given layer `offset`.
+    pub fn nine_slice(
+        handle: &image::Handle,
+        bounds: Rectangle,
+        (width, height): (u32, u32),
+        nine_slice: image::NineSlice,
+        opacity: f32,
+        offset: Vector<u32>,
+    ) -> Vec<Image> {
+        let (width, height) = (width as f32, height as f32);
+
+        let columns = slices(
+            width,
+            f32::from(nine_slice.left),
+            f32::from(nine_slice.right),
+            bounds.x,
+            bounds.width,
+        );
+
+        let rows = slices(
+            height,
+            f32::from(nine_slice.top),
+            f32::from(nine_slice.bottom),
+            bounds.y,
+            bounds.height,
+        );
+
+        let mut images = Vec::with_capacity(9);
+
+        for (source_y, target_y) in rows.iter() {
+            for (source_x, target_x) in columns.iter() {
+                let source_width = source_x.1 - source_x.0;
+                let source_height = source_y.1 - source_y.0;
+                let target_width = target_x.1 - target_x.0;
+                let target_height = target_y.1 - target_y.0;
+
+                if source_width <= 0.0
+                    || source_height <= 0.0
+                    || target_width <= 0.0
+                    || target_height <= 0.0
+                {
+                    continue;
+                }
+
+                let scale_x = target_width * width / source_width;
+                let scale_y = target_height * height / source_height;
+
+                images.push(Image {
+                    handle: Handle::Raster(handle.clone()),
+                    position: [
+                        target_x.0 - source_x.0 * scale_x / width,
+                        target_y.0 - source_y.0 * scale_y / height,
+                    ],
+                    scale: [scale_x, scale_y],
+                    opacity,
+                    clip: Some(Rectangle {
+                        x: target_x.0 - offset.x as f32,
+                        y: target_y.0 - offset.y as f32,
+                        width: target_width,
+                        height: target_height,
+                    }),
+                });
+            }
+        }
+
+        images
+    }
+}
+
+/// Computes the source and target ranges of the three slices of a nine-slice
+/// image along a single axis.
+#[cfg(feature = "image")]
+fn slices(
+    size: f32,
+    start: f32,
+    end: f32,
+    position: f32,
+    length: f32,
+) -> [((f32, f32), (f32, f32)); 3] {
+    let start = start.min(size);
+    let end = end.min(size - start);
+
+    // Shrink the fixed edges if they do not fit in the target
+    let fit = if start + end > length && start + end > 0.0 {
+        length / (start + end)
+    } else {
+        1.0
+    };
+
+    let target_start = position + start * fit;
+    let target_end = position + length - end * fit;
+
+    [
+        ((0.0, start), (position, target_start)),
+        ((start, size - end), (target_start, target_end)),
+        ((size - end, size), (target_end, position + length)),
+    ]
+}
+
+/// Turns a clip rectangle in logical coordinates into a scissor rectangle in
+/// physical coordinates, contained in the given `bounds`.
+fn scissor(
+    clip: &Rectangle,
+    bounds: Rectangle<u32>,
+    scale: f32,
+) -> Rectangle<u32> {
+    let left = (clip.x * scale).round().max(bounds.x as f32);
+    let top = (clip.y * scale).round().max(bounds.y as f32);
+    let right = ((clip.x + clip.width) * scale)
+        .round()
+        .min((bounds.x + bounds.width) as f32);
+    let bottom = ((clip.y + clip.height) * scale)
+        .round()
+        .min((bounds.y + bounds.height) as f32);
+
+    Rectangle {
+        x: left as u32,
+        y: top as u32,
+        width: (right - left).max(0.0) as u32,
+        height: (bottom - top).max(0.0) as u32,
+    }
 }
 
 pub enum Handle {
     Raster(image::Handle),
-    Vector(svg::Handle),
+    Vector(svg::Handle, Option<Color>),
+    Texture(Rc<wgpu::BindGroup>),
 }
 
 #[repr(C)]
@@ -399,6 +620,7 @@ const QUAD_VERTS: [Vertex; 4] = [
 struct Instance {
     _position: [f32; 2],
     _scale: [f32; 2],
+    _opacity: f32,
 }
 
 #[repr(C)]
@@ -406,3 +628,18 @@ struct Instance {
 struct Uniforms {
     transform: [f32; 16],
 }
+
+#[cfg(feature = "svg")]
+fn svg_options(dpi: Option<u16>, font_family: Option<&str>) -> resvg::Options {
+    let mut options = resvg::Options::default();
+
+    if let Some(dpi) = dpi {
+        options.usvg.dpi = f64::from(dpi);
+    }
+
+    if let Some(font_family) = font_family {
+        options.usvg.font_family = font_family.to_owned();
+    }
+
+    options
+}
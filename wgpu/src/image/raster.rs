@@ -21,8 +21,8 @@ impl Memory {
         match self {
             Memory::Host(image) => image.dimensions(),
             Memory::Device { width, height, .. } => (*width, *height),
-            Memory::NotFound => (1, 1),
-            Memory::Invalid => (1, 1),
+            Memory::NotFound => (0, 0),
+            Memory::Invalid => (0, 0),
         }
     }
 
@@ -132,6 +132,7 @@ impl Cache {
             return self.get(handle).unwrap();
         }
 
+        // Animated formats, like GIF, only decode their first frame
         let memory = match handle.data() {
             image::Data::Path(path) => {
                 if let Ok(image) = ::image::open(path) {
@@ -174,3 +175,64 @@ impl Cache {
         self.map.contains_key(&handle.id())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> image::Handle {
+        let pixels = ::image::ImageBuffer::from_pixel(
+            width,
+            height,
+            ::image::Rgba([255, 0, 0, 255]),
+        );
+
+        let mut bytes = Vec::new();
+
+        ::image::DynamicImage::ImageRgba8(pixels)
+            .write_to(&mut bytes, ::image::ImageOutputFormat::PNG)
+            .expect("Encode PNG");
+
+        image::Handle::from_memory(bytes)
+    }
+
+    #[test]
+    fn repeated_loads_hit_the_cache() {
+        let mut cache = Cache::new();
+        let handle = png(3, 2);
+
+        let memory = cache.load(&handle);
+        assert_eq!(memory.dimensions(), (3, 2));
+
+        // The image is not decoded again, so the replacement is kept
+        *memory = Memory::Invalid;
+
+        assert!(matches!(cache.load(&handle), Memory::Invalid));
+    }
+
+    #[test]
+    fn trim_drops_unused_images() {
+        let mut cache = Cache::new();
+        let used = png(1, 1);
+        let unused = png(2, 2);
+
+        let _ = cache.load(&used);
+        let _ = cache.load(&unused);
+        cache.trim();
+
+        let _ = cache.load(&used);
+        cache.trim();
+
+        assert!(cache.contains(&used));
+        assert!(!cache.contains(&unused));
+    }
+
+    #[test]
+    fn invalid_bytes_are_not_decoded() {
+        let mut cache = Cache::new();
+        let handle = image::Handle::from_memory(vec![1, 2, 3]);
+
+        assert!(matches!(cache.load(&handle), Memory::Invalid));
+        assert_eq!(cache.load(&handle).dimensions(), (0, 0));
+    }
+}
@@ -1,7 +1,13 @@
-use iced_native::svg;
+use iced_native::{svg, Color};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::{Hash, Hasher},
     rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
 };
 
 pub enum Svg {
@@ -11,14 +17,25 @@ pub enum Svg {
 
 impl Svg {
     pub fn viewport_dimensions(&self) -> (u32, u32) {
-        match self {
+        self.scaled_dimensions(1.0)
+    }
+
+    /// Returns the dimensions of the viewport of the SVG in physical pixels,
+    /// for the given scale factor.
+    ///
+    /// The dimensions are rounded the same way `Cache::upload` rounds the
+    /// size of a rasterization.
+    pub fn scaled_dimensions(&self, scale_factor: f32) -> (u32, u32) {
+        let (width, height) = match self {
             Svg::Loaded { tree } => {
                 let size = tree.svg_node().size;
 
-                (size.width() as u32, size.height() as u32)
+                (size.width() as f32, size.height() as f32)
             }
-            Svg::NotFound => (1, 1),
-        }
+            Svg::NotFound => (1.0, 1.0),
+        };
+
+        physical_size(width, height, scale_factor)
     }
 }
 
@@ -28,169 +45,1184 @@ impl std::fmt::Debug for Svg {
     }
 }
 
+/// The scales, relative to the first requested size, at which an SVG is
+/// rasterized at once when scale sets are enabled.
+const SCALE_SET: [f32; 3] = [1.0, 1.5, 2.0];
+
+/// The amount of threads used to rasterize SVGs asynchronously.
+const RASTERIZER_THREADS: usize = 2;
+
 #[derive(Debug)]
 pub struct Cache {
     svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32), Rc<wgpu::BindGroup>>,
+    rasterized: Rasterized<Rc<wgpu::BindGroup>>,
+    errors: HashMap<u64, String>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32)>,
+    prerasterize_scales: bool,
+    options: resvg::Options,
+    rasterizer: Option<Rasterizer>,
+    pending: HashMap<(u64, u32, u32), (u64, Arc<AtomicBool>)>,
 }
 
 impl Cache {
-    pub fn new() -> Self {
+    pub fn new(prerasterize_scales: bool) -> Self {
+        Self::with_capacity(prerasterize_scales, usize::MAX)
+    }
+
+    /// Creates a cache that keeps at most `max_rasterized` rasterizations,
+    /// evicting the least recently used ones first.
+    ///
+    /// Rasterizations used in the current frame are never evicted, so the
+    /// limit may be exceeded temporarily.
+    pub fn with_capacity(
+        prerasterize_scales: bool,
+        max_rasterized: usize,
+    ) -> Self {
         Self {
             svgs: HashMap::new(),
-            rasterized: HashMap::new(),
+            rasterized: Rasterized::new(max_rasterized),
+            errors: HashMap::new(),
             svg_hits: HashSet::new(),
-            rasterized_hits: HashSet::new(),
+            prerasterize_scales,
+            options: resvg::Options::default(),
+            rasterizer: None,
+            pending: HashMap::new(),
         }
     }
 
+    /// Sets whether SVGs are rasterized on background threads.
+    ///
+    /// An asynchronous rasterization is drawn as nothing until it is ready.
+    /// Then, it is uploaded in a later frame.
+    pub fn asynchronous(mut self, asynchronous: bool) -> Self {
+        self.rasterizer = if asynchronous {
+            Some(Rasterizer::new())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Sets the function called from a background thread every time an
+    /// asynchronous rasterization is ready to be uploaded.
+    ///
+    /// It should request a redraw, since nothing else will.
+    pub fn set_waker(&mut self, waker: Box<dyn Fn() + Send + Sync>) {
+        if let Some(rasterizer) = &self.rasterizer {
+            if let Ok(mut current) = rasterizer.waker.lock() {
+                *current = Some(waker);
+            }
+        }
+    }
+
+    /// Sets the options used to parse and rasterize SVGs.
+    ///
+    /// They are reused for every SVG.
+    pub fn options(mut self, options: resvg::Options) -> Self {
+        self.options = options;
+        self
+    }
+
     pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
         if self.svgs.contains_key(&handle.id()) {
+            // Handles created separately for the same SVG do not share their
+            // status, so it is reported again, but never downgraded
+            if handle.status() == svg::Status::Loading {
+                self.report(handle);
+            }
+
             return self.svgs.get(&handle.id()).unwrap();
         }
 
-        let opt = resvg::Options::default();
+        let svg = match parse(handle.data(), &self.options) {
+            Ok(tree) => {
+                // Asynchronous rasterizations report when they are ready
+                if self.rasterizer.is_none() {
+                    handle.report(svg::Status::Loaded, None);
+                }
+
+                Svg::Loaded { tree }
+            }
+            Err(error) => {
+                let error = error.to_string();
+
+                log::warn!("Failed to load SVG {:?}: {}", handle.data(), error);
 
-        let svg = match resvg::usvg::Tree::from_file(handle.path(), &opt.usvg) {
-            Ok(tree) => Svg::Loaded { tree },
-            Err(_) => Svg::NotFound,
+                handle.report(svg::Status::NotFound, Some(error.clone()));
+
+                let _ = self.errors.insert(handle.id(), error);
+
+                Svg::NotFound
+            }
         };
 
         let _ = self.svgs.insert(handle.id(), svg);
         self.svgs.get(&handle.id()).unwrap()
     }
 
+    /// Reports the status of an SVG that is already loaded to the given
+    /// handle.
+    fn report(&self, handle: &svg::Handle) {
+        let id = handle.id();
+
+        match self.svgs.get(&id) {
+            Some(Svg::NotFound) => handle
+                .report(svg::Status::NotFound, self.errors.get(&id).cloned()),
+            Some(Svg::Loaded { .. }) => {
+                let is_pending =
+                    self.pending.values().any(|(svg, _)| *svg == id);
+
+                if !is_pending {
+                    handle.report(svg::Status::Loaded, None);
+                }
+            }
+            None => {}
+        }
+    }
+
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
+        color: Option<Color>,
         [width, height]: [f32; 2],
         scale: f32,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         texture_layout: &wgpu::BindGroupLayout,
     ) -> Option<Rc<wgpu::BindGroup>> {
-        let id = handle.id();
+        // Rasterizations with different tints are cached separately
+        let id = rasterization_id(handle.id(), color);
 
-        let (width, height) = (
-            (scale * width).round() as u32,
-            (scale * height).round() as u32,
-        );
+        self.receive(device, encoder, texture_layout);
+
+        let (width, height) = physical_size(width, height, scale);
 
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
-        // It would be cool to be able to smooth resize the `svg` example.
-        if let Some(bind_group) = self.rasterized.get(&(id, width, height)) {
-            let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert((id, width, height));
+        // Scale sets mitigate this by rasterizing a few sizes at once and
+        // letting the sampler scale the nearest one.
+        if let Some(bind_group) = self.rasterized.get(id, width, height) {
+            let _ = self.svg_hits.insert(handle.id());
 
-            return Some(bind_group.clone());
+            return Some(bind_group);
         }
 
+        let sizes: Vec<(u32, u32)> = if self.prerasterize_scales {
+            SCALE_SET
+                .iter()
+                .map(|scale| {
+                    (
+                        (width as f32 * scale).round() as u32,
+                        (height as f32 * scale).round() as u32,
+                    )
+                })
+                .collect()
+        } else {
+            vec![(width, height)]
+        };
+
         match self.load(handle) {
-            Svg::Loaded { tree } => {
-                if width == 0 || height == 0 {
-                    return None;
-                }
+            Svg::Loaded { .. } if width > 0 && height > 0 => {}
+            _ => return None,
+        }
 
-                let extent = wgpu::Extent3d {
-                    width,
-                    height,
-                    depth: 1,
-                };
+        let _ = self.svg_hits.insert(handle.id());
+
+        if let Some(rasterizer) = &self.rasterizer {
+            let key = (id, width, height);
+
+            if !self.pending.contains_key(&key) {
+                let cancelled = Arc::new(AtomicBool::new(false));
 
-                let texture = device.create_texture(&wgpu::TextureDescriptor {
-                    size: extent,
-                    array_layer_count: 1,
-                    mip_level_count: 1,
-                    sample_count: 1,
-                    dimension: wgpu::TextureDimension::D2,
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    usage: wgpu::TextureUsage::COPY_DST
-                        | wgpu::TextureUsage::SAMPLED,
+                handle.report(svg::Status::Loading, None);
+
+                rasterizer.rasterize(Job {
+                    key,
+                    handle: handle.clone(),
+                    options: self.options.clone(),
+                    color,
+                    sizes,
+                    cancelled: cancelled.clone(),
                 });
 
-                let temp_buf = {
-                    let screen_size =
-                        resvg::ScreenSize::new(width, height).unwrap();
-
-                    let mut canvas = resvg::raqote::DrawTarget::new(
-                        width as i32,
-                        height as i32,
-                    );
-
-                    resvg::backend_raqote::render_to_canvas(
-                        &tree,
-                        &resvg::Options::default(),
-                        screen_size,
-                        &mut canvas,
-                    );
-
-                    let slice = canvas.get_data();
-
-                    device
-                        .create_buffer_mapped(
-                            slice.len(),
-                            wgpu::BufferUsage::COPY_SRC,
-                        )
-                        .fill_from_slice(slice)
-                };
+                let _ = self.pending.insert(key, (handle.id(), cancelled));
+            }
 
-                encoder.copy_buffer_to_texture(
-                    wgpu::BufferCopyView {
-                        buffer: &temp_buf,
-                        offset: 0,
-                        row_pitch: 4 * width as u32,
-                        image_height: height as u32,
-                    },
-                    wgpu::TextureCopyView {
-                        texture: &texture,
-                        array_layer: 0,
-                        mip_level: 0,
-                        origin: wgpu::Origin3d {
-                            x: 0.0,
-                            y: 0.0,
-                            z: 0.0,
-                        },
-                    },
-                    extent,
-                );
+            // Keep the pending rasterization alive until it is picked up
+            self.rasterized.keep(key);
 
-                let bind_group =
-                    device.create_bind_group(&wgpu::BindGroupDescriptor {
-                        layout: texture_layout,
-                        bindings: &[wgpu::Binding {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(
-                                &texture.create_default_view(),
-                            ),
-                        }],
-                    });
+            return None;
+        }
 
-                let bind_group = Rc::new(bind_group);
+        let pixels: Vec<Vec<u32>> = match &self.svgs[&handle.id()] {
+            Svg::Loaded { tree } => sizes
+                .iter()
+                .map(|&(width, height)| {
+                    render(tree, &self.options, color, width, height)
+                })
+                .collect(),
+            Svg::NotFound => return None,
+        };
 
-                let _ = self
-                    .rasterized
-                    .insert((id, width, height), bind_group.clone());
+        self.insert(
+            (id, width, height),
+            sizes,
+            pixels,
+            device,
+            encoder,
+            texture_layout,
+        )
+    }
 
-                let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert((id, width, height));
+    /// Uploads the rasterizations of an SVG at the given sizes and records
+    /// them as a scale set, if needed.
+    ///
+    /// It returns the rasterization of the first size.
+    fn insert(
+        &mut self,
+        key: (u64, u32, u32),
+        sizes: Vec<(u32, u32)>,
+        pixels: Vec<Vec<u32>>,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture_layout: &wgpu::BindGroupLayout,
+    ) -> Option<Rc<wgpu::BindGroup>> {
+        let bind_groups = sizes
+            .into_iter()
+            .zip(pixels)
+            .map(|((width, height), pixels)| {
+                let bind_group = upload_texture(
+                    &pixels,
+                    width,
+                    height,
+                    device,
+                    encoder,
+                    texture_layout,
+                );
 
-                Some(bind_group)
+                ((width, height), Rc::new(bind_group))
+            })
+            .collect();
+
+        self.rasterized
+            .insert(key, bind_groups, self.prerasterize_scales)
+    }
+
+    /// Uploads the asynchronous rasterizations that are ready.
+    fn receive(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture_layout: &wgpu::BindGroupLayout,
+    ) {
+        let rasterizations: Vec<Rasterization> = match &self.rasterizer {
+            Some(rasterizer) => rasterizer.results.try_iter().collect(),
+            None => return,
+        };
+
+        for Rasterization { key, sizes, pixels } in rasterizations {
+            // Rasterizations cancelled in the meantime are discarded
+            if self.pending.remove(&key).is_some() && !pixels.is_empty() {
+                let _ = self.insert(
+                    key,
+                    sizes,
+                    pixels,
+                    device,
+                    encoder,
+                    texture_layout,
+                );
             }
-            Svg::NotFound => None,
         }
     }
 
+    /// Drops every SVG and rasterization in the cache.
+    pub fn clear(&mut self) {
+        self.svgs.clear();
+        self.rasterized.clear();
+        self.errors.clear();
+        self.svg_hits.clear();
+
+        for (_, cancelled) in self.pending.values() {
+            cancelled.store(true, Ordering::Relaxed);
+        }
+
+        self.pending.clear();
+    }
+
     pub fn trim(&mut self) {
         let svg_hits = &self.svg_hits;
-        let rasterized_hits = &self.rasterized_hits;
+        let rasterized_hits = self.rasterized.trim();
 
         self.svgs.retain(|k, _| svg_hits.contains(k));
-        self.rasterized.retain(|k, _| rasterized_hits.contains(k));
+        self.errors.retain(|k, _| svg_hits.contains(k));
+
+        // Cancel the pending rasterizations that are not needed anymore
+        self.pending.retain(|k, (_, cancelled)| {
+            let is_hit = rasterized_hits.contains(k);
+
+            if !is_hit {
+                cancelled.store(true, Ordering::Relaxed);
+            }
+
+            is_hit
+        });
         self.svg_hits.clear();
-        self.rasterized_hits.clear();
+    }
+}
+
+/// The rasterizations of the SVGs in a `Cache`, along with the scale sets
+/// they belong to and how recently they were used.
+#[derive(Debug)]
+struct Rasterized<T> {
+    entries: HashMap<(u64, u32, u32), T>,
+    scale_sets: HashMap<(u64, u32, u32), Vec<(u32, u32)>>,
+    hits: HashSet<(u64, u32, u32)>,
+    last_used: HashMap<(u64, u32, u32), u64>,
+    uses: u64,
+    capacity: usize,
+}
+
+impl<T: Clone> Rasterized<T> {
+    fn new(capacity: usize) -> Self {
+        Rasterized {
+            entries: HashMap::new(),
+            scale_sets: HashMap::new(),
+            hits: HashSet::new(),
+            last_used: HashMap::new(),
+            uses: 0,
+            capacity,
+        }
+    }
+
+    /// Returns the rasterization of an SVG with the given size, or the
+    /// nearest one of its scale sets, and marks it as used.
+    fn get(&mut self, id: u64, width: u32, height: u32) -> Option<T> {
+        let key = if self.entries.contains_key(&(id, width, height)) {
+            (id, width, height)
+        } else {
+            self.nearest_in_scale_sets(id, width, height)?
+        };
+
+        self.hit(key);
+        self.entries.get(&key).cloned()
+    }
+
+    /// Stores the rasterizations of an SVG, recording them as a scale set
+    /// when needed, and evicts the least recently used ones if the capacity
+    /// is exceeded.
+    ///
+    /// It returns the first rasterization.
+    fn insert(
+        &mut self,
+        key: (u64, u32, u32),
+        rasterizations: Vec<((u32, u32), T)>,
+        is_scale_set: bool,
+    ) -> Option<T> {
+        let (id, _, _) = key;
+        let first = rasterizations.first().map(|(_, value)| value.clone());
+
+        let mut sizes = Vec::with_capacity(rasterizations.len());
+
+        for ((width, height), value) in rasterizations {
+            let _ = self.entries.insert((id, width, height), value);
+
+            self.uses += 1;
+            let _ = self.last_used.insert((id, width, height), self.uses);
+
+            sizes.push((width, height));
+        }
+
+        if is_scale_set {
+            let _ = self.scale_sets.insert(key, sizes);
+        }
+
+        self.hit(key);
+        self.evict();
+
+        first
+    }
+
+    /// Keeps a rasterization alive until the end of the current frame,
+    /// without marking it as used.
+    fn keep(&mut self, key: (u64, u32, u32)) {
+        let _ = self.hits.insert(key);
+    }
+
+    /// Marks a rasterization as used in the current frame.
+    fn hit(&mut self, key: (u64, u32, u32)) {
+        self.uses += 1;
+
+        let _ = self.hits.insert(key);
+        let _ = self.last_used.insert(key, self.uses);
+    }
+
+    /// Evicts the least recently used rasterizations until the capacity is
+    /// respected, skipping the ones used in the current frame.
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            let hits = &self.hits;
+
+            let least_recently_used = self
+                .last_used
+                .iter()
+                .filter(|(key, _)| !hits.contains(key))
+                .min_by_key(|(_, uses)| **uses)
+                .map(|(key, _)| *key);
+
+            match least_recently_used {
+                Some(key) => {
+                    let _ = self.entries.remove(&key);
+                    let _ = self.last_used.remove(&key);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Finds the smallest rasterization of a scale set that is at least as
+    /// big as the given size, as long as the size falls within the range of
+    /// the set.
+    fn nearest_in_scale_sets(
+        &self,
+        id: u64,
+        width: u32,
+        height: u32,
+    ) -> Option<(u64, u32, u32)> {
+        self.scale_sets
+            .iter()
+            .filter(|((set_id, min_width, min_height), _)| {
+                *set_id == id && *min_width <= width && *min_height <= height
+            })
+            .filter_map(|(_, sizes)| {
+                sizes
+                    .iter()
+                    .filter(|(w, h)| {
+                        *w >= width
+                            && *h >= height
+                            && self.entries.contains_key(&(id, *w, *h))
+                    })
+                    .min_by_key(|(w, h)| w * h)
+            })
+            .min_by_key(|(w, h)| w * h)
+            .map(|&(w, h)| (id, w, h))
+    }
+
+    /// Drops the rasterizations that were not used in the current frame and
+    /// starts a new one.
+    ///
+    /// It returns the rasterizations that were used.
+    fn trim(&mut self) -> HashSet<(u64, u32, u32)> {
+        let hits = &mut self.hits;
+
+        // Keep a whole scale set alive when any of its members is hit
+        self.scale_sets.retain(|(id, _, _), sizes| {
+            let is_hit =
+                sizes.iter().any(|&(w, h)| hits.contains(&(*id, w, h)));
+
+            if is_hit {
+                hits.extend(sizes.iter().map(|&(w, h)| (*id, w, h)));
+            }
+
+            is_hit
+        });
+
+        let hits = std::mem::take(&mut self.hits);
+
+        self.entries.retain(|k, _| hits.contains(k));
+        self.last_used.retain(|k, _| hits.contains(k));
+
+        hits
+    }
+
+    /// Drops every rasterization.
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.scale_sets.clear();
+        self.hits.clear();
+        self.last_used.clear();
+    }
+}
+
+/// A pool of threads that rasterize SVGs in the background.
+struct Rasterizer {
+    jobs: mpsc::Sender<Job>,
+    results: mpsc::Receiver<Rasterization>,
+    waker: Arc<Mutex<Option<Box<dyn Fn() + Send + Sync>>>>,
+}
+
+impl Rasterizer {
+    fn new() -> Self {
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        let (sender, results) = mpsc::channel();
+
+        let receiver = Arc::new(Mutex::new(receiver));
+        let waker: Arc<Mutex<Option<Box<dyn Fn() + Send + Sync>>>> =
+            Arc::new(Mutex::new(None));
+
+        for _ in 0..RASTERIZER_THREADS {
+            let receiver = receiver.clone();
+            let sender = sender.clone();
+            let waker = waker.clone();
+
+            // The threads stop when the `Rasterizer` is dropped
+            let _ = thread::spawn(move || loop {
+                let job = match receiver
+                    .lock()
+                    .ok()
+                    .and_then(|receiver| receiver.recv().ok())
+                {
+                    Some(job) => job,
+                    None => break,
+                };
+
+                if job.cancelled.load(Ordering::Relaxed) {
+                    continue;
+                }
+
+                let handle = job.handle.clone();
+                let rasterization = job.run();
+                let is_rasterized = !rasterization.pixels.is_empty();
+
+                if sender.send(rasterization).is_err() {
+                    break;
+                }
+
+                // The rasterization is uploaded in the next frame
+                if is_rasterized {
+                    handle.report(svg::Status::Loaded, None);
+                }
+
+                // The result is only uploaded when drawing, so we need to
+                // wake up the event loop
+                if let Ok(waker) = waker.lock() {
+                    if let Some(wake) = waker.as_ref() {
+                        wake();
+                    }
+                }
+            });
+        }
+
+        Rasterizer {
+            jobs,
+            results,
+            waker,
+        }
+    }
+
+    fn rasterize(&self, job: Job) {
+        // Sending only fails if every thread panicked, in which case the SVG
+        // is never drawn
+        let _ = self.jobs.send(job);
+    }
+}
+
+impl std::fmt::Debug for Rasterizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Rasterizer")
+    }
+}
+
+/// A rasterization of an SVG at some sizes, to be run in the background.
+struct Job {
+    key: (u64, u32, u32),
+    handle: svg::Handle,
+    options: resvg::Options,
+    color: Option<Color>,
+    sizes: Vec<(u32, u32)>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Job {
+    fn run(self) -> Rasterization {
+        // `usvg` trees cannot be sent between threads, so the SVG is parsed
+        // again here
+        let pixels = match parse(self.handle.data(), &self.options) {
+            Ok(tree) => self
+                .sizes
+                .iter()
+                .map(|&(width, height)| {
+                    render(&tree, &self.options, self.color, width, height)
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        Rasterization {
+            key: self.key,
+            sizes: self.sizes,
+            pixels,
+        }
+    }
+}
+
+/// The result of a `Job`.
+struct Rasterization {
+    key: (u64, u32, u32),
+    sizes: Vec<(u32, u32)>,
+    pixels: Vec<Vec<u32>>,
+}
+
+fn parse(
+    data: &svg::Data,
+    options: &resvg::Options,
+) -> Result<resvg::usvg::Tree, resvg::usvg::Error> {
+    match data {
+        svg::Data::Path(path) => {
+            resvg::usvg::Tree::from_file(path, &options.usvg)
+        }
+        svg::Data::Bytes(bytes) => {
+            resvg::usvg::Tree::from_data(bytes, &options.usvg)
+        }
+    }
+}
+
+/// Rasterizes an SVG into premultiplied ARGB pixels, tinting them with the
+/// given color, if any.
+fn render(
+    tree: &resvg::usvg::Tree,
+    options: &resvg::Options,
+    color: Option<Color>,
+    width: u32,
+    height: u32,
+) -> Vec<u32> {
+    let screen_size = resvg::ScreenSize::new(width, height).unwrap();
+
+    let mut canvas =
+        resvg::raqote::DrawTarget::new(width as i32, height as i32);
+
+    resvg::backend_raqote::render_to_canvas(
+        tree,
+        options,
+        screen_size,
+        &mut canvas,
+    );
+
+    let mut pixels = canvas.get_data().to_vec();
+
+    if let Some(color) = color {
+        tint(&mut pixels, color);
+    }
+
+    pixels
+}
+
+/// Uploads some rasterized pixels to a new texture, along with its mipmaps.
+fn upload_texture(
+    pixels: &[u32],
+    width: u32,
+    height: u32,
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    texture_layout: &wgpu::BindGroupLayout,
+) -> wgpu::BindGroup {
+    let descriptor = texture_descriptor(width, height);
+    let mip_level_count = descriptor.mip_level_count;
+
+    let texture = device.create_texture(&descriptor);
+
+    let mut pixels = pixels.to_vec();
+    let (mut level_width, mut level_height) = (width, height);
+
+    for mip_level in 0..mip_level_count {
+        if mip_level > 0 {
+            pixels = downsample(&pixels, level_width, level_height);
+            level_width = (level_width / 2).max(1);
+            level_height = (level_height / 2).max(1);
+        }
+
+        let temp_buf = device
+            .create_buffer_mapped(pixels.len(), wgpu::BufferUsage::COPY_SRC)
+            .fill_from_slice(&pixels);
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &temp_buf,
+                offset: 0,
+                row_pitch: 4 * level_width,
+                image_height: level_height,
+            },
+            wgpu::TextureCopyView {
+                texture: &texture,
+                array_layer: 0,
+                mip_level,
+                origin: wgpu::Origin3d {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            },
+            wgpu::Extent3d {
+                width: level_width,
+                height: level_height,
+                depth: 1,
+            },
+        );
+    }
+
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout: texture_layout,
+        bindings: &[wgpu::Binding {
+            binding: 0,
+            resource: wgpu::BindingResource::TextureView(
+                &texture.create_default_view(),
+            ),
+        }],
+    })
+}
+
+/// Describes the texture of a rasterization with the given size.
+fn texture_descriptor(width: u32, height: u32) -> wgpu::TextureDescriptor {
+    // Downscaled SVGs alias badly without mipmaps, so we generate the whole
+    // mip chain on the CPU.
+    let mip_level_count = 32 - width.max(height).leading_zeros();
+
+    wgpu::TextureDescriptor {
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+        array_layer_count: 1,
+        mip_level_count,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        usage: wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+    }
+}
+
+/// Converts a logical size into a size in physical pixels.
+fn physical_size(width: f32, height: f32, scale_factor: f32) -> (u32, u32) {
+    (
+        (width * scale_factor).round() as u32,
+        (height * scale_factor).round() as u32,
+    )
+}
+
+/// Produces the identifier of the rasterizations of an SVG with a tint.
+fn rasterization_id(id: u64, color: Option<Color>) -> u64 {
+    match color {
+        Some(Color { r, g, b, a }) => {
+            let mut hasher = DefaultHasher::new();
+
+            (id, r.to_bits(), g.to_bits(), b.to_bits(), a.to_bits())
+                .hash(&mut hasher);
+
+            hasher.finish()
+        }
+        None => id,
+    }
+}
+
+/// Multiplies some premultiplied ARGB pixels by a color.
+fn tint(pixels: &mut [u32], color: Color) {
+    let factors = [
+        color.a,
+        color.r * color.a,
+        color.g * color.a,
+        color.b * color.a,
+    ];
+
+    for pixel in pixels.iter_mut() {
+        let original = *pixel;
+
+        *pixel = factors.iter().zip(&[24, 16, 8, 0]).fold(
+            0,
+            |tinted, (factor, shift)| {
+                let channel = ((original >> shift) & 0xFF) as f32;
+
+                tinted | (((channel * factor).round() as u32) << shift)
+            },
+        );
+    }
+}
+
+/// Halves the size of some packed 32-bit pixels by averaging every block of
+/// 2x2 pixels, channel by channel.
+fn downsample(pixels: &[u32], width: u32, height: u32) -> Vec<u32> {
+    let (new_width, new_height) = ((width / 2).max(1), (height / 2).max(1));
+
+    let pixel = |x: u32, y: u32| {
+        pixels[(y.min(height - 1) * width + x.min(width - 1)) as usize]
+    };
+
+    let mut result = Vec::with_capacity((new_width * new_height) as usize);
+
+    for y in 0..new_height {
+        for x in 0..new_width {
+            let block = [
+                pixel(2 * x, 2 * y),
+                pixel(2 * x + 1, 2 * y),
+                pixel(2 * x, 2 * y + 1),
+                pixel(2 * x + 1, 2 * y + 1),
+            ];
+
+            let average = (0..4).fold(0, |average, channel| {
+                let shift = channel * 8;
+                let sum: u32 =
+                    block.iter().map(|pixel| (pixel >> shift) & 0xFF).sum();
+
+                average | ((sum / 4) << shift)
+            });
+
+            result.push(average);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn scale_set(value: u32) -> Vec<((u32, u32), u32)> {
+        vec![
+            ((100, 100), value),
+            ((150, 150), value + 1),
+            ((200, 200), value + 2),
+        ]
+    }
+
+    #[test]
+    fn resizing_between_prepared_scales_reuses_them() {
+        let mut rasterized = Rasterized::new(usize::MAX);
+
+        let _ = rasterized.insert((1, 100, 100), scale_set(0), true);
+
+        assert_eq!(rasterized.get(1, 120, 120), Some(1));
+        assert_eq!(rasterized.get(1, 150, 150), Some(1));
+        assert_eq!(rasterized.get(1, 180, 180), Some(2));
+        assert_eq!(rasterized.get(1, 201, 201), None);
+        assert_eq!(rasterized.get(1, 99, 99), None);
+    }
+
+    #[test]
+    fn sizes_are_not_shared_without_a_scale_set() {
+        let mut rasterized = Rasterized::new(usize::MAX);
+
+        let _ = rasterized.insert((1, 100, 100), scale_set(0), false);
+
+        assert_eq!(rasterized.get(1, 120, 120), None);
+    }
+
+    #[test]
+    fn hitting_a_scale_set_keeps_all_of_it() {
+        let mut rasterized = Rasterized::new(usize::MAX);
+
+        let _ = rasterized.insert((1, 100, 100), scale_set(0), true);
+        let _ = rasterized.trim();
+
+        assert_eq!(rasterized.get(1, 200, 200), Some(2));
+
+        let _ = rasterized.trim();
+
+        assert_eq!(rasterized.get(1, 100, 100), Some(0));
+        assert_eq!(rasterized.get(1, 120, 120), Some(1));
+    }
+
+    #[test]
+    fn svg_is_loaded_from_memory() {
+        let mut cache = Cache::new(false);
+        let handle = svg::Handle::from_memory(
+            br#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10">
+                <rect width="20" height="10" fill="red"/>
+            </svg>"#
+                .to_vec(),
+        );
+
+        let svg = cache.load(&handle);
+
+        assert!(matches!(svg, Svg::Loaded { .. }));
+        assert_eq!(svg.viewport_dimensions(), (20, 10));
+        assert_eq!(handle.status(), svg::Status::Loaded);
+    }
+
+    #[test]
+    fn malformed_svg_reports_its_error() {
+        let mut cache = Cache::new(false);
+        let bytes = b"<svg xmlns=\"http://www.w3.org/2000/svg\"".to_vec();
+        let handle = svg::Handle::from_memory(bytes.clone());
+
+        assert!(matches!(cache.load(&handle), Svg::NotFound));
+        assert_eq!(handle.status(), svg::Status::NotFound);
+
+        let error = handle.error().expect("Load error");
+        assert!(!error.is_empty());
+
+        // A separate handle for the same SVG gets the same error
+        let other = svg::Handle::from_memory(bytes);
+        let _ = cache.load(&other);
+
+        assert_eq!(other.status(), svg::Status::NotFound);
+        assert_eq!(other.error(), Some(error));
+    }
+
+    #[test]
+    fn least_recently_used_rasterization_is_evicted() {
+        let mut rasterized = Rasterized::new(2);
+
+        let _ = rasterized.insert((1, 10, 10), vec![((10, 10), 1)], false);
+        let _ = rasterized.insert((2, 10, 10), vec![((10, 10), 2)], false);
+        let _ = rasterized.trim();
+
+        let _ = rasterized.get(2, 10, 10);
+        let _ = rasterized.get(1, 10, 10);
+        let _ = rasterized.trim();
+
+        // Both were used in the last frame, but the first one more recently
+        let _ = rasterized.insert((3, 10, 10), vec![((10, 10), 3)], false);
+
+        assert!(rasterized.entries.contains_key(&(1, 10, 10)));
+        assert!(!rasterized.entries.contains_key(&(2, 10, 10)));
+        assert!(rasterized.entries.contains_key(&(3, 10, 10)));
+    }
+
+    #[test]
+    fn rasterizations_used_in_the_current_frame_are_not_evicted() {
+        let mut rasterized = Rasterized::new(1);
+
+        let _ = rasterized.insert((1, 10, 10), vec![((10, 10), 1)], false);
+        let _ = rasterized.insert((2, 10, 10), vec![((10, 10), 2)], false);
+
+        assert_eq!(rasterized.entries.len(), 2);
+
+        let _ = rasterized.trim();
+        let _ = rasterized.get(2, 10, 10);
+        let _ = rasterized.insert((3, 10, 10), vec![((10, 10), 3)], false);
+
+        assert!(!rasterized.entries.contains_key(&(1, 10, 10)));
+        assert!(rasterized.entries.contains_key(&(2, 10, 10)));
+        assert!(rasterized.entries.contains_key(&(3, 10, 10)));
+    }
+
+    #[test]
+    fn clearing_drops_every_rasterization() {
+        let mut rasterized = Rasterized::new(usize::MAX);
+
+        let _ = rasterized.insert((1, 100, 100), scale_set(0), true);
+        rasterized.clear();
+
+        assert_eq!(rasterized.get(1, 100, 100), None);
+        assert_eq!(rasterized.get(1, 120, 120), None);
+    }
+
+    #[test]
+    fn texture_has_a_full_mip_chain() {
+        assert_eq!(texture_descriptor(64, 32).mip_level_count, 7);
+        assert_eq!(texture_descriptor(100, 3).mip_level_count, 7);
+        assert_eq!(texture_descriptor(1, 1).mip_level_count, 1);
+    }
+
+    #[test]
+    fn downsampling_averages_every_channel() {
+        let pixels =
+            [0xFF_FF_00_00, 0xFF_00_FF_00, 0xFF_00_00_FF, 0x00_00_00_00];
+
+        assert_eq!(downsample(&pixels, 2, 2), vec![0xBF_3F_3F_3F]);
+    }
+
+    #[test]
+    fn downsampling_repeats_the_edges_of_odd_sizes() {
+        let pixels = [0xFF_00_00_00, 0x00_00_00_00];
+
+        assert_eq!(downsample(&pixels, 1, 2), vec![0x7F_00_00_00]);
+    }
+
+    #[test]
+    fn tints_are_cached_separately() {
+        let red = rasterization_id(1, Some(Color::from_rgb(1.0, 0.0, 0.0)));
+        let blue = rasterization_id(1, Some(Color::from_rgb(0.0, 0.0, 1.0)));
+
+        assert_ne!(red, blue);
+        assert_ne!(red, rasterization_id(1, None));
+
+        let mut rasterized = Rasterized::new(usize::MAX);
+
+        let _ = rasterized.insert((red, 10, 10), vec![((10, 10), 1)], false);
+        let _ = rasterized.insert((blue, 10, 10), vec![((10, 10), 2)], false);
+
+        assert_eq!(rasterized.entries.len(), 2);
+        assert_eq!(rasterized.get(red, 10, 10), Some(1));
+        assert_eq!(rasterized.get(blue, 10, 10), Some(2));
+    }
+
+    #[test]
+    fn tint_multiplies_the_pixels() {
+        let mut pixels = [0xFF_FF_FF_FF, 0x80_80_80_80];
+
+        tint(&mut pixels, Color::from_rgb(1.0, 0.5, 0.0));
+
+        assert_eq!(pixels, [0xFF_FF_80_00, 0x80_80_40_00]);
+    }
+
+    #[test]
+    fn options_are_reused_for_every_svg() {
+        let mut cache = Cache::new(false).options(resvg::Options {
+            usvg: resvg::usvg::Options {
+                dpi: 192.0,
+                ..resvg::usvg::Options::default()
+            },
+            ..resvg::Options::default()
+        });
+
+        let inch = |name: &str| {
+            svg::Handle::from_memory(
+                format!(
+                    r#"<svg xmlns="http://www.w3.org/2000/svg" id="{}"
+                        width="1in" height="0.5in"/>"#,
+                    name
+                )
+                .into_bytes(),
+            )
+        };
+
+        assert_eq!(cache.load(&inch("a")).viewport_dimensions(), (192, 96));
+        assert_eq!(cache.load(&inch("b")).viewport_dimensions(), (192, 96));
+    }
+
+    fn job(key: u64, cancelled: bool) -> Job {
+        Job {
+            key: (key, 4, 2),
+            handle: svg::Handle::from_memory(
+                br#"<svg xmlns="http://www.w3.org/2000/svg"
+                    width="4" height="2"/>"#
+                    .to_vec(),
+            ),
+            options: resvg::Options::default(),
+            color: None,
+            sizes: vec![(4, 2), (8, 4)],
+            cancelled: Arc::new(AtomicBool::new(cancelled)),
+        }
+    }
+
+    #[test]
+    fn pending_rasterization_is_eventually_ready() {
+        let rasterizer = Rasterizer::new();
+        let job = job(1, false);
+        let handle = job.handle.clone();
+
+        rasterizer.rasterize(job);
+
+        let rasterization = rasterizer
+            .results
+            .recv_timeout(Duration::from_secs(10))
+            .expect("Rasterization");
+
+        assert_eq!(rasterization.key, (1, 4, 2));
+        assert_eq!(rasterization.sizes, vec![(4, 2), (8, 4)]);
+        assert_eq!(
+            rasterization
+                .pixels
+                .iter()
+                .map(Vec::len)
+                .collect::<Vec<_>>(),
+            vec![8, 32]
+        );
+
+        // The handle is reported right after the result is sent
+        let start = Instant::now();
+
+        while handle.status() != svg::Status::Loaded {
+            assert!(start.elapsed() < Duration::from_secs(10));
+            thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn cancelled_rasterization_is_skipped() {
+        let rasterizer = Rasterizer::new();
+
+        rasterizer.rasterize(job(1, true));
+        rasterizer.rasterize(job(2, false));
+
+        let rasterization = rasterizer
+            .results
+            .recv_timeout(Duration::from_secs(10))
+            .expect("Rasterization");
+
+        assert_eq!(rasterization.key, (2, 4, 2));
+        assert!(rasterizer
+            .results
+            .recv_timeout(Duration::from_millis(100))
+            .is_err());
+    }
+
+    #[test]
+    fn scaled_dimensions_follow_the_scale_factor() {
+        let mut cache = Cache::new(false);
+        let handle = svg::Handle::from_memory(
+            br#"<svg xmlns="http://www.w3.org/2000/svg"
+                width="15" height="10.3"/>"#
+                .to_vec(),
+        );
+
+        let svg = cache.load(&handle);
+
+        assert_eq!(svg.scaled_dimensions(1.0), (15, 10));
+        assert_eq!(svg.scaled_dimensions(2.0), (30, 21));
+        assert_eq!(svg.scaled_dimensions(1.5), physical_size(15.0, 10.3, 1.5));
+    }
+
+    fn square() -> svg::Handle {
+        svg::Handle::from_memory(
+            br#"<svg xmlns="http://www.w3.org/2000/svg"
+                width="4" height="4"/>"#
+                .to_vec(),
+        )
+    }
+
+    #[test]
+    fn status_is_loaded_once_the_svg_is_parsed() {
+        let mut cache = Cache::new(false);
+        let handle = square();
+
+        assert_eq!(handle.status(), svg::Status::Loading);
+
+        let _ = cache.load(&handle);
+        assert_eq!(handle.status(), svg::Status::Loaded);
+
+        // Separate handles for the same SVG are reported in later frames
+        let other = square();
+        let _ = cache.load(&other);
+
+        assert_eq!(other.status(), svg::Status::Loaded);
+    }
+
+    #[test]
+    fn status_is_loading_until_the_svg_is_rasterized() {
+        let mut cache = Cache::new(false).asynchronous(true);
+        let handle = square();
+
+        let _ = cache.load(&handle);
+
+        assert_eq!(handle.status(), svg::Status::Loading);
+
+        let rasterizer = cache.rasterizer.as_ref().expect("Rasterizer");
+
+        rasterizer.rasterize(Job {
+            handle: handle.clone(),
+            ..job(1, false)
+        });
+
+        let _ = rasterizer
+            .results
+            .recv_timeout(Duration::from_secs(10))
+            .expect("Rasterization");
+
+        let start = Instant::now();
+
+        while handle.status() != svg::Status::Loaded {
+            assert!(start.elapsed() < Duration::from_secs(10));
+            thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn doubling_the_scale_doubles_the_rasterization() {
+        assert_eq!(physical_size(20.0, 10.5, 1.0), (20, 11));
+        assert_eq!(physical_size(20.0, 10.5, 2.0), (40, 21));
     }
 }
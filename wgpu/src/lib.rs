@@ -25,6 +25,7 @@
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
 pub mod defaults;
+pub mod settings;
 pub mod triangle;
 pub mod widget;
 pub mod window;
@@ -33,14 +34,20 @@ mod image;
 mod primitive;
 mod quad;
 mod renderer;
-mod settings;
 mod target;
 mod text;
+mod texture_cache;
 mod transformation;
 mod viewport;
 
 pub use wgpu;
 
+#[cfg(feature = "svg")]
+pub use resvg;
+
+#[cfg(feature = "canvas")]
+pub use lyon;
+
 pub use defaults::Defaults;
 pub use primitive::Primitive;
 pub use renderer::Renderer;
@@ -1,6 +1,6 @@
 use iced_native::{
-    image, svg, Background, Color, Font, HorizontalAlignment, Rectangle,
-    Vector, VerticalAlignment,
+    image, svg, Background, BorderRadius, BorderStyle, Color, Font,
+    HorizontalAlignment, Rectangle, Vector, VerticalAlignment,
 };
 
 use crate::triangle;
@@ -28,6 +28,8 @@ pub enum Primitive {
         size: f32,
         /// The font of the text
         font: Font,
+        /// The space added between the glyphs of the text
+        letter_spacing: f32,
         /// The horizontal alignment of the text
         horizontal_alignment: HorizontalAlignment,
         /// The vertical alignment of the text
@@ -40,26 +42,47 @@ pub enum Primitive {
         /// The background of the quad
         background: Background,
         /// The border radius of the quad
-        border_radius: u16,
+        border_radius: BorderRadius,
         /// The border width of the quad
         border_width: u16,
         /// The border color of the quad
         border_color: Color,
     },
+    /// A shadow primitive
+    Shadow {
+        /// The bounds of the shadow, before it is blurred
+        bounds: Rectangle,
+        /// The border radius of the shadow
+        border_radius: BorderRadius,
+        /// The radius of the blur of the shadow
+        blur_radius: f32,
+        /// The color of the shadow
+        color: Color,
+    },
     /// An image primitive
     Image {
         /// The handle of the image
         handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The nine-slice insets of the image, if any
+        nine_slice: Option<image::NineSlice>,
+        /// The opacity of the image
+        opacity: f32,
     },
     /// An SVG primitive
     Svg {
         /// The path of the SVG file
         handle: svg::Handle,
 
+        /// The color used to tint the SVG, if any
+        color: Option<Color>,
+
         /// The bounds of the viewport
         bounds: Rectangle,
+
+        /// The opacity of the SVG
+        opacity: f32,
     },
     /// A clip primitive
     Clip {
@@ -74,6 +97,20 @@ pub enum Primitive {
     ///
     /// It can be used to render many kinds of geometry freely.
     Mesh2D(Arc<triangle::Mesh2D>),
+    /// A primitive drawn once into a texture, which is reused while its
+    /// contents stay the same
+    Cached {
+        /// The identifier of the texture
+        id: u64,
+        /// The hash of the contents
+        hash: u64,
+        /// The bounds of the contents
+        bounds: Rectangle,
+        /// The opacity of the texture
+        opacity: f32,
+        /// The contents drawn into the texture
+        content: Arc<Primitive>,
+    },
 }
 
 impl Default for Primitive {
@@ -81,3 +118,290 @@ impl Default for Primitive {
         Primitive::None
     }
 }
+
+impl Primitive {
+    /// Creates a [`Primitive`] that draws a border with the given style
+    /// around the given bounds.
+    ///
+    /// Dashed and dotted borders are drawn as a mesh following the rounded
+    /// corners of the bounds.
+    ///
+    /// [`Primitive`]: enum.Primitive.html
+    pub(crate) fn border(
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        border_width: u16,
+        border_color: Color,
+        border_style: BorderStyle,
+    ) -> Primitive {
+        let width = f32::from(border_width);
+
+        let meshes = match border_style {
+            _ if border_width == 0 => return Primitive::None,
+            BorderStyle::Solid => {
+                return Primitive::Quad {
+                    bounds,
+                    background: Background::Color(Color::TRANSPARENT),
+                    border_radius,
+                    border_width,
+                    border_color,
+                };
+            }
+            BorderStyle::Dashed { length, gap } => {
+                triangle::Mesh2D::dashed_border(
+                    bounds,
+                    border_radius,
+                    width,
+                    border_color,
+                    f32::from(length.max(1)),
+                    f32::from(gap),
+                )
+            }
+            BorderStyle::Dotted => triangle::Mesh2D::dotted_border(
+                bounds,
+                border_radius,
+                width,
+                border_color,
+            ),
+        };
+
+        Primitive::Group {
+            primitives: meshes
+                .into_iter()
+                .map(|mesh| Primitive::Mesh2D(Arc::new(mesh)))
+                .collect(),
+        }
+    }
+
+    /// Creates a [`Primitive`] that draws the shadow of the given bounds.
+    ///
+    /// No shadow is drawn when it would be hidden behind the bounds, that is,
+    /// when the `offset` is zero and the `blur_radius` is not positive, or
+    /// when the `color` is transparent.
+    ///
+    /// [`Primitive`]: enum.Primitive.html
+    pub(crate) fn shadow(
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        offset: Vector,
+        blur_radius: f32,
+        color: Color,
+    ) -> Primitive {
+        let is_hidden = offset == Vector::default() && blur_radius <= 0.0;
+
+        if is_hidden || color.a <= 0.0 {
+            return Primitive::None;
+        }
+
+        Primitive::Shadow {
+            bounds: Rectangle {
+                x: bounds.x + offset.x,
+                y: bounds.y + offset.y,
+                ..bounds
+            },
+            border_radius,
+            blur_radius: blur_radius.max(0.0),
+            color,
+        }
+    }
+
+    /// Returns the [`Primitive`] with the alpha of all of its colors
+    /// multiplied by the given opacity.
+    ///
+    /// [`Primitive`]: enum.Primitive.html
+    pub(crate) fn with_opacity(self, opacity: f32) -> Primitive {
+        match self {
+            Primitive::Group { primitives } => Primitive::Group {
+                primitives: primitives
+                    .into_iter()
+                    .map(|primitive| primitive.with_opacity(opacity))
+                    .collect(),
+            },
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                letter_spacing,
+                horizontal_alignment,
+                vertical_alignment,
+            } => Primitive::Text {
+                content,
+                bounds,
+                color: color.scale_alpha(opacity),
+                size,
+                font,
+                letter_spacing,
+                horizontal_alignment,
+                vertical_alignment,
+            },
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+            } => Primitive::Quad {
+                bounds,
+                background: background.scale_alpha(opacity),
+                border_radius,
+                border_width,
+                border_color: border_color.scale_alpha(opacity),
+            },
+            Primitive::Shadow {
+                bounds,
+                border_radius,
+                blur_radius,
+                color,
+            } => Primitive::Shadow {
+                bounds,
+                border_radius,
+                blur_radius,
+                color: color.scale_alpha(opacity),
+            },
+            Primitive::Image {
+                handle,
+                bounds,
+                nine_slice,
+                opacity: image_opacity,
+            } => Primitive::Image {
+                handle,
+                bounds,
+                nine_slice,
+                opacity: image_opacity * opacity.clamp(0.0, 1.0),
+            },
+            Primitive::Svg {
+                handle,
+                color,
+                bounds,
+                opacity: svg_opacity,
+            } => Primitive::Svg {
+                handle,
+                color,
+                bounds,
+                opacity: svg_opacity * opacity.clamp(0.0, 1.0),
+            },
+            Primitive::Clip {
+                bounds,
+                offset,
+                content,
+            } => Primitive::Clip {
+                bounds,
+                offset,
+                content: Box::new(content.with_opacity(opacity)),
+            },
+            Primitive::Mesh2D(mesh) => {
+                let mut mesh = (*mesh).clone();
+
+                for vertex in &mut mesh.vertices {
+                    vertex.color[3] *= opacity;
+                }
+
+                Primitive::Mesh2D(Arc::new(mesh))
+            }
+            Primitive::Cached {
+                id,
+                hash,
+                bounds,
+                opacity: cached_opacity,
+                content,
+            } => Primitive::Cached {
+                id,
+                hash,
+                bounds,
+                opacity: cached_opacity * opacity.clamp(0.0, 1.0),
+                content,
+            },
+            Primitive::None => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 10.0,
+        width: 100.0,
+        height: 40.0,
+    };
+
+    fn shadow(offset: Vector, blur_radius: f32, color: Color) -> Primitive {
+        Primitive::shadow(
+            BOUNDS,
+            BorderRadius::from(4.0),
+            offset,
+            blur_radius,
+            color,
+        )
+    }
+
+    #[test]
+    fn zero_radius_shadow_without_offset_is_not_drawn() {
+        assert!(matches!(
+            shadow(Vector::default(), 0.0, Color::BLACK),
+            Primitive::None
+        ));
+    }
+
+    #[test]
+    fn blurred_shadow_is_drawn() {
+        match shadow(Vector::default(), 8.0, Color::BLACK) {
+            Primitive::Shadow {
+                bounds,
+                blur_radius,
+                color,
+                ..
+            } => {
+                assert_eq!(bounds, BOUNDS);
+                assert_eq!(blur_radius, 8.0);
+                assert_eq!(color, Color::BLACK);
+            }
+            _ => panic!("Expected a shadow"),
+        }
+    }
+
+    #[test]
+    fn offset_shadow_is_drawn_without_blur() {
+        match shadow(Vector::new(0.0, 2.0), 0.0, Color::BLACK) {
+            Primitive::Shadow { bounds, .. } => {
+                assert_eq!(bounds, Rectangle { y: 12.0, ..BOUNDS });
+            }
+            _ => panic!("Expected a shadow"),
+        }
+    }
+
+    #[test]
+    fn transparent_shadow_is_not_drawn() {
+        assert!(matches!(
+            shadow(Vector::new(0.0, 2.0), 8.0, Color::TRANSPARENT),
+            Primitive::None
+        ));
+    }
+
+    #[test]
+    fn nested_opacities_multiply() {
+        let quad = Primitive::Quad {
+            bounds: BOUNDS,
+            background: Background::Color(Color::BLACK),
+            border_radius: BorderRadius::from(0.0),
+            border_width: 1,
+            border_color: Color::WHITE,
+        };
+
+        match quad.with_opacity(0.5).with_opacity(0.5) {
+            Primitive::Quad {
+                background: Background::Color(background),
+                border_color,
+                ..
+            } => {
+                assert_eq!(background.a, 0.25);
+                assert_eq!(border_color.a, 0.25);
+            }
+            _ => panic!("Expected a quad"),
+        }
+    }
+}
@@ -130,13 +130,18 @@ impl Pipeline {
                             },
                             wgpu::VertexAttributeDescriptor {
                                 shader_location: 5,
-                                format: wgpu::VertexFormat::Float,
+                                format: wgpu::VertexFormat::Float4,
                                 offset: 4 * (2 + 2 + 4 + 4),
                             },
                             wgpu::VertexAttributeDescriptor {
                                 shader_location: 6,
                                 format: wgpu::VertexFormat::Float,
-                                offset: 4 * (2 + 2 + 4 + 4 + 1),
+                                offset: 4 * (2 + 2 + 4 + 4 + 4),
+                            },
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 7,
+                                format: wgpu::VertexFormat::Float,
+                                offset: 4 * (2 + 2 + 4 + 4 + 4 + 1),
                             },
                         ],
                     },
@@ -289,8 +294,9 @@ pub struct Quad {
     pub scale: [f32; 2],
     pub color: [f32; 4],
     pub border_color: [f32; 4],
-    pub border_radius: f32,
+    pub border_radius: [f32; 4],
     pub border_width: f32,
+    pub blur: f32,
 }
 
 impl Quad {
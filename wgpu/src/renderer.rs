@@ -1,10 +1,10 @@
 use crate::{
-    image, quad, text, triangle, Defaults, Image, Primitive, Quad, Settings,
-    Target, Transformation,
+    image, quad, text, texture_cache, triangle, Defaults, Image, Primitive,
+    Quad, Settings, Target, Transformation,
 };
 use iced_native::{
-    layout, Background, Color, Layout, MouseCursor, Point, Rectangle, Vector,
-    Widget,
+    layout, renderer, Background, BorderRadius, Color, Layout, MouseCursor,
+    Point, Rectangle, Vector, Widget,
 };
 use std::sync::Arc;
 
@@ -19,6 +19,7 @@ pub struct Renderer {
     image_pipeline: image::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: crate::triangle::Pipeline,
+    texture_cache: texture_cache::Cache,
 }
 
 struct Layer<'a> {
@@ -27,7 +28,9 @@ struct Layer<'a> {
     quads: Vec<Quad>,
     images: Vec<Image>,
     meshes: Vec<Arc<triangle::Mesh2D>>,
-    text: Vec<wgpu_glyph::Section<'a>>,
+    meshes_over_quads: Vec<Arc<triangle::Mesh2D>>,
+    meshes_over_quads_bounds: Option<Rectangle>,
+    text: Vec<(wgpu_glyph::Section<'a>, f32)>,
 }
 
 impl<'a> Layer<'a> {
@@ -39,6 +42,30 @@ impl<'a> Layer<'a> {
             images: Vec::new(),
             text: Vec::new(),
             meshes: Vec::new(),
+            meshes_over_quads: Vec::new(),
+            meshes_over_quads_bounds: None,
+        }
+    }
+
+    /// Adds a mesh that is drawn right after the quads of the layer.
+    fn push_mesh_over_quads(&mut self, mesh: Arc<triangle::Mesh2D>) {
+        if let Some(bounds) = mesh_bounds(&mesh) {
+            self.meshes_over_quads_bounds =
+                Some(match self.meshes_over_quads_bounds {
+                    Some(current) => union(current, bounds),
+                    None => bounds,
+                });
+        }
+
+        self.meshes_over_quads.push(mesh);
+    }
+
+    /// Returns whether a quad with the given bounds would hide any of the
+    /// meshes drawn after the quads of the layer.
+    fn covers_meshes(&self, bounds: &Rectangle) -> bool {
+        match &self.meshes_over_quads_bounds {
+            Some(meshes) => intersects(meshes, bounds),
+            None => false,
         }
     }
 }
@@ -48,9 +75,21 @@ impl Renderer {
     ///
     /// [`Renderer`]: struct.Renderer.html
     pub fn new(device: &mut wgpu::Device, settings: Settings) -> Self {
-        let text_pipeline = text::Pipeline::new(device, settings.default_font);
+        let text_pipeline = text::Pipeline::new(
+            device,
+            settings.default_font,
+            settings.text_atlas_size,
+            settings.text_atlas_max_size,
+        );
         let quad_pipeline = quad::Pipeline::new(device);
-        let image_pipeline = crate::image::Pipeline::new(device);
+        let image_pipeline = crate::image::Pipeline::new(
+            device,
+            settings.prerasterize_svg_scales,
+            settings.svg_cache_capacity,
+            settings.asynchronous_svg_rasterization,
+            settings.svg_dpi,
+            settings.svg_font_family,
+        );
         let triangle_pipeline = triangle::Pipeline::new(device);
 
         Self {
@@ -58,9 +97,16 @@ impl Renderer {
             image_pipeline,
             text_pipeline,
             triangle_pipeline,
+            texture_cache: texture_cache::Cache::new(),
         }
     }
 
+    /// Sets the function called when some work done in the background is
+    /// ready to be drawn.
+    pub(crate) fn set_waker(&mut self, waker: Box<dyn Fn() + Send + Sync>) {
+        self.image_pipeline.set_waker(waker);
+    }
+
     /// Draws the provided primitives in the given [`Target`].
     ///
     /// The text provided as overlay will be renderer on top of the primitives.
@@ -82,14 +128,16 @@ impl Renderer {
         let scale_factor = scale_factor as f32;
         let transformation = target.viewport.transformation();
 
+        self.draw_cached(device, encoder, primitive, scale_factor);
+
         let mut layers = Vec::new();
 
         layers.push(Layer::new(
             Rectangle {
                 x: 0,
                 y: 0,
-                width: u32::from(width),
-                height: u32::from(height),
+                width: (width as f32 / scale_factor).ceil() as u32,
+                height: (height as f32 / scale_factor).ceil() as u32,
             },
             Vector::new(0, 0),
         ));
@@ -109,10 +157,113 @@ impl Renderer {
         }
 
         self.image_pipeline.trim_cache();
+        self.texture_cache.trim();
 
         *mouse_cursor
     }
 
+    /// Draws the contents of every outdated `Primitive::Cached` into their
+    /// texture.
+    fn draw_cached(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        primitive: &Primitive,
+        scale_factor: f32,
+    ) {
+        match primitive {
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.draw_cached(device, encoder, primitive, scale_factor);
+                }
+            }
+            Primitive::Clip { content, .. } => {
+                self.draw_cached(device, encoder, content, scale_factor);
+            }
+            Primitive::Cached {
+                id,
+                hash,
+                bounds,
+                content,
+                ..
+            } => {
+                if self
+                    .texture_cache
+                    .texture(*id, *hash, *bounds, scale_factor)
+                    .is_some()
+                {
+                    return;
+                }
+
+                // Nested textures are sampled while drawing this one, so they
+                // need to be ready first
+                self.draw_cached(device, encoder, content, scale_factor);
+
+                let texture = match texture_cache::Texture::new(
+                    device,
+                    self.image_pipeline.texture_layout(),
+                    *hash,
+                    *bounds,
+                    scale_factor,
+                ) {
+                    Some(texture) => texture,
+                    None => {
+                        self.texture_cache.remove_texture(*id);
+                        return;
+                    }
+                };
+
+                let region = texture.region();
+                let (width, height) = texture.size();
+
+                let _ =
+                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        color_attachments: &[
+                            wgpu::RenderPassColorAttachmentDescriptor {
+                                attachment: texture.view(),
+                                resolve_target: None,
+                                load_op: wgpu::LoadOp::Clear,
+                                store_op: wgpu::StoreOp::Store,
+                                clear_color: wgpu::Color {
+                                    r: 0.0,
+                                    g: 0.0,
+                                    b: 0.0,
+                                    a: 0.0,
+                                },
+                            },
+                        ],
+                        depth_stencil_attachment: None,
+                    });
+
+                let mut layers = vec![Layer::new(
+                    Rectangle {
+                        x: 0,
+                        y: 0,
+                        width: region.width,
+                        height: region.height,
+                    },
+                    Vector::new(region.x, region.y),
+                )];
+
+                self.draw_primitive(content, &mut layers);
+
+                for layer in layers {
+                    self.flush(
+                        device,
+                        scale_factor,
+                        Transformation::orthographic(width, height),
+                        &layer,
+                        encoder,
+                        texture.view(),
+                    );
+                }
+
+                self.texture_cache.insert_texture(*id, texture);
+            }
+            _ => {}
+        }
+    }
+
     fn draw_primitive<'a>(
         &mut self,
         primitive: &'a Primitive,
@@ -134,6 +285,7 @@ impl Renderer {
                 size,
                 color,
                 font,
+                letter_spacing,
                 horizontal_alignment,
                 vertical_alignment,
             } => {
@@ -157,7 +309,7 @@ impl Renderer {
                     }
                 };
 
-                layer.text.push(wgpu_glyph::Section {
+                let section = wgpu_glyph::Section {
                     text: &content,
                     screen_position: (
                         x - layer.offset.x as f32,
@@ -191,7 +343,9 @@ impl Renderer {
                             }
                         }),
                     ..Default::default()
-                })
+                };
+
+                layer.text.push((section, *letter_spacing));
             }
             Primitive::Quad {
                 bounds,
@@ -200,37 +354,171 @@ impl Renderer {
                 border_width,
                 border_color,
             } => {
+                if layer.covers_meshes(bounds) {
+                    let new_layer = Layer::new(layer.bounds, layer.offset);
+
+                    layers.push(new_layer);
+                }
+
+                let layer = layers.last_mut().unwrap();
+
                 // TODO: Move some of this computations to the GPU (?)
-                layer.quads.push(Quad {
+                let offset = layer.offset;
+                let quad = |color: Color| Quad {
                     position: [
-                        bounds.x - layer.offset.x as f32,
-                        bounds.y - layer.offset.y as f32,
+                        bounds.x - offset.x as f32,
+                        bounds.y - offset.y as f32,
                     ],
                     scale: [bounds.width, bounds.height],
-                    color: match background {
-                        Background::Color(color) => color.into_linear(),
-                    },
-                    border_radius: *border_radius as f32,
-                    border_width: *border_width as f32,
+                    color: color.into_linear(),
+                    border_radius: border_radius.corners(),
+                    border_width: f32::from(*border_width),
                     border_color: border_color.into_linear(),
-                });
+                    blur: 0.0,
+                };
+
+                match background.normalize() {
+                    Background::Color(color) => {
+                        layer.quads.push(quad(color));
+                    }
+                    Background::LinearGradient(gradient) => {
+                        // The quad pipeline only supports solid colors, so the
+                        // gradient is drawn as a mesh in a layer of its own to
+                        // keep the order of the primitives
+                        let mut gradient_layer =
+                            Layer::new(layer.bounds, layer.offset);
+                        let new_layer = Layer::new(layer.bounds, layer.offset);
+
+                        gradient_layer.meshes.extend(
+                            triangle::Mesh2D::linear_gradient(
+                                *bounds,
+                                *border_radius,
+                                gradient.start,
+                                gradient.end,
+                                &gradient.stops().collect::<Vec<_>>(),
+                            )
+                            .into_iter()
+                            .map(Arc::new),
+                        );
+
+                        if *border_width > 0 {
+                            gradient_layer.quads.push(quad(Color::TRANSPARENT));
+                        }
+
+                        layers.push(gradient_layer);
+                        layers.push(new_layer);
+                    }
+                }
             }
-            Primitive::Image { handle, bounds } => {
-                layer.images.push(Image {
-                    handle: image::Handle::Raster(handle.clone()),
-                    position: [bounds.x, bounds.y],
+            Primitive::Shadow {
+                bounds,
+                border_radius,
+                blur_radius,
+                color,
+            } => {
+                if layer.covers_meshes(bounds) {
+                    let new_layer = Layer::new(layer.bounds, layer.offset);
+
+                    layers.push(new_layer);
+                }
+
+                let layer = layers.last_mut().unwrap();
+                let offset = layer.offset;
+
+                layer.quads.push(Quad {
+                    position: [
+                        bounds.x - offset.x as f32,
+                        bounds.y - offset.y as f32,
+                    ],
                     scale: [bounds.width, bounds.height],
+                    color: color.into_linear(),
+                    border_radius: border_radius.corners(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT.into_linear(),
+                    blur: *blur_radius,
                 });
             }
-            Primitive::Svg { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                bounds,
+                nine_slice,
+                opacity,
+            } => match nine_slice {
+                #[cfg(feature = "image")]
+                Some(nine_slice) => {
+                    let dimensions = self.image_pipeline.dimensions(handle);
+
+                    layer.images.extend(Image::nine_slice(
+                        handle,
+                        *bounds,
+                        dimensions,
+                        *nine_slice,
+                        *opacity,
+                        layer.offset,
+                    ));
+                }
+                _ => {
+                    layer.images.push(Image {
+                        handle: image::Handle::Raster(handle.clone()),
+                        position: [bounds.x, bounds.y],
+                        scale: [bounds.width, bounds.height],
+                        opacity: *opacity,
+                        clip: None,
+                    });
+                }
+            },
+            Primitive::Svg {
+                handle,
+                color,
+                bounds,
+                opacity,
+            } => {
                 layer.images.push(Image {
-                    handle: image::Handle::Vector(handle.clone()),
+                    handle: image::Handle::Vector(handle.clone(), *color),
                     position: [bounds.x, bounds.y],
                     scale: [bounds.width, bounds.height],
+                    opacity: *opacity,
+                    clip: None,
                 });
             }
             Primitive::Mesh2D(mesh) => {
-                layer.meshes.push(mesh.clone());
+                // Meshes are drawn before or right after the quads of a layer,
+                // so a new one is only needed to draw them on top of images or
+                // text
+                if layer.images.is_empty() && layer.text.is_empty() {
+                    if layer.quads.is_empty() {
+                        layer.meshes.push(mesh.clone());
+                    } else {
+                        layer.push_mesh_over_quads(mesh.clone());
+                    }
+                } else {
+                    let mut mesh_layer = Layer::new(layer.bounds, layer.offset);
+
+                    mesh_layer.meshes.push(mesh.clone());
+                    layers.push(mesh_layer);
+                }
+            }
+            Primitive::Cached { id, opacity, .. } => {
+                if let Some(texture) = self.texture_cache.get(*id) {
+                    let region = texture.region();
+
+                    // Textures are drawn in a layer of their own to keep the
+                    // order of the primitives
+                    let mut texture_layer =
+                        Layer::new(layer.bounds, layer.offset);
+                    let new_layer = Layer::new(layer.bounds, layer.offset);
+
+                    texture_layer.images.push(Image {
+                        handle: image::Handle::Texture(texture.bind_group()),
+                        position: [region.x as f32, region.y as f32],
+                        scale: [region.width as f32, region.height as f32],
+                        opacity: *opacity,
+                        clip: None,
+                    });
+
+                    layers.push(texture_layer);
+                    layers.push(new_layer);
+                }
             }
             Primitive::Clip {
                 bounds,
@@ -278,23 +566,29 @@ impl Renderer {
         let scale = wgpu_glyph::Scale { x: 20.0, y: 20.0 };
 
         for (i, line) in lines.iter().enumerate() {
-            overlay.text.push(wgpu_glyph::Section {
-                text: line.as_ref(),
-                screen_position: (11.0, 11.0 + 25.0 * i as f32),
-                color: [0.9, 0.9, 0.9, 1.0],
-                scale,
-                font_id,
-                ..wgpu_glyph::Section::default()
-            });
-
-            overlay.text.push(wgpu_glyph::Section {
-                text: line.as_ref(),
-                screen_position: (10.0, 10.0 + 25.0 * i as f32),
-                color: [0.0, 0.0, 0.0, 1.0],
-                scale,
-                font_id,
-                ..wgpu_glyph::Section::default()
-            });
+            overlay.text.push((
+                wgpu_glyph::Section {
+                    text: line.as_ref(),
+                    screen_position: (11.0, 11.0 + 25.0 * i as f32),
+                    color: [0.9, 0.9, 0.9, 1.0],
+                    scale,
+                    font_id,
+                    ..wgpu_glyph::Section::default()
+                },
+                0.0,
+            ));
+
+            overlay.text.push((
+                wgpu_glyph::Section {
+                    text: line.as_ref(),
+                    screen_position: (10.0, 10.0 + 25.0 * i as f32),
+                    color: [0.0, 0.0, 0.0, 1.0],
+                    scale,
+                    font_id,
+                    ..wgpu_glyph::Section::default()
+                },
+                0.0,
+            ));
         }
 
         layers.push(overlay);
@@ -341,6 +635,24 @@ impl Renderer {
             );
         }
 
+        if layer.meshes_over_quads.len() > 0 {
+            let translated = transformation
+                * Transformation::translate(
+                    -(layer.offset.x as f32) * scale_factor,
+                    -(layer.offset.y as f32) * scale_factor,
+                );
+
+            self.triangle_pipeline.draw(
+                device,
+                encoder,
+                target,
+                translated,
+                scale_factor,
+                &layer.meshes_over_quads,
+                bounds,
+            );
+        }
+
         if layer.images.len() > 0 {
             let translated_and_scaled = transformation
                 * Transformation::scale(scale_factor, scale_factor)
@@ -361,7 +673,7 @@ impl Renderer {
         }
 
         if layer.text.len() > 0 {
-            for text in layer.text.iter() {
+            for (text, letter_spacing) in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
                 let text = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
@@ -392,7 +704,11 @@ impl Renderer {
                     ..*text
                 };
 
-                self.text_pipeline.queue(text);
+                self.text_pipeline.queue(
+                    device,
+                    text,
+                    letter_spacing * scale_factor,
+                );
             }
 
             self.text_pipeline.draw_queued(
@@ -426,6 +742,43 @@ impl iced_native::Renderer for Renderer {
 
         node
     }
+
+    fn overlay(
+        &mut self,
+        (base_primitive, base_cursor): (Primitive, MouseCursor),
+        (overlay_primitive, overlay_cursor): (Primitive, MouseCursor),
+        overlay_bounds: Rectangle,
+    ) -> (Primitive, MouseCursor) {
+        (
+            Primitive::Group {
+                primitives: vec![
+                    base_primitive,
+                    Primitive::Clip {
+                        bounds: overlay_bounds,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(overlay_primitive),
+                    },
+                ],
+            },
+            base_cursor.max(overlay_cursor),
+        )
+    }
+}
+
+impl renderer::Opacity for Renderer {
+    fn opacity<Message>(
+        &mut self,
+        defaults: &Defaults,
+        widget: &dyn Widget<Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        opacity: f32,
+    ) -> Self::Output {
+        let (primitive, cursor) =
+            widget.draw(self, defaults, layout, cursor_position);
+
+        (primitive.with_opacity(opacity), cursor)
+    }
 }
 
 impl layout::Debugger for Renderer {
@@ -456,7 +809,7 @@ fn explain_layout(
     primitives.push(Primitive::Quad {
         bounds: layout.bounds(),
         background: Background::Color(Color::TRANSPARENT),
-        border_radius: 0,
+        border_radius: BorderRadius::ZERO,
         border_width: 1,
         border_color: [0.6, 0.6, 0.6, 0.5].into(),
     });
@@ -465,3 +818,108 @@ fn explain_layout(
         explain_layout(child, color, primitives);
     }
 }
+
+/// Returns the bounds of the vertices of the given mesh, if any.
+fn mesh_bounds(mesh: &triangle::Mesh2D) -> Option<Rectangle> {
+    let mut vertices = mesh.vertices.iter().map(|vertex| vertex.position);
+    let [x, y] = vertices.next()?;
+
+    let (left, top, right, bottom) =
+        vertices.fold((x, y, x, y), |(left, top, right, bottom), [x, y]| {
+            (left.min(x), top.min(y), right.max(x), bottom.max(y))
+        });
+
+    Some(Rectangle {
+        x: left,
+        y: top,
+        width: right - left,
+        height: bottom - top,
+    })
+}
+
+fn union(a: Rectangle, b: Rectangle) -> Rectangle {
+    let left = a.x.min(b.x);
+    let top = a.y.min(b.y);
+    let right = (a.x + a.width).max(b.x + b.width);
+    let bottom = (a.y + a.height).max(b.y + b.height);
+
+    Rectangle {
+        x: left,
+        y: top,
+        width: right - left,
+        height: bottom - top,
+    }
+}
+
+fn intersects(a: &Rectangle, b: &Rectangle) -> bool {
+    a.x < b.x + b.width
+        && b.x < a.x + a.width
+        && a.y < b.y + b.height
+        && b.y < a.y + a.height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::triangle::{Mesh2D, Vertex2D};
+
+    fn border(x: f32, y: f32) -> Arc<Mesh2D> {
+        let vertex = |x, y| Vertex2D {
+            position: [x, y],
+            color: [0.0, 0.0, 0.0, 1.0],
+        };
+
+        Arc::new(Mesh2D {
+            vertices: vec![
+                vertex(x, y),
+                vertex(x + 10.0, y),
+                vertex(x + 10.0, y + 10.0),
+            ],
+            indices: vec![0, 1, 2],
+        })
+    }
+
+    fn quad(x: f32, y: f32) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width: 10.0,
+            height: 10.0,
+        }
+    }
+
+    #[test]
+    fn quads_beside_the_meshes_do_not_cover_them() {
+        let mut layer = Layer::new(Rectangle::default(), Vector::new(0, 0));
+
+        assert!(!layer.covers_meshes(&quad(0.0, 0.0)));
+
+        layer.push_mesh_over_quads(border(0.0, 0.0));
+        layer.push_mesh_over_quads(border(0.0, 20.0));
+
+        assert!(!layer.covers_meshes(&quad(20.0, 0.0)));
+        assert!(!layer.covers_meshes(&quad(0.0, 30.0)));
+    }
+
+    #[test]
+    fn quads_overlapping_the_meshes_cover_them() {
+        let mut layer = Layer::new(Rectangle::default(), Vector::new(0, 0));
+
+        layer.push_mesh_over_quads(border(0.0, 0.0));
+        layer.push_mesh_over_quads(border(0.0, 20.0));
+
+        assert!(layer.covers_meshes(&quad(5.0, 5.0)));
+        assert!(layer.covers_meshes(&quad(0.0, 15.0)));
+    }
+
+    #[test]
+    fn empty_meshes_have_no_bounds() {
+        let mesh = Mesh2D {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        assert_eq!(mesh_bounds(&mesh), None);
+        assert_eq!(mesh_bounds(&border(5.0, 5.0)), Some(quad(5.0, 5.0)));
+    }
+}
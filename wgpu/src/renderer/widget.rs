@@ -1,15 +1,20 @@
+mod badge;
 mod button;
+mod cached;
 mod checkbox;
 mod column;
 mod container;
+mod pick_list;
 mod progress_bar;
 mod radio;
 mod row;
+mod rule;
 mod scrollable;
 mod slider;
 mod space;
 mod text;
 mod text_input;
+mod tooltip;
 
 #[cfg(feature = "svg")]
 mod svg;
@@ -0,0 +1,93 @@
+use crate::{badge::StyleSheet, Primitive, Renderer};
+use iced_native::{
+    badge, Element, Font, HorizontalAlignment, Layout, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
+};
+
+const SIZE: f32 = 18.0;
+const TEXT_SIZE: f32 = 12.0;
+const PADDING: f32 = 4.0;
+const MAX_COUNT: u32 = 99;
+
+impl badge::Renderer for Renderer {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        count: u32,
+        style_sheet: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content, mouse_cursor) =
+            content.draw(self, defaults, content_layout, cursor_position);
+
+        if count == 0 {
+            return (content, mouse_cursor);
+        }
+
+        let style = style_sheet.style();
+
+        let label = if count > MAX_COUNT {
+            format!("{}+", MAX_COUNT)
+        } else {
+            count.to_string()
+        };
+
+        let (text_width, _) = self.text_pipeline.measure(
+            &label,
+            TEXT_SIZE,
+            Font::Default,
+            0.0,
+            Size::INFINITY,
+        );
+
+        let width = SIZE.max(text_width + PADDING * 2.0);
+
+        let badge_bounds = Rectangle {
+            x: bounds.x + bounds.width - width / 2.0,
+            y: bounds.y - SIZE / 2.0,
+            width,
+            height: SIZE,
+        };
+
+        let badge = Primitive::Quad {
+            bounds: badge_bounds,
+            background: style.background,
+            border_radius: (SIZE / 2.0).into(),
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let label = Primitive::Text {
+            content: label,
+            bounds: badge_bounds,
+            color: style.text_color,
+            size: TEXT_SIZE,
+            font: Font::Default,
+            letter_spacing: 0.0,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        // The badge sticks out of the content, so it is drawn on its own
+        // layer on top of it, without being clipped by its parents
+        let badge = Primitive::Clip {
+            bounds: badge_bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Group {
+                primitives: vec![badge, label],
+            }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![content, badge],
+            },
+            mouse_cursor,
+        )
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{button::StyleSheet, defaults, Defaults, Primitive, Renderer};
+use crate::{
+    button::{Style, StyleSheet},
+    defaults, Defaults, Primitive, Renderer,
+};
 use iced_native::{
-    Background, Color, Element, Layout, MouseCursor, Point, Rectangle, Vector,
+    Background, BorderStyle, Color, Element, Layout, MouseCursor, Point,
+    Rectangle,
 };
 
 impl iced_native::button::Renderer for Renderer {
@@ -13,82 +17,270 @@ impl iced_native::button::Renderer for Renderer {
         defaults: &Defaults,
         bounds: Rectangle,
         cursor_position: Point,
+        is_mouse_over: bool,
         is_disabled: bool,
         is_pressed: bool,
+        is_loading: bool,
+        is_focused: bool,
         style: &Box<dyn StyleSheet>,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let styling = styling(
+            style.as_ref(),
+            is_mouse_over,
+            is_disabled,
+            is_pressed,
+            is_loading,
+        );
 
-        let styling = if is_disabled {
-            style.disabled()
-        } else if is_mouse_over {
-            if is_pressed {
-                style.pressed()
-            } else {
-                style.hovered()
-            }
+        let content = if is_loading {
+            busy_indicator(content_layout.bounds(), styling.text_color)
         } else {
-            style.active()
-        };
-
-        let (content, _) = content.draw(
-            self,
-            &Defaults {
-                text: defaults::Text {
-                    color: styling.text_color,
+            let (content, _) = content.draw(
+                self,
+                &Defaults {
+                    text: defaults::Text {
+                        color: styling.text_color,
+                    },
+                    ..*defaults
                 },
-                ..*defaults
-            },
-            content_layout,
-            cursor_position,
-        );
+                content_layout,
+                cursor_position,
+            );
 
-        (
-            if styling.background.is_some() || styling.border_width > 0 {
-                let background = Primitive::Quad {
+            content
+        };
+
+        let (primitive, mouse_cursor) = (
+            if let Some(background) = background(bounds, &styling) {
+                match Primitive::shadow(
                     bounds,
-                    background: styling
-                        .background
-                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                    border_radius: styling.border_radius,
-                    border_width: styling.border_width,
-                    border_color: styling.border_color,
-                };
-
-                if styling.shadow_offset == Vector::default() {
-                    Primitive::Group {
+                    styling.border_radius,
+                    styling.shadow_offset,
+                    styling.shadow_blur_radius,
+                    styling.shadow_color,
+                ) {
+                    Primitive::None => Primitive::Group {
                         primitives: vec![background, content],
-                    }
-                } else {
-                    // TODO: Implement proper shadow support
-                    let shadow = Primitive::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + styling.shadow_offset.x,
-                            y: bounds.y + styling.shadow_offset.y,
-                            ..bounds
-                        },
-                        background: Background::Color(
-                            [0.0, 0.0, 0.0, 0.5].into(),
-                        ),
-                        border_radius: styling.border_radius,
-                        border_width: 0,
-                        border_color: Color::TRANSPARENT,
-                    };
-
-                    Primitive::Group {
+                    },
+                    shadow => Primitive::Group {
                         primitives: vec![shadow, background, content],
-                    }
+                    },
                 }
             } else {
                 content
             },
-            if is_mouse_over {
+            if is_mouse_over && is_loading {
+                MouseCursor::Working
+            } else if is_mouse_over {
                 MouseCursor::Pointer
             } else {
                 MouseCursor::OutOfBounds
             },
-        )
+        );
+
+        if is_focused {
+            let focus_ring = Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x - FOCUS_RING_OFFSET,
+                    y: bounds.y - FOCUS_RING_OFFSET,
+                    width: bounds.width + FOCUS_RING_OFFSET * 2.0,
+                    height: bounds.height + FOCUS_RING_OFFSET * 2.0,
+                },
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: styling.border_radius.expand(FOCUS_RING_OFFSET),
+                border_width: 2,
+                border_color: match styling.background {
+                    Some(Background::Color(color)) => color,
+                    _ => styling.text_color,
+                },
+            };
+
+            (
+                Primitive::Group {
+                    primitives: vec![focus_ring, primitive],
+                },
+                mouse_cursor,
+            )
+        } else {
+            (primitive, mouse_cursor)
+        }
+    }
+}
+
+fn styling(
+    style: &dyn StyleSheet,
+    is_mouse_over: bool,
+    is_disabled: bool,
+    is_pressed: bool,
+    is_loading: bool,
+) -> Style {
+    if is_disabled {
+        style.disabled()
+    } else if is_loading {
+        style.active()
+    } else if is_mouse_over {
+        if is_pressed {
+            style.pressed()
+        } else {
+            style.hovered()
+        }
+    } else {
+        style.active()
+    }
+}
+
+fn background(bounds: Rectangle, styling: &Style) -> Option<Primitive> {
+    if styling.background.is_none() && styling.border_width == 0 {
+        return None;
+    }
+
+    let is_solid = styling.border_style == BorderStyle::Solid;
+
+    let background = Primitive::Quad {
+        bounds,
+        background: styling
+            .background
+            .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        border_radius: styling.border_radius,
+        border_width: if is_solid { styling.border_width } else { 0 },
+        border_color: styling.border_color,
+    };
+
+    Some(if is_solid {
+        background
+    } else {
+        Primitive::Group {
+            primitives: vec![
+                background,
+                Primitive::border(
+                    bounds,
+                    styling.border_radius,
+                    styling.border_width,
+                    styling.border_color,
+                    styling.border_style,
+                ),
+            ],
+        }
+    })
+}
+
+const FOCUS_RING_OFFSET: f32 = 3.0;
+
+const DOT_SIZE: f32 = 4.0;
+const DOT_SPACING: f32 = 4.0;
+
+fn busy_indicator(bounds: Rectangle, color: Color) -> Primitive {
+    let width = DOT_SIZE * 3.0 + DOT_SPACING * 2.0;
+    let x = bounds.x + (bounds.width - width) / 2.0;
+    let y = bounds.y + (bounds.height - DOT_SIZE) / 2.0;
+
+    Primitive::Group {
+        primitives: (0..3)
+            .map(|i| Primitive::Quad {
+                bounds: Rectangle {
+                    x: x + i as f32 * (DOT_SIZE + DOT_SPACING),
+                    y,
+                    width: DOT_SIZE,
+                    height: DOT_SIZE,
+                },
+                background: Background::Color(color),
+                border_radius: (DOT_SIZE / 2.0).into(),
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::Status;
+
+    fn style(status: Status) -> Style {
+        Style {
+            text_color: match status {
+                Status::Active => Color::BLACK,
+                Status::Hovered => Color::from_rgb(1.0, 0.0, 0.0),
+                Status::Pressed => Color::from_rgb(0.0, 1.0, 0.0),
+                Status::Disabled => Color::from_rgb(0.0, 0.0, 1.0),
+            },
+            ..Style::default()
+        }
+    }
+
+    #[test]
+    fn pressed_style_is_used_while_held_over_the_button() {
+        assert_eq!(
+            styling(&style, true, false, true, false).text_color,
+            style(Status::Pressed).text_color
+        );
+    }
+
+    #[test]
+    fn hovered_style_is_used_when_released_or_pressed_elsewhere() {
+        assert_eq!(
+            styling(&style, true, false, false, false).text_color,
+            style(Status::Hovered).text_color
+        );
+        assert_eq!(
+            styling(&style, false, false, true, false).text_color,
+            style(Status::Active).text_color
+        );
+    }
+
+    #[test]
+    fn border_is_drawn_without_a_background() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 40.0,
+        };
+
+        assert!(background(bounds, &Style::default()).is_none());
+
+        let outlined = Style {
+            border_width: 2,
+            border_color: Color::BLACK,
+            ..Style::default()
+        };
+
+        match background(bounds, &outlined) {
+            Some(Primitive::Quad {
+                border_width,
+                border_color,
+                ..
+            }) => {
+                assert_eq!(border_width, 2);
+                assert_eq!(border_color, Color::BLACK);
+            }
+            _ => panic!("Expected a border quad"),
+        }
+    }
+
+    #[test]
+    fn border_radius_reaches_the_background_quad() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 40.0,
+        };
+
+        let tab = Style {
+            background: Some(Background::Color(Color::WHITE)),
+            border_radius: [8.0, 8.0, 0.0, 0.0].into(),
+            ..Style::default()
+        };
+
+        match background(bounds, &tab) {
+            Some(Primitive::Quad { border_radius, .. }) => {
+                assert_eq!(border_radius.corners(), [8.0, 8.0, 0.0, 0.0]);
+            }
+            _ => panic!("Expected a background quad"),
+        }
     }
 }
@@ -0,0 +1,49 @@
+use crate::{Primitive, Renderer};
+use iced_native::{cached, Element, Layout, Point};
+
+use std::sync::Arc;
+
+impl cached::Renderer for Renderer {
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        id: u64,
+        hash: u64,
+        content: &Element<'_, Message, Self>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output {
+        let bounds = layout.bounds();
+
+        let (content, mouse_cursor) =
+            match self.texture_cache.output(id, hash, bounds) {
+                Some(output) => output,
+                None => {
+                    let (primitive, mouse_cursor) =
+                        content.draw(self, defaults, layout, cursor_position);
+                    let primitive = Arc::new(primitive);
+
+                    self.texture_cache.insert_output(
+                        id,
+                        hash,
+                        bounds,
+                        primitive.clone(),
+                        mouse_cursor,
+                    );
+
+                    (primitive, mouse_cursor)
+                }
+            };
+
+        (
+            Primitive::Cached {
+                id,
+                hash,
+                bounds,
+                opacity: 1.0,
+                content,
+            },
+            mouse_cursor,
+        )
+    }
+}
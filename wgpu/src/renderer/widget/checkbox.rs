@@ -1,9 +1,11 @@
 use crate::{checkbox::StyleSheet, Primitive, Renderer};
 use iced_native::{
-    checkbox, HorizontalAlignment, MouseCursor, Rectangle, VerticalAlignment,
+    checkbox, Background, BorderRadius, Color, HorizontalAlignment,
+    MouseCursor, Rectangle, VerticalAlignment,
 };
 
 const SIZE: f32 = 28.0;
+const FOCUS_RING_OFFSET: f32 = 3.0;
 
 impl checkbox::Renderer for Renderer {
     type Style = Box<dyn StyleSheet>;
@@ -17,10 +19,13 @@ impl checkbox::Renderer for Renderer {
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_focused: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
+        let style = if is_focused {
+            style_sheet.focused(is_checked)
+        } else if is_mouse_over {
             style_sheet.hovered(is_checked)
         } else {
             style_sheet.active(is_checked)
@@ -29,29 +34,48 @@ impl checkbox::Renderer for Renderer {
         let checkbox = Primitive::Quad {
             bounds,
             background: style.background,
-            border_radius: style.border_radius,
+            border_radius: style.border_radius.into(),
             border_width: style.border_width,
             border_color: style.border_color,
         };
 
-        (
-            Primitive::Group {
-                primitives: if is_checked {
-                    let check = Primitive::Text {
-                        content: crate::text::CHECKMARK_ICON.to_string(),
-                        font: crate::text::BUILTIN_ICONS,
-                        size: bounds.height * 0.7,
-                        bounds: bounds,
-                        color: style.checkmark_color,
-                        horizontal_alignment: HorizontalAlignment::Center,
-                        vertical_alignment: VerticalAlignment::Center,
-                    };
-
-                    vec![checkbox, check, label]
-                } else {
-                    vec![checkbox, label]
+        let mut primitives = Vec::with_capacity(4);
+
+        if is_focused {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x - FOCUS_RING_OFFSET,
+                    y: bounds.y - FOCUS_RING_OFFSET,
+                    width: bounds.width + FOCUS_RING_OFFSET * 2.0,
+                    height: bounds.height + FOCUS_RING_OFFSET * 2.0,
                 },
-            },
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: BorderRadius::from(style.border_radius)
+                    .expand(FOCUS_RING_OFFSET),
+                border_width: 2,
+                border_color: style.checkmark_color,
+            });
+        }
+
+        primitives.push(checkbox);
+
+        if is_checked {
+            primitives.push(Primitive::Text {
+                content: crate::text::CHECKMARK_ICON.to_string(),
+                font: crate::text::BUILTIN_ICONS,
+                letter_spacing: 0.0,
+                size: bounds.height * 0.7,
+                bounds,
+                color: style.checkmark_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        primitives.push(label);
+
+        (
+            Primitive::Group { primitives },
             if is_mouse_over {
                 MouseCursor::Pointer
             } else {
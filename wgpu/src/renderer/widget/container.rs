@@ -1,5 +1,7 @@
 use crate::{container, defaults, Defaults, Primitive, Renderer};
-use iced_native::{Background, Color, Element, Layout, Point, Rectangle};
+use iced_native::{
+    Background, BorderStyle, Color, Element, Layout, Point, Rectangle,
+};
 
 impl iced_native::container::Renderer for Renderer {
     type Style = Box<dyn container::StyleSheet>;
@@ -25,25 +27,85 @@ impl iced_native::container::Renderer for Renderer {
         let (content, mouse_cursor) =
             content.draw(self, &defaults, content_layout, cursor_position);
 
-        if style.background.is_some() || style.border_width > 0 {
-            let quad = Primitive::Quad {
-                bounds,
-                background: style
-                    .background
-                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                border_radius: style.border_radius,
-                border_width: style.border_width,
-                border_color: style.border_color,
-            };
-
-            (
-                Primitive::Group {
-                    primitives: vec![quad, content],
-                },
-                mouse_cursor,
-            )
-        } else {
-            (content, mouse_cursor)
+        match background(bounds, &style) {
+            Some(mut primitives) => {
+                primitives.push(content);
+
+                (Primitive::Group { primitives }, mouse_cursor)
+            }
+            None => (content, mouse_cursor),
+        }
+    }
+}
+
+fn background(
+    bounds: Rectangle,
+    style: &container::Style,
+) -> Option<Vec<Primitive>> {
+    if style.background.is_none() && style.border_width == 0 {
+        return None;
+    }
+
+    let is_solid = style.border_style == BorderStyle::Solid;
+
+    let quad = Primitive::Quad {
+        bounds,
+        background: style
+            .background
+            .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        border_radius: style.border_radius.into(),
+        border_width: if is_solid { style.border_width } else { 0 },
+        border_color: style.border_color,
+    };
+
+    Some(if is_solid {
+        vec![quad]
+    } else {
+        let border = Primitive::border(
+            bounds,
+            style.border_radius.into(),
+            style.border_width,
+            style.border_color,
+            style.border_style,
+        );
+
+        vec![quad, border]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styled_container_draws_its_background() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 200.0,
+            height: 100.0,
+        };
+
+        assert!(background(bounds, &container::Style::default()).is_none());
+
+        let color = Color::from_rgb(0.9, 0.9, 0.9);
+        let style = container::Style {
+            background: Some(Background::Color(color)),
+            ..container::Style::default()
+        };
+
+        match background(bounds, &style).as_deref() {
+            Some(
+                [Primitive::Quad {
+                    bounds: quad_bounds,
+                    background,
+                    ..
+                }],
+            ) => {
+                assert_eq!(*quad_bounds, bounds);
+                assert_eq!(*background, Background::Color(color));
+            }
+            _ => panic!("Expected a background quad"),
         }
     }
 }
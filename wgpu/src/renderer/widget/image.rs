@@ -1,5 +1,5 @@
 use crate::{Primitive, Renderer};
-use iced_native::{image, Layout, MouseCursor};
+use iced_native::{image, Layout, MouseCursor, Size, Vector};
 
 impl image::Renderer for Renderer {
     fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
@@ -9,12 +9,47 @@ impl image::Renderer for Renderer {
     fn draw(
         &mut self,
         handle: image::Handle,
+        content_fit: image::ContentFit,
+        nine_slice: Option<image::NineSlice>,
         layout: Layout<'_>,
     ) -> Self::Output {
+        let bounds = layout.bounds();
+
+        if nine_slice.is_some() {
+            return (
+                Primitive::Image {
+                    handle,
+                    bounds,
+                    nine_slice,
+                    opacity: 1.0,
+                },
+                MouseCursor::OutOfBounds,
+            );
+        }
+
+        let (width, height) = self.image_pipeline.dimensions(&handle);
+
+        let image_bounds =
+            content_fit.place(Size::new(width as f32, height as f32), bounds);
+
+        let image = Primitive::Image {
+            handle,
+            bounds: image_bounds,
+            nine_slice: None,
+            opacity: 1.0,
+        };
+
         (
-            Primitive::Image {
-                handle,
-                bounds: layout.bounds(),
+            if image_bounds.width > bounds.width
+                || image_bounds.height > bounds.height
+            {
+                Primitive::Clip {
+                    bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(image),
+                }
+            } else {
+                image
             },
             MouseCursor::OutOfBounds,
         )
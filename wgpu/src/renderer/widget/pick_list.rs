@@ -0,0 +1,194 @@
+use crate::{pick_list::StyleSheet, Primitive, Renderer};
+use iced_native::{
+    pick_list, Background, BorderRadius, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, VerticalAlignment,
+};
+
+impl pick_list::Renderer for Renderer {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        selected: Option<String>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        is_open: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_open || is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let padding = f32::from(padding);
+        let text_size = f32::from(text_size);
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let label = Primitive::Text {
+            content: selected.unwrap_or_default(),
+            bounds: Rectangle {
+                x: bounds.x + padding,
+                y: bounds.y,
+                width: (bounds.width - padding * 2.0 - text_size).max(0.0),
+                height: bounds.height,
+            },
+            color: style.text_color,
+            size: text_size,
+            font,
+            letter_spacing: 0.0,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        let arrow = arrow(
+            Point::new(
+                bounds.x + bounds.width - padding - text_size / 2.0,
+                bounds.y + bounds.height / 2.0,
+            ),
+            text_size * style.icon_size,
+            style.text_color,
+            is_open,
+        );
+
+        (
+            Primitive::Group {
+                primitives: vec![background, label, arrow],
+            },
+            if is_mouse_over {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+
+    fn draw_menu(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        options: &[String],
+        selected_option: Option<usize>,
+        hovered_option: Option<usize>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.menu();
+
+        let padding = f32::from(padding);
+        let text_size = f32::from(text_size);
+        let option_height = text_size + padding * 2.0;
+
+        let mut primitives = Vec::with_capacity(options.len() * 2 + 1);
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: BorderRadius::ZERO,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        });
+
+        for (i, option) in options.iter().enumerate() {
+            let is_selected = selected_option == Some(i);
+            let is_hovered = hovered_option == Some(i);
+
+            let option_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + option_height * i as f32,
+                width: bounds.width,
+                height: option_height,
+            };
+
+            let highlight = if is_selected {
+                Some(style.selected_background)
+            } else if is_hovered {
+                Some(style.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(background) = highlight {
+                primitives.push(Primitive::Quad {
+                    bounds: option_bounds,
+                    background,
+                    border_radius: BorderRadius::ZERO,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: option.clone(),
+                bounds: Rectangle {
+                    x: option_bounds.x + padding,
+                    y: option_bounds.y,
+                    width: (option_bounds.width - padding * 2.0).max(0.0),
+                    height: option_bounds.height,
+                },
+                color: if is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                size: text_size,
+                font,
+                letter_spacing: 0.0,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            if bounds.contains(cursor_position) {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
+
+/// Builds a small triangle pointing down, or up, out of one quad per row of
+/// pixels.
+fn arrow(center: Point, size: f32, color: Color, is_up: bool) -> Primitive {
+    let rows = (size / 2.0).round().max(1.0) as usize;
+    let top = center.y - rows as f32 / 2.0;
+
+    let primitives = (0..rows)
+        .map(|row| {
+            let distance_to_tip = if is_up { row } else { rows - 1 - row };
+            let width = size * (distance_to_tip + 1) as f32 / rows as f32;
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: center.x - width / 2.0,
+                    y: top + row as f32,
+                    width,
+                    height: 1.0,
+                },
+                background: Background::Color(color),
+                border_radius: BorderRadius::ZERO,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            }
+        })
+        .collect();
+
+    Primitive::Group { primitives }
+}
@@ -15,15 +15,14 @@ impl progress_bar::Renderer for Renderer {
     ) -> Self::Output {
         let style = style_sheet.style();
 
-        let (range_start, range_end) = range.into_inner();
-        let active_progress_width = bounds.width
-            * ((value - range_start) / (range_end - range_start).max(1.0));
+        let active_progress_width =
+            active_progress_width(bounds.width, range, value);
 
         let background = Primitive::Group {
             primitives: vec![Primitive::Quad {
                 bounds: Rectangle { ..bounds },
                 background: style.background,
-                border_radius: style.border_radius,
+                border_radius: style.border_radius.into(),
                 border_width: 0,
                 border_color: Color::TRANSPARENT,
             }],
@@ -37,7 +36,7 @@ impl progress_bar::Renderer for Renderer {
                         ..bounds
                     },
                     background: style.bar,
-                    border_radius: style.border_radius,
+                    border_radius: style.border_radius.into(),
                     border_width: 0,
                     border_color: Color::TRANSPARENT,
                 };
@@ -52,3 +51,41 @@ impl progress_bar::Renderer for Renderer {
         )
     }
 }
+
+fn active_progress_width(
+    width: f32,
+    range: std::ops::RangeInclusive<f32>,
+    value: f32,
+) -> f32 {
+    let (range_start, range_end) = range.into_inner();
+
+    if range_end > range_start {
+        let amount_filled = (value - range_start) / (range_end - range_start);
+
+        width * amount_filled.max(0.0).min(1.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar_width_is_the_normalized_value_of_the_track() {
+        assert_eq!(active_progress_width(200.0, 0.0..=100.0, 25.0), 50.0);
+        assert_eq!(active_progress_width(200.0, 10.0..=20.0, 15.0), 100.0);
+    }
+
+    #[test]
+    fn bar_width_is_clamped_to_the_track() {
+        assert_eq!(active_progress_width(200.0, 0.0..=100.0, -10.0), 0.0);
+        assert_eq!(active_progress_width(200.0, 0.0..=100.0, 150.0), 200.0);
+    }
+
+    #[test]
+    fn empty_range_has_no_bar() {
+        assert_eq!(active_progress_width(200.0, 50.0..=50.0, 50.0), 0.0);
+    }
+}
@@ -2,7 +2,7 @@ use crate::{radio::StyleSheet, Primitive, Renderer};
 use iced_native::{radio, Background, Color, MouseCursor, Rectangle};
 
 const SIZE: f32 = 28.0;
-const DOT_SIZE: f32 = SIZE / 2.0;
+const FOCUS_RING_OFFSET: f32 = 3.0;
 
 impl radio::Renderer for Renderer {
     type Style = Box<dyn StyleSheet>;
@@ -16,44 +16,78 @@ impl radio::Renderer for Renderer {
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
-        (label, _): Self::Output,
+        is_focused: bool,
+        is_enabled: bool,
+        (mut label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
+        let is_mouse_over = is_mouse_over && is_enabled;
+
+        if !is_enabled {
+            if let Primitive::Text { color, .. } = &mut label {
+                color.a *= 0.5;
+            }
+        }
+
+        let style = if !is_enabled {
+            style_sheet.disabled()
+        } else if is_focused {
+            style_sheet.focused()
+        } else if is_mouse_over {
             style_sheet.hovered()
         } else {
             style_sheet.active()
         };
 
+        let size = bounds.width;
+        let dot_size = size / 2.0;
+
         let radio = Primitive::Quad {
             bounds,
             background: style.background,
-            border_radius: (SIZE / 2.0) as u16,
+            border_radius: (size / 2.0).into(),
             border_width: style.border_width,
             border_color: style.border_color,
         };
 
-        (
-            Primitive::Group {
-                primitives: if is_selected {
-                    let radio_circle = Primitive::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + DOT_SIZE / 2.0,
-                            y: bounds.y + DOT_SIZE / 2.0,
-                            width: bounds.width - DOT_SIZE,
-                            height: bounds.height - DOT_SIZE,
-                        },
-                        background: Background::Color(style.dot_color),
-                        border_radius: (DOT_SIZE / 2.0) as u16,
-                        border_width: 0,
-                        border_color: Color::TRANSPARENT,
-                    };
+        let mut primitives = Vec::with_capacity(4);
 
-                    vec![radio, radio_circle, label]
-                } else {
-                    vec![radio, label]
+        if is_focused {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x - FOCUS_RING_OFFSET,
+                    y: bounds.y - FOCUS_RING_OFFSET,
+                    width: bounds.width + FOCUS_RING_OFFSET * 2.0,
+                    height: bounds.height + FOCUS_RING_OFFSET * 2.0,
                 },
-            },
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: (size / 2.0 + FOCUS_RING_OFFSET).into(),
+                border_width: 2,
+                border_color: style.dot_color,
+            });
+        }
+
+        primitives.push(radio);
+
+        if is_selected {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + dot_size / 2.0,
+                    y: bounds.y + dot_size / 2.0,
+                    width: bounds.width - dot_size,
+                    height: bounds.height - dot_size,
+                },
+                background: Background::Color(style.dot_color),
+                border_radius: (dot_size / 2.0).into(),
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        primitives.push(label);
+
+        (
+            Primitive::Group { primitives },
             if is_mouse_over {
                 MouseCursor::Pointer
             } else {
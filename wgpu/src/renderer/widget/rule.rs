@@ -0,0 +1,100 @@
+use crate::{rule::StyleSheet, Primitive, Renderer};
+use iced_native::{rule, Background, Color, MouseCursor, Rectangle};
+
+impl rule::Renderer for Renderer {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        style_sheet: &Self::Style,
+        is_horizontal: bool,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        (
+            Primitive::Quad {
+                bounds: line_bounds(bounds, style.width, is_horizontal),
+                background: Background::Color(style.color),
+                border_radius: style.radius,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+}
+
+/// Centers a line of the given width in the bounds of a rule.
+fn line_bounds(
+    bounds: Rectangle,
+    width: u16,
+    is_horizontal: bool,
+) -> Rectangle {
+    if is_horizontal {
+        let thickness = f32::from(width).min(bounds.height);
+
+        Rectangle {
+            x: bounds.x,
+            y: (bounds.y + (bounds.height - thickness) / 2.0).round(),
+            width: bounds.width,
+            height: thickness,
+        }
+    } else {
+        let thickness = f32::from(width).min(bounds.width);
+
+        Rectangle {
+            x: (bounds.x + (bounds.width - thickness) / 2.0).round(),
+            y: bounds.y,
+            width: thickness,
+            height: bounds.height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 100.0,
+        width: 200.0,
+        height: 20.0,
+    };
+
+    #[test]
+    fn horizontal_line_is_centered_in_the_spacing() {
+        assert_eq!(
+            line_bounds(BOUNDS, 2, true),
+            Rectangle {
+                y: 109.0,
+                height: 2.0,
+                ..BOUNDS
+            }
+        );
+    }
+
+    #[test]
+    fn vertical_line_is_centered_in_the_spacing() {
+        let bounds = Rectangle {
+            width: 20.0,
+            height: 200.0,
+            ..BOUNDS
+        };
+
+        assert_eq!(
+            line_bounds(bounds, 4, false),
+            Rectangle {
+                x: 18.0,
+                width: 4.0,
+                ..bounds
+            }
+        );
+    }
+
+    #[test]
+    fn line_is_not_thicker_than_the_spacing() {
+        assert_eq!(line_bounds(BOUNDS, 50, true), BOUNDS);
+    }
+}
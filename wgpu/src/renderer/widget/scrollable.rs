@@ -3,7 +3,6 @@ use iced_native::{
     scrollable, Background, Color, MouseCursor, Rectangle, Vector,
 };
 
-const SCROLLBAR_WIDTH: u16 = 10;
 const SCROLLBAR_MARGIN: u16 = 2;
 
 impl scrollable::Renderer for Renderer {
@@ -14,13 +13,14 @@ impl scrollable::Renderer for Renderer {
         bounds: Rectangle,
         content_bounds: Rectangle,
         offset: u32,
+        scrollbar_width: u16,
     ) -> Option<scrollable::Scrollbar> {
         if content_bounds.height > bounds.height {
             let scrollbar_bounds = Rectangle {
                 x: bounds.x + bounds.width
-                    - f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                    - f32::from(scrollbar_width + 2 * SCROLLBAR_MARGIN),
                 y: bounds.y,
-                width: f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                width: f32::from(scrollbar_width + 2 * SCROLLBAR_MARGIN),
                 height: bounds.height,
             };
 
@@ -84,7 +84,7 @@ impl scrollable::Renderer for Renderer {
                     Primitive::Quad {
                         bounds: scrollbar.scroller.bounds,
                         background: Background::Color(style.scroller.color),
-                        border_radius: style.scroller.border_radius,
+                        border_radius: style.scroller.border_radius.into(),
                         border_width: style.scroller.border_width,
                         border_color: style.scroller.border_color,
                     }
@@ -103,7 +103,7 @@ impl scrollable::Renderer for Renderer {
                         background: style
                             .background
                             .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                        border_radius: style.border_radius,
+                        border_radius: style.border_radius.into(),
                         border_width: style.border_width,
                         border_color: style.border_color,
                     }
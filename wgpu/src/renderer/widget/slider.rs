@@ -1,8 +1,10 @@
 use crate::{
-    slider::{HandleShape, StyleSheet},
+    slider::{HandleShape, Style, StyleSheet},
     Primitive, Renderer,
 };
-use iced_native::{slider, Background, Color, MouseCursor, Point, Rectangle};
+use iced_native::{
+    slider, Background, BorderRadius, Color, MouseCursor, Point, Rectangle,
+};
 
 const HANDLE_HEIGHT: f32 = 22.0;
 
@@ -13,6 +15,23 @@ impl slider::Renderer for Renderer {
         30
     }
 
+    fn handle_bounds(
+        &self,
+        bounds: Rectangle,
+        range: std::ops::RangeInclusive<f32>,
+        value: f32,
+        is_dragging: bool,
+        style_sheet: &Self::Style,
+    ) -> Rectangle {
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else {
+            style_sheet.hovered()
+        };
+
+        handle_bounds(bounds, range, value, &style)
+    }
+
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -43,7 +62,7 @@ impl slider::Renderer for Renderer {
                     height: 2.0,
                 },
                 background: Background::Color(style.rail_colors.0),
-                border_radius: 0,
+                border_radius: BorderRadius::ZERO,
                 border_width: 0,
                 border_color: Color::TRANSPARENT,
             },
@@ -55,37 +74,22 @@ impl slider::Renderer for Renderer {
                     height: 2.0,
                 },
                 background: Background::Color(style.rail_colors.1),
-                border_radius: 0,
+                border_radius: BorderRadius::ZERO,
                 border_width: 0,
                 border_color: Color::TRANSPARENT,
             },
         );
 
-        let (range_start, range_end) = range.into_inner();
-
-        let (handle_width, handle_height, handle_border_radius) =
-            match style.handle.shape {
-                HandleShape::Circle { radius } => {
-                    (f32::from(radius * 2), f32::from(radius * 2), radius)
-                }
-                HandleShape::Rectangle {
-                    width,
-                    border_radius,
-                } => (f32::from(width), HANDLE_HEIGHT, border_radius),
-            };
-
-        let handle_offset = (bounds.width - handle_width)
-            * ((value - range_start) / (range_end - range_start).max(1.0));
+        let handle_bounds = handle_bounds(bounds, range, value, &style);
+        let handle_border_radius = match style.handle.shape {
+            HandleShape::Circle { radius } => radius,
+            HandleShape::Rectangle { border_radius, .. } => border_radius,
+        };
 
         let handle = Primitive::Quad {
-            bounds: Rectangle {
-                x: bounds.x + handle_offset.round(),
-                y: rail_y - handle_height / 2.0,
-                width: handle_width,
-                height: handle_height,
-            },
+            bounds: handle_bounds,
             background: Background::Color(style.handle.color),
-            border_radius: handle_border_radius,
+            border_radius: handle_border_radius.into(),
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
         };
@@ -104,3 +108,37 @@ impl slider::Renderer for Renderer {
         )
     }
 }
+
+fn handle_bounds(
+    bounds: Rectangle,
+    range: std::ops::RangeInclusive<f32>,
+    value: f32,
+    style: &Style,
+) -> Rectangle {
+    let (range_start, range_end) = range.into_inner();
+
+    let (handle_width, handle_height) = match style.handle.shape {
+        HandleShape::Circle { radius } => {
+            (f32::from(radius * 2), f32::from(radius * 2))
+        }
+        HandleShape::Rectangle { width, .. } => {
+            (f32::from(width), HANDLE_HEIGHT)
+        }
+    };
+
+    let percent = if range_end > range_start {
+        (value - range_start) / (range_end - range_start)
+    } else {
+        0.0
+    };
+
+    let handle_offset = (bounds.width - handle_width) * percent;
+    let rail_y = bounds.y + (bounds.height / 2.0).round();
+
+    Rectangle {
+        x: bounds.x + handle_offset.round(),
+        y: rail_y - handle_height / 2.0,
+        width: handle_width,
+        height: handle_height,
+    }
+}
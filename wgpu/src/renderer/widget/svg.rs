@@ -1,5 +1,5 @@
 use crate::{Primitive, Renderer};
-use iced_native::{svg, Layout, MouseCursor};
+use iced_native::{svg, Color, Layout, MouseCursor, Size, Vector};
 
 impl svg::Renderer for Renderer {
     fn dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
@@ -9,14 +9,63 @@ impl svg::Renderer for Renderer {
     fn draw(
         &mut self,
         handle: svg::Handle,
+        content_fit: svg::ContentFit,
+        color: Option<Color>,
         layout: Layout<'_>,
     ) -> Self::Output {
+        let bounds = layout.bounds();
+        let (width, height) = self.image_pipeline.viewport_dimensions(&handle);
+
+        // The SVG is rasterized at the size of the fitted bounds
+        let svg_bounds =
+            content_fit.place(Size::new(width as f32, height as f32), bounds);
+
+        let svg = Primitive::Svg {
+            handle,
+            color,
+            bounds: svg_bounds,
+            opacity: 1.0,
+        };
+
         (
-            Primitive::Svg {
-                handle,
-                bounds: layout.bounds(),
+            if svg_bounds.width > bounds.width
+                || svg_bounds.height > bounds.height
+            {
+                Primitive::Clip {
+                    bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(svg),
+                }
+            } else {
+                svg
             },
             MouseCursor::OutOfBounds,
         )
     }
 }
+
+impl Renderer {
+    /// Returns the default dimensions of the SVG of the given `svg::Handle`
+    /// in physical pixels, for the given scale factor.
+    ///
+    /// Layout happens in logical units, so the default dimensions used by
+    /// an `Svg` widget can be off by a pixel on HiDPI displays. Sizing an
+    /// `Svg` with these dimensions, divided by the scale factor, makes it
+    /// rasterize at exactly this size and stay sharp.
+    pub fn svg_dimensions(
+        &self,
+        handle: &svg::Handle,
+        scale_factor: f32,
+    ) -> (u32, u32) {
+        self.image_pipeline
+            .scaled_viewport_dimensions(handle, scale_factor)
+    }
+
+    /// Drops every SVG and rasterization kept by the [`Renderer`], forcing
+    /// them to be loaded and rasterized again.
+    ///
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn clear_svg_cache(&mut self) {
+        self.image_pipeline.clear_svg_cache();
+    }
+}
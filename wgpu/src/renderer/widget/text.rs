@@ -1,7 +1,7 @@
 use crate::{Primitive, Renderer};
 use iced_native::{
-    text, Color, Font, HorizontalAlignment, MouseCursor, Rectangle, Size,
-    VerticalAlignment,
+    text, Background, BorderRadius, Color, Font, HorizontalAlignment,
+    MouseCursor, Rectangle, Size, VerticalAlignment,
 };
 
 use std::f32;
@@ -9,6 +9,13 @@ use std::f32;
 // TODO: Obtain from renderer configuration
 const DEFAULT_TEXT_SIZE: f32 = 20.0;
 
+const SELECTION_COLOR: Color = Color {
+    r: 0.8,
+    g: 0.8,
+    b: 1.0,
+    a: 1.0,
+};
+
 impl text::Renderer for Renderer {
     fn default_size(&self) -> u16 {
         DEFAULT_TEXT_SIZE as u16
@@ -19,10 +26,16 @@ impl text::Renderer for Renderer {
         content: &str,
         size: u16,
         font: Font,
+        letter_spacing: f32,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline
-            .measure(content, f32::from(size), font, bounds)
+        self.text_pipeline.measure(
+            content,
+            f32::from(size),
+            font,
+            letter_spacing,
+            bounds,
+        )
     }
 
     fn draw(
@@ -32,6 +45,7 @@ impl text::Renderer for Renderer {
         content: &str,
         size: u16,
         font: Font,
+        letter_spacing: f32,
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
@@ -43,10 +57,40 @@ impl text::Renderer for Renderer {
                 bounds,
                 color: color.unwrap_or(defaults.text.color),
                 font,
+                letter_spacing,
                 horizontal_alignment,
                 vertical_alignment,
             },
             MouseCursor::OutOfBounds,
         )
     }
+
+    fn draw_selection(
+        &mut self,
+        selection: &[Rectangle],
+        is_mouse_over: bool,
+        (text, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let mut primitives: Vec<Primitive> = selection
+            .iter()
+            .map(|bounds| Primitive::Quad {
+                bounds: *bounds,
+                background: Background::Color(SELECTION_COLOR),
+                border_radius: BorderRadius::ZERO,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            })
+            .collect();
+
+        primitives.push(text);
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                MouseCursor::Text
+            } else {
+                mouse_cursor
+            },
+        )
+    }
 }
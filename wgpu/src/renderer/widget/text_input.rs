@@ -1,8 +1,8 @@
 use crate::{text_input::StyleSheet, Primitive, Renderer};
 
 use iced_native::{
-    text_input, Background, Color, Font, HorizontalAlignment, MouseCursor,
-    Point, Rectangle, Size, Vector, VerticalAlignment,
+    text_input, Background, BorderRadius, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, Size, Vector, VerticalAlignment,
 };
 use std::f32;
 
@@ -19,6 +19,7 @@ impl text_input::Renderer for Renderer {
             value,
             f32::from(size),
             font,
+            0.0,
             Size::INFINITY,
         );
 
@@ -67,6 +68,7 @@ impl text_input::Renderer for Renderer {
         value: &text_input::Value,
         state: &text_input::State,
         style_sheet: &Self::Style,
+        icons: Vec<Self::Output>,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
@@ -81,7 +83,7 @@ impl text_input::Renderer for Renderer {
         let input = Primitive::Quad {
             bounds,
             background: style.background,
-            border_radius: style.border_radius,
+            border_radius: style.border_radius.into(),
             border_width: style.border_width,
             border_color: style.border_color,
         };
@@ -101,6 +103,7 @@ impl text_input::Renderer for Renderer {
             }
             .into(),
             font,
+            letter_spacing: 0.0,
             bounds: Rectangle {
                 width: f32::INFINITY,
                 ..text_bounds
@@ -120,22 +123,54 @@ impl text_input::Renderer for Renderer {
                 font,
             );
 
-            let cursor = Primitive::Quad {
-                bounds: Rectangle {
-                    x: text_bounds.x + text_value_width,
-                    y: text_bounds.y,
-                    width: 1.0,
-                    height: text_bounds.height,
+            let cursor = match state.selection(value) {
+                Some((start, end)) => {
+                    let left = text_input::Renderer::measure_value(
+                        self,
+                        &value.until(start).to_string(),
+                        size,
+                        font,
+                    );
+                    let right = text_input::Renderer::measure_value(
+                        self,
+                        &value.until(end).to_string(),
+                        size,
+                        font,
+                    );
+
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + left,
+                            y: text_bounds.y,
+                            width: right - left,
+                            height: text_bounds.height,
+                        },
+                        background: Background::Color(
+                            style_sheet.selection_color(),
+                        ),
+                        border_radius: BorderRadius::ZERO,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    }
+                }
+                None if state.is_cursor_visible() => Primitive::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x + text_value_width,
+                        y: text_bounds.y,
+                        width: 1.0,
+                        height: text_bounds.height,
+                    },
+                    background: Background::Color(style_sheet.value_color()),
+                    border_radius: BorderRadius::ZERO,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
                 },
-                background: Background::Color(style_sheet.value_color()),
-                border_radius: 0,
-                border_width: 0,
-                border_color: Color::TRANSPARENT,
+                None => Primitive::None,
             };
 
             (
                 Primitive::Group {
-                    primitives: vec![text_value, cursor],
+                    primitives: vec![cursor, text_value],
                 },
                 Vector::new(offset as u32, 0),
             )
@@ -149,16 +184,23 @@ impl text_input::Renderer for Renderer {
             content: Box::new(contents_primitive),
         };
 
-        (
-            Primitive::Group {
-                primitives: vec![input, contents],
-            },
-            if is_mouse_over {
-                MouseCursor::Text
-            } else {
-                MouseCursor::OutOfBounds
-            },
-        )
+        let mut mouse_cursor = if is_mouse_over {
+            MouseCursor::Text
+        } else {
+            MouseCursor::OutOfBounds
+        };
+
+        let mut primitives = vec![input, contents];
+
+        for (icon, icon_mouse_cursor) in icons {
+            if icon_mouse_cursor > MouseCursor::Idle {
+                mouse_cursor = icon_mouse_cursor;
+            }
+
+            primitives.push(icon);
+        }
+
+        (Primitive::Group { primitives }, mouse_cursor)
     }
 }
 
@@ -0,0 +1,45 @@
+use crate::{tooltip::StyleSheet, Primitive, Renderer};
+use iced_native::{
+    tooltip, Font, HorizontalAlignment, MouseCursor, Rectangle,
+    VerticalAlignment,
+};
+
+impl tooltip::Renderer for Renderer {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        tip: &str,
+        text_size: u16,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let label = Primitive::Text {
+            content: tip.to_string(),
+            bounds,
+            color: style.text_color,
+            size: f32::from(text_size),
+            font: Font::Default,
+            letter_spacing: 0.0,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![background, label],
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+}
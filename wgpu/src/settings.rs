@@ -1,3 +1,5 @@
+//! Configure a renderer.
+
 /// The settings of a [`Renderer`].
 ///
 /// [`Renderer`]: struct.Renderer.html
@@ -7,4 +9,153 @@ pub struct Settings {
     ///
     /// If `None` is provided, a default system font will be chosen.
     pub default_font: Option<&'static [u8]>,
+
+    /// The initial width and height of the texture atlases used to cache
+    /// glyphs.
+    ///
+    /// An atlas grows automatically when it is not big enough to fit the
+    /// glyphs of a frame. Therefore, if you draw a lot of different glyphs,
+    /// you should start with a bigger atlas to avoid resizing it while
+    /// drawing.
+    ///
+    /// Images and SVGs do not use these atlases.
+    ///
+    /// If `None` is provided, a `2048x2048` atlas will be used.
+    pub text_atlas_size: Option<u32>,
+
+    /// The maximum width and height of the texture atlases used to cache
+    /// glyphs.
+    ///
+    /// When the glyphs drawn at once do not fit in an atlas of this size,
+    /// they spill over to an additional atlas instead of being dropped.
+    /// Additional atlases are kept around for later frames.
+    ///
+    /// It should not exceed the maximum texture size of the device.
+    ///
+    /// If `None` is provided, `4096` will be used.
+    pub text_atlas_max_size: Option<u32>,
+
+    /// The power preference used when choosing a graphics adapter.
+    ///
+    /// It can be used to force the discrete or integrated GPU on devices with
+    /// more than one.
+    pub power_preference: PowerPreference,
+
+    /// The graphics [`Backend`] to use.
+    ///
+    /// If `None` is provided, or if no adapter supports the chosen
+    /// [`Backend`], any backend available will be used.
+    ///
+    /// [`Backend`]: enum.Backend.html
+    pub backend: Option<Backend>,
+
+    /// Whether to skip the preferred graphics adapter and use a fallback
+    /// adapter directly.
+    ///
+    /// A fallback adapter is also used automatically when no preferred
+    /// adapter can be found. Fallback adapters use the secondary backends of
+    /// `wgpu` (OpenGL and DirectX 11).
+    ///
+    /// This is not a software renderer. However, on machines without working
+    /// GPU drivers, those backends may still be available through a software
+    /// rasterizer installed in the system, like Mesa's llvmpipe or Microsoft
+    /// WARP.
+    pub force_fallback_adapter: bool,
+
+    /// Whether to rasterize SVGs at a few scales at once (1x, 1.5x and 2x of
+    /// the first size they are drawn at).
+    ///
+    /// When enabled, resizing an SVG within that range reuses the nearest
+    /// rasterization instead of rasterizing it again, which makes resizing
+    /// smoother at the cost of extra memory.
+    pub prerasterize_svg_scales: bool,
+
+    /// The maximum amount of SVG rasterizations kept in memory.
+    ///
+    /// When the limit is reached, the least recently used rasterizations are
+    /// dropped first. Rasterizations used in the current frame are always
+    /// kept.
+    ///
+    /// If `None` is provided, the amount is unbounded.
+    pub svg_cache_capacity: Option<usize>,
+
+    /// Whether to rasterize SVGs on background threads.
+    ///
+    /// When enabled, rasterizing a big SVG does not stall the frame where it
+    /// is first drawn. Instead, the SVG is drawn as nothing until it is
+    /// ready. Then, the window is redrawn to show it.
+    pub asynchronous_svg_rasterization: bool,
+
+    /// The resolution used to convert the absolute units of SVGs, like `mm`
+    /// or `pt`, to pixels, in dots per inch.
+    ///
+    /// If `None` is provided, `96` will be used.
+    pub svg_dpi: Option<u16>,
+
+    /// The font family used for the text of SVGs that do not specify one.
+    ///
+    /// If `None` is provided, `Times New Roman` will be used.
+    pub svg_font_family: Option<&'static str>,
+}
+
+/// The power preference of a graphics adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerPreference {
+    /// Let the graphics library choose the adapter.
+    Default,
+
+    /// Prefer an adapter with low power usage, like an integrated GPU.
+    LowPower,
+
+    /// Prefer an adapter with high performance, like a discrete GPU.
+    HighPerformance,
+}
+
+impl Default for PowerPreference {
+    fn default() -> Self {
+        PowerPreference::Default
+    }
+}
+
+impl From<PowerPreference> for wgpu::PowerPreference {
+    fn from(power_preference: PowerPreference) -> wgpu::PowerPreference {
+        match power_preference {
+            PowerPreference::Default => wgpu::PowerPreference::Default,
+            PowerPreference::LowPower => wgpu::PowerPreference::LowPower,
+            PowerPreference::HighPerformance => {
+                wgpu::PowerPreference::HighPerformance
+            }
+        }
+    }
+}
+
+/// A graphics backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Vulkan
+    Vulkan,
+
+    /// Metal
+    Metal,
+
+    /// DirectX 12
+    Dx12,
+
+    /// DirectX 11
+    Dx11,
+
+    /// OpenGL
+    Gl,
+}
+
+impl From<Backend> for wgpu::BackendBit {
+    fn from(backend: Backend) -> wgpu::BackendBit {
+        match backend {
+            Backend::Vulkan => wgpu::BackendBit::VULKAN,
+            Backend::Metal => wgpu::BackendBit::METAL,
+            Backend::Dx12 => wgpu::BackendBit::DX12,
+            Backend::Dx11 => wgpu::BackendBit::DX11,
+            Backend::Gl => wgpu::BackendBit::GL,
+        }
+    }
 }
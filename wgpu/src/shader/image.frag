@@ -1,6 +1,7 @@
 #version 450
 
 layout(location = 0) in vec2 v_Uv;
+layout(location = 1) in float v_Opacity;
 
 layout(set = 0, binding = 1) uniform sampler u_Sampler;
 layout(set = 1, binding = 0) uniform texture2D u_Texture;
@@ -8,5 +9,7 @@ layout(set = 1, binding = 0) uniform texture2D u_Texture;
 layout(location = 0) out vec4 o_Color;
 
 void main() {
-    o_Color = texture(sampler2D(u_Texture, u_Sampler), v_Uv);
+    vec4 color = texture(sampler2D(u_Texture, u_Sampler), v_Uv);
+
+    o_Color = vec4(color.rgb, color.a * v_Opacity);
 }
@@ -3,15 +3,18 @@
 layout(location = 0) in vec2 v_Pos;
 layout(location = 1) in vec2 i_Pos;
 layout(location = 2) in vec2 i_Scale;
+layout(location = 3) in float i_Opacity;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
 };
 
 layout(location = 0) out vec2 o_Uv;
+layout(location = 1) out float o_Opacity;
 
 void main() {
     o_Uv = v_Pos;
+    o_Opacity = i_Opacity;
 
     mat4 i_Transform = mat4(
         vec4(i_Scale.x, 0.0, 0.0, 0.0),
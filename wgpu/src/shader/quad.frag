@@ -4,8 +4,9 @@ layout(location = 0) in vec4 v_Color;
 layout(location = 1) in vec4 v_BorderColor;
 layout(location = 2) in vec2 v_Pos;
 layout(location = 3) in vec2 v_Scale;
-layout(location = 4) in float v_BorderRadius;
+layout(location = 4) in vec4 v_BorderRadius;
 layout(location = 5) in float v_BorderWidth;
+layout(location = 6) in float v_Blur;
 
 layout(location = 0) out vec4 o_Color;
 
@@ -27,12 +28,30 @@ float distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
     return sqrt(distance.x * distance.x + distance.y * distance.y);
 }
 
+// Selects the radius of the corner of the quad closest to the fragment.
+// The radii are given in top-left, top-right, bottom-right and bottom-left
+// order.
+float corner_radius(in vec2 frag_coord, in vec2 position, in vec2 size, in vec4 radii)
+{
+    vec2 is_bottom_right = step(position + size * 0.5, frag_coord);
+    vec2 row = mix(radii.xy, radii.wz, is_bottom_right.y);
+
+    return mix(row.x, row.y, is_bottom_right.x);
+}
+
 void main() {
     vec4 mixed_color;
 
+    float border_radius = corner_radius(
+        gl_FragCoord.xy,
+        v_Pos,
+        v_Scale,
+        v_BorderRadius
+    );
+
     // TODO: Remove branching (?)
     if(v_BorderWidth > 0) {
-        float internal_border = max(v_BorderRadius - v_BorderWidth, 0);
+        float internal_border = max(border_radius - v_BorderWidth, 0);
 
         float internal_distance = distance(
             gl_FragCoord.xy,
@@ -52,15 +71,22 @@ void main() {
         mixed_color = v_Color;
     }
 
+    // The edge fades out over the blur radius, and the corners are rounded
+    // at least as much as the blur
+    float radius = max(border_radius, v_Blur);
+
     float d = distance(
         gl_FragCoord.xy,
         v_Pos,
         v_Scale,
-        v_BorderRadius
+        radius
     );
 
-    float radius_alpha =
-        1.0 - smoothstep(max(v_BorderRadius - 0.5, 0), v_BorderRadius + 0.5, d);
+    float radius_alpha = 1.0 - smoothstep(
+        max(radius - v_Blur - 0.5, 0),
+        radius + v_Blur + 0.5,
+        d
+    );
 
     o_Color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
 }
@@ -1,7 +1,12 @@
 mod font;
+mod letter_spacing;
+mod measurement;
+mod page;
 
 use crate::Transformation;
 
+use letter_spacing::LetterSpacing;
+
 use std::{cell::RefCell, collections::HashMap};
 
 pub const BUILTIN_ICONS: iced_native::Font = iced_native::Font::External {
@@ -13,16 +18,39 @@ pub const CHECKMARK_ICON: char = '\u{F00C}';
 
 const FALLBACK_FONT: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
 
+const DEFAULT_ATLAS_SIZE: u32 = 2048;
+const DEFAULT_MAX_ATLAS_SIZE: u32 = 4096;
+
 #[derive(Debug)]
 pub struct Pipeline {
-    draw_brush: RefCell<wgpu_glyph::GlyphBrush<'static, ()>>,
+    draw_pages: RefCell<Vec<Page>>,
+    draw_fonts: RefCell<Vec<Vec<u8>>>,
     draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
+    atlas_size: u32,
+    max_atlas_size: u32,
 
     measure_brush: RefCell<glyph_brush::GlyphBrush<'static, ()>>,
+    measurement_cache: RefCell<measurement::Cache>,
+}
+
+/// A glyph atlas, with the glyph brush that draws from it.
+///
+/// When the glyphs queued at once do not fit in an atlas, they spill over to
+/// the next page. Pages are kept around to be reused by later frames.
+#[derive(Debug)]
+struct Page {
+    brush: wgpu_glyph::GlyphBrush<'static, ()>,
+    usage: page::Usage,
+    is_queued: bool,
 }
 
 impl Pipeline {
-    pub fn new(device: &mut wgpu::Device, default_font: Option<&[u8]>) -> Self {
+    pub fn new(
+        device: &mut wgpu::Device,
+        default_font: Option<&[u8]>,
+        atlas_size: Option<u32>,
+        max_atlas_size: Option<u32>,
+    ) -> Self {
         // TODO: Font customization
         let font_source = font::Source::new();
 
@@ -41,26 +69,45 @@ impl Pipeline {
 
             Ok((
                 builder,
-                glyph_brush::GlyphBrushBuilder::using_font_bytes(font).build(),
+                glyph_brush::GlyphBrushBuilder::using_font_bytes(font.clone())
+                    .build(),
+                font,
             ))
         };
 
-        let (brush_builder, measure_brush) = load_glyph_brush(default_font)
-            .unwrap_or_else(|_: wgpu_glyph::rusttype::Error| {
-                log::warn!("System font failed to load. Falling back to embedded font...");
+        let (brush_builder, measure_brush, default_font) = load_glyph_brush(
+            default_font,
+        )
+        .unwrap_or_else(|_: wgpu_glyph::rusttype::Error| {
+            log::warn!(
+                "System font failed to load. Falling back to embedded font..."
+            );
 
-                load_glyph_brush(FALLBACK_FONT.to_vec()).expect("Load fallback font")
-            });
+            load_glyph_brush(FALLBACK_FONT.to_vec())
+                .expect("Load fallback font")
+        });
+
+        let max_atlas_size = max_atlas_size.unwrap_or(DEFAULT_MAX_ATLAS_SIZE);
+        let atlas_size =
+            atlas_size.unwrap_or(DEFAULT_ATLAS_SIZE).min(max_atlas_size);
 
         let draw_brush = brush_builder
-            .initial_cache_size((2048, 2048))
+            .initial_cache_size((atlas_size, atlas_size))
             .build(device, wgpu::TextureFormat::Bgra8UnormSrgb);
 
         Pipeline {
-            draw_brush: RefCell::new(draw_brush),
+            draw_pages: RefCell::new(vec![Page {
+                brush: draw_brush,
+                usage: page::Usage::new(max_atlas_size),
+                is_queued: false,
+            }]),
+            draw_fonts: RefCell::new(vec![default_font]),
             draw_font_map: RefCell::new(HashMap::new()),
+            atlas_size,
+            max_atlas_size,
 
             measure_brush: RefCell::new(measure_brush),
+            measurement_cache: RefCell::new(measurement::Cache::new()),
         }
     }
 
@@ -68,8 +115,46 @@ impl Pipeline {
         wgpu_glyph::FontId(0)
     }
 
-    pub fn queue(&mut self, section: wgpu_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn queue(
+        &mut self,
+        device: &mut wgpu::Device,
+        section: wgpu_glyph::Section<'_>,
+        letter_spacing: f32,
+    ) {
+        let glyphs = self.glyphs(section, letter_spacing);
+        let mut pages = self.draw_pages.borrow_mut();
+
+        let index = match pages
+            .iter_mut()
+            .position(|page| page.usage.insert(&glyphs))
+        {
+            Some(index) => index,
+            None => {
+                let mut usage = page::Usage::new(self.max_atlas_size);
+                let _ = usage.insert(&glyphs);
+
+                log::info!("Adding glyph atlas page #{}", pages.len() + 1);
+
+                pages.push(Page {
+                    brush: self.build_brush(device),
+                    usage,
+                    is_queued: false,
+                });
+
+                pages.len() - 1
+            }
+        };
+
+        let page = &mut pages[index];
+        page.is_queued = true;
+
+        if letter_spacing == 0.0 {
+            page.brush.queue(section);
+        } else {
+            let layout = LetterSpacing::new(section.layout, letter_spacing);
+
+            page.brush.queue_custom_layout(section, &layout);
+        }
     }
 
     pub fn draw_queued(
@@ -80,16 +165,74 @@ impl Pipeline {
         transformation: Transformation,
         region: wgpu_glyph::Region,
     ) {
-        self.draw_brush
-            .borrow_mut()
-            .draw_queued_with_transform_and_scissoring(
-                device,
-                encoder,
-                target,
-                transformation.into(),
-                region,
-            )
-            .expect("Draw text");
+        for page in self.draw_pages.borrow_mut().iter_mut() {
+            if !page.is_queued {
+                continue;
+            }
+
+            page.brush
+                .draw_queued_with_transform_and_scissoring(
+                    device,
+                    encoder,
+                    target,
+                    transformation.into(),
+                    region,
+                )
+                .expect("Draw text");
+
+            page.usage.clear();
+            page.is_queued = false;
+        }
+    }
+
+    /// Returns the glyphs of the given section that take space in an atlas,
+    /// with their area in pixels.
+    fn glyphs(
+        &self,
+        section: wgpu_glyph::Section<'_>,
+        letter_spacing: f32,
+    ) -> HashMap<page::Glyph, u64> {
+        use wgpu_glyph::GlyphCruncher;
+
+        let wgpu_glyph::FontId(font_id) = section.font_id;
+
+        let glyph = |glyph: &wgpu_glyph::rusttype::PositionedGlyph<'_>| {
+            let bounds = glyph.pixel_bounding_box()?;
+
+            // Glyphs are padded by a pixel on each side in the atlas
+            let width = u64::from(bounds.width() as u32 + 2);
+            let height = u64::from(bounds.height() as u32 + 2);
+
+            Some((
+                page::Glyph::new(font_id, glyph.id().0, glyph.scale()),
+                width * height,
+            ))
+        };
+
+        let mut measure_brush = self.measure_brush.borrow_mut();
+
+        if letter_spacing == 0.0 {
+            measure_brush.glyphs(section).filter_map(glyph).collect()
+        } else {
+            let layout = LetterSpacing::new(section.layout, letter_spacing);
+
+            measure_brush
+                .glyphs_custom_layout(section, &layout)
+                .filter_map(glyph)
+                .collect()
+        }
+    }
+
+    fn build_brush(
+        &self,
+        device: &mut wgpu::Device,
+    ) -> wgpu_glyph::GlyphBrush<'static, ()> {
+        wgpu_glyph::GlyphBrushBuilder::using_fonts_bytes(
+            self.draw_fonts.borrow().clone(),
+        )
+        .expect("Load fonts")
+        .initial_cache_size((self.atlas_size, self.atlas_size))
+        .build(device, wgpu::TextureFormat::Bgra8UnormSrgb)
     }
 
     pub fn measure(
@@ -97,12 +240,26 @@ impl Pipeline {
         content: &str,
         size: f32,
         font: iced_native::Font,
+        letter_spacing: f32,
         bounds: iced_native::Size,
     ) -> (f32, f32) {
         use wgpu_glyph::GlyphCruncher;
 
         let wgpu_glyph::FontId(font_id) = self.find_font(font);
 
+        let key = measurement::Key {
+            content,
+            size,
+            font_id,
+            letter_spacing,
+            bounds,
+        };
+
+        if let Some(measurement) = self.measurement_cache.borrow_mut().get(&key)
+        {
+            return measurement;
+        }
+
         let section = wgpu_glyph::Section {
             text: content,
             scale: wgpu_glyph::Scale { x: size, y: size },
@@ -111,13 +268,27 @@ impl Pipeline {
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
-        {
+        let mut measure_brush = self.measure_brush.borrow_mut();
+
+        let glyph_bounds = if letter_spacing == 0.0 {
+            measure_brush.glyph_bounds(section)
+        } else {
+            let layout = LetterSpacing::new(section.layout, letter_spacing);
+
+            measure_brush.glyph_bounds_custom_layout(section, &layout)
+        };
+
+        let measurement = if let Some(bounds) = glyph_bounds {
             (bounds.width().ceil(), bounds.height().ceil())
         } else {
             (0.0, 0.0)
-        }
+        };
+
+        self.measurement_cache
+            .borrow_mut()
+            .insert(&key, measurement);
+
+        measurement
     }
 
     pub fn space_width(&self, size: f32) -> f32 {
@@ -169,8 +340,14 @@ impl Pipeline {
                 // TODO: Find a way to share font data
                 let _ = self.measure_brush.borrow_mut().add_font_bytes(bytes);
 
-                let font_id =
-                    self.draw_brush.borrow_mut().add_font_bytes(bytes);
+                let mut pages = self.draw_pages.borrow_mut();
+                let font_id = pages[0].brush.add_font_bytes(bytes);
+
+                for page in pages[1..].iter_mut() {
+                    let _ = page.brush.add_font_bytes(bytes);
+                }
+
+                self.draw_fonts.borrow_mut().push(bytes.to_vec());
 
                 let _ = self
                     .draw_font_map
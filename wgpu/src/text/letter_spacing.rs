@@ -0,0 +1,288 @@
+use glyph_brush::{
+    rusttype::{point, PositionedGlyph, Rect},
+    BuiltInLineBreaker, FontId, FontMap, GlyphPositioner, HorizontalAlign,
+    Layout, SectionGeometry, SectionText, VerticalAlign,
+};
+use std::hash::{Hash, Hasher};
+
+/// A glyph layout that adds some space between glyphs.
+///
+/// Glyphs are placed using their advance width and the kerning of the font.
+/// Lines are broken at explicit line breaks and, if the layout wraps, before
+/// any word that would overflow the bounds.
+#[derive(Debug, Clone, Copy)]
+pub struct LetterSpacing {
+    layout: Layout<BuiltInLineBreaker>,
+    spacing: f32,
+}
+
+impl LetterSpacing {
+    pub fn new(layout: Layout<BuiltInLineBreaker>, spacing: f32) -> Self {
+        LetterSpacing { layout, spacing }
+    }
+}
+
+impl Hash for LetterSpacing {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.layout.hash(state);
+        self.spacing.to_bits().hash(state);
+    }
+}
+
+struct Glyph<'font> {
+    glyph: PositionedGlyph<'font>,
+    x: f32,
+    is_whitespace: bool,
+    color: [f32; 4],
+    font_id: FontId,
+}
+
+impl<'font> Glyph<'font> {
+    fn right(&self) -> f32 {
+        self.x + self.glyph.unpositioned().h_metrics().advance_width
+    }
+}
+
+#[derive(Default)]
+struct Line<'font> {
+    glyphs: Vec<Glyph<'font>>,
+    width: f32,
+    ascent: f32,
+    descent: f32,
+    line_gap: f32,
+}
+
+impl GlyphPositioner for LetterSpacing {
+    fn calculate_glyphs<'font, F: FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+    ) -> Vec<(PositionedGlyph<'font>, [f32; 4], FontId)> {
+        let (h_align, v_align, wraps) = match self.layout {
+            Layout::SingleLine {
+                h_align, v_align, ..
+            } => (h_align, v_align, false),
+            Layout::Wrap {
+                h_align, v_align, ..
+            } => (h_align, v_align, true),
+        };
+
+        let max_width = geometry.bounds.0;
+
+        let mut lines = vec![Line::default()];
+
+        // The index where the current word starts in the last line, as the
+        // whole word moves to a new line when it does not fit
+        let mut word_start = 0;
+
+        for section in sections {
+            let font = fonts.font(section.font_id);
+            let v_metrics = font.v_metrics(section.scale);
+
+            let grow = |line: &mut Line<'font>| {
+                line.ascent = line.ascent.max(v_metrics.ascent);
+                line.descent = line.descent.min(v_metrics.descent);
+                line.line_gap = line.line_gap.max(v_metrics.line_gap);
+            };
+
+            grow(lines.last_mut().expect("Last line"));
+
+            let mut previous = None;
+
+            for character in section.text.chars() {
+                if character == '\n' {
+                    let mut line = Line::default();
+                    grow(&mut line);
+
+                    lines.push(line);
+                    word_start = 0;
+                    previous = None;
+                    continue;
+                }
+
+                if character.is_control() {
+                    continue;
+                }
+
+                let line = lines.last_mut().expect("Last line");
+
+                let glyph = font.glyph(character).scaled(section.scale);
+                let id = glyph.id();
+
+                let kerning = previous
+                    .map(|previous| {
+                        font.pair_kerning(section.scale, previous, id)
+                    })
+                    .unwrap_or(0.0);
+
+                let x = if line.glyphs.is_empty() {
+                    0.0
+                } else {
+                    line.width + self.spacing + kerning
+                };
+
+                let advance = glyph.h_metrics().advance_width;
+
+                line.glyphs.push(Glyph {
+                    glyph: glyph.positioned(point(0.0, 0.0)),
+                    x,
+                    is_whitespace: character.is_whitespace(),
+                    color: section.color,
+                    font_id: section.font_id,
+                });
+
+                line.width = x + advance;
+                previous = Some(id);
+
+                if character.is_whitespace() {
+                    word_start = line.glyphs.len();
+                    continue;
+                }
+
+                if wraps && line.width > max_width && word_start > 0 {
+                    let word = line.glyphs.split_off(word_start);
+                    let offset = word[0].x;
+
+                    // Drop the trailing whitespace of the broken line
+                    while line
+                        .glyphs
+                        .last()
+                        .map_or(false, |glyph| glyph.is_whitespace)
+                    {
+                        let _ = line.glyphs.pop();
+                    }
+
+                    line.width = line.glyphs.last().map_or(0.0, Glyph::right);
+
+                    let mut next = Line {
+                        ascent: line.ascent,
+                        descent: line.descent,
+                        line_gap: line.line_gap,
+                        ..Line::default()
+                    };
+
+                    for mut glyph in word {
+                        glyph.x -= offset;
+                        next.width = glyph.right();
+                        next.glyphs.push(glyph);
+                    }
+
+                    lines.push(next);
+                    word_start = 0;
+                }
+            }
+        }
+
+        let height: f32 = lines
+            .iter()
+            .map(|line| line.ascent - line.descent + line.line_gap)
+            .sum();
+
+        let (x, y) = geometry.screen_position;
+
+        let mut top = match v_align {
+            VerticalAlign::Top => y,
+            VerticalAlign::Center => y - height / 2.0,
+            VerticalAlign::Bottom => y - height,
+        };
+
+        let mut glyphs = Vec::new();
+
+        for line in lines {
+            let left = match h_align {
+                HorizontalAlign::Left => x,
+                HorizontalAlign::Center => x - line.width / 2.0,
+                HorizontalAlign::Right => x - line.width,
+            };
+
+            let baseline = top + line.ascent;
+
+            for glyph in line.glyphs {
+                let mut positioned = glyph.glyph;
+                positioned.set_position(point(left + glyph.x, baseline));
+
+                glyphs.push((positioned, glyph.color, glyph.font_id));
+            }
+
+            top += line.ascent - line.descent + line.line_gap;
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect<f32> {
+        self.layout.bounds_rect(geometry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glyph_brush::{rusttype::Scale, GlyphBrush, GlyphCruncher, Section};
+
+    fn brush() -> GlyphBrush<'static, ()> {
+        glyph_brush::GlyphBrushBuilder::using_font_bytes(
+            super::super::FALLBACK_FONT,
+        )
+        .build()
+    }
+
+    fn baseline(v_align: VerticalAlignment, y: f32) -> f32 {
+        let layout = Layout::default_wrap().v_align(v_align);
+
+        let section = Section {
+            text: "00:00",
+            scale: Scale::uniform(20.0),
+            screen_position: (0.0, y),
+            bounds: (200.0, 100.0),
+            layout,
+            ..Section::default()
+        };
+
+        let mut brush = brush();
+        let spacing = LetterSpacing::new(layout, 1.0);
+
+        let baseline = brush
+            .glyphs_custom_layout(section, &spacing)
+            .next()
+            .map(|glyph| glyph.position().y);
+
+        baseline.expect("First glyph")
+    }
+
+    #[test]
+    fn centered_text_is_lowered_in_taller_bounds() {
+        // The renderer positions the section at the vertical center of the
+        // bounds, which are 100 pixels tall
+        let top = baseline(VerticalAlignment::Top, 0.0);
+        let center = baseline(VerticalAlignment::Center, 50.0);
+        let bottom = baseline(VerticalAlignment::Bottom, 100.0);
+
+        assert!(top < center);
+        assert!(center < bottom);
+        assert!((center - top - (bottom - center)).abs() < 0.01);
+    }
+
+    #[test]
+    fn positive_spacing_widens_the_measured_bounds() {
+        let section = || Section {
+            text: "00:00",
+            scale: Scale::uniform(20.0),
+            ..Section::default()
+        };
+
+        let mut brush = brush();
+
+        let default = brush.glyph_bounds(section()).expect("Default bounds");
+        let spaced = brush
+            .glyph_bounds_custom_layout(
+                section(),
+                &LetterSpacing::new(section().layout, 2.0),
+            )
+            .expect("Spaced bounds");
+
+        assert!(spaced.width() > default.width());
+        assert!((spaced.width() - default.width() - 4.0 * 2.0).abs() < 1.0);
+    }
+}
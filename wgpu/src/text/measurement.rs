@@ -0,0 +1,172 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+const CAPACITY: usize = 256;
+
+/// A bounded cache of text measurements that evicts the least recently used
+/// entry when full.
+///
+/// Entries are found by the hash of their [`Key`], but a measurement is only
+/// returned when the whole [`Key`] matches, so colliding hashes are misses.
+///
+/// [`Key`]: struct.Key.html
+#[derive(Debug)]
+pub struct Cache {
+    entries: HashMap<u64, Entry>,
+    clock: u64,
+}
+
+/// The text being measured.
+#[derive(Debug, Clone, Copy)]
+pub struct Key<'a> {
+    pub content: &'a str,
+    pub size: f32,
+    pub font_id: usize,
+    pub letter_spacing: f32,
+    pub bounds: iced_native::Size,
+}
+
+impl Key<'_> {
+    fn hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.content.hash(&mut hasher);
+        self.bits().hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    fn bits(&self) -> (u32, usize, u32, u32, u32) {
+        (
+            self.size.to_bits(),
+            self.font_id,
+            self.letter_spacing.to_bits(),
+            self.bounds.width.to_bits(),
+            self.bounds.height.to_bits(),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    content: String,
+    bits: (u32, usize, u32, u32, u32),
+    measurement: (f32, f32),
+    last_used: u64,
+}
+
+impl Entry {
+    fn matches(&self, key: &Key<'_>) -> bool {
+        self.content == key.content && self.bits == key.bits()
+    }
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Cache {
+            entries: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    pub fn get(&mut self, key: &Key<'_>) -> Option<(f32, f32)> {
+        self.clock += 1;
+
+        let clock = self.clock;
+
+        self.entries
+            .get_mut(&key.hash())
+            .filter(|entry| entry.matches(key))
+            .map(|entry| {
+                entry.last_used = clock;
+                entry.measurement
+            })
+    }
+
+    pub fn insert(&mut self, key: &Key<'_>, measurement: (f32, f32)) {
+        let hash = key.hash();
+
+        if self.entries.len() >= CAPACITY && !self.entries.contains_key(&hash) {
+            let least_recently_used = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(hash, _)| *hash);
+
+            if let Some(hash) = least_recently_used {
+                let _ = self.entries.remove(&hash);
+            }
+        }
+
+        self.clock += 1;
+
+        let _ = self.entries.insert(
+            hash,
+            Entry {
+                content: key.content.to_owned(),
+                bits: key.bits(),
+                measurement,
+                last_used: self.clock,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Size;
+
+    fn key(content: &str, width: f32) -> Key<'_> {
+        Key {
+            content,
+            size: 20.0,
+            font_id: 0,
+            letter_spacing: 0.0,
+            bounds: Size::new(width, f32::INFINITY),
+        }
+    }
+
+    #[test]
+    fn measuring_the_same_text_twice_hits_the_cache() {
+        let mut cache = Cache::new();
+        let text = "Some long text\nspanning multiple lines";
+
+        assert_eq!(cache.get(&key(text, 100.0)), None);
+
+        cache.insert(&key(text, 100.0), (95.0, 40.0));
+
+        assert_eq!(cache.get(&key(text, 100.0)), Some((95.0, 40.0)));
+    }
+
+    #[test]
+    fn changing_the_width_invalidates_the_measurement() {
+        let mut cache = Cache::new();
+        let text = "Some long text";
+
+        cache.insert(&key(text, 100.0), (95.0, 40.0));
+
+        assert_eq!(cache.get(&key(text, 50.0)), None);
+    }
+
+    #[test]
+    fn least_recently_used_measurement_is_evicted() {
+        let mut cache = Cache::new();
+        let contents: Vec<String> =
+            (0..=CAPACITY).map(|i| i.to_string()).collect();
+
+        for content in &contents[..CAPACITY] {
+            cache.insert(&key(content, 100.0), (1.0, 1.0));
+        }
+
+        assert!(cache.get(&key(&contents[0], 100.0)).is_some());
+
+        cache.insert(&key(&contents[CAPACITY], 100.0), (1.0, 1.0));
+
+        assert!(cache.get(&key(&contents[0], 100.0)).is_some());
+        assert!(cache.get(&key(&contents[1], 100.0)).is_none());
+        assert!(cache.get(&key(&contents[CAPACITY], 100.0)).is_some());
+    }
+}
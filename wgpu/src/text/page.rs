@@ -0,0 +1,116 @@
+use std::collections::{HashMap, HashSet};
+
+/// A glyph, as it is rasterized in a glyph atlas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Glyph {
+    pub font_id: usize,
+    pub id: u16,
+    pub scale: (u32, u32),
+}
+
+impl Glyph {
+    pub fn new(font_id: usize, id: u16, scale: wgpu_glyph::Scale) -> Glyph {
+        Glyph {
+            font_id,
+            id,
+            scale: (scale.x.to_bits(), scale.y.to_bits()),
+        }
+    }
+}
+
+/// The glyphs queued in a page of the glyph atlas since it was last drawn.
+///
+/// The glyphs of a page must all fit in its atlas at once. Keeping track of
+/// the area they take lets us spill over to another page before the atlas
+/// needs to grow past its maximum size.
+#[derive(Debug)]
+pub struct Usage {
+    glyphs: HashSet<Glyph>,
+    area: u64,
+    capacity: u64,
+}
+
+impl Usage {
+    /// Creates an empty [`Usage`] for an atlas of the given maximum size.
+    ///
+    /// Only half of the atlas is considered usable, as glyphs cannot be
+    /// packed perfectly.
+    ///
+    /// [`Usage`]: struct.Usage.html
+    pub fn new(max_size: u32) -> Usage {
+        Usage {
+            glyphs: HashSet::new(),
+            area: 0,
+            capacity: u64::from(max_size) * u64::from(max_size) / 2,
+        }
+    }
+
+    /// Records the given glyphs, with their area in pixels, if they fit
+    /// along the glyphs already recorded.
+    ///
+    /// An empty [`Usage`] accepts any glyphs, so a single section never
+    /// spills over.
+    ///
+    /// [`Usage`]: struct.Usage.html
+    pub fn insert(&mut self, glyphs: &HashMap<Glyph, u64>) -> bool {
+        let new_area: u64 = glyphs
+            .iter()
+            .filter(|(glyph, _)| !self.glyphs.contains(glyph))
+            .map(|(_, area)| area)
+            .sum();
+
+        if self.area > 0 && self.area + new_area > self.capacity {
+            return false;
+        }
+
+        self.glyphs.extend(glyphs.keys());
+        self.area += new_area;
+
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+        self.area = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyphs(ids: &[u16], area: u64) -> HashMap<Glyph, u64> {
+        let scale = wgpu_glyph::Scale { x: 20.0, y: 20.0 };
+
+        ids.iter()
+            .map(|id| (Glyph::new(0, *id, scale), area))
+            .collect()
+    }
+
+    #[test]
+    fn glyphs_fit_until_half_of_the_atlas_is_used() {
+        let mut usage = Usage::new(20);
+
+        assert!(usage.insert(&glyphs(&[1, 2], 100)));
+        assert!(!usage.insert(&glyphs(&[3], 1)));
+    }
+
+    #[test]
+    fn recorded_glyphs_take_no_extra_space() {
+        let mut usage = Usage::new(20);
+
+        assert!(usage.insert(&glyphs(&[1, 2], 100)));
+        assert!(usage.insert(&glyphs(&[1, 2], 100)));
+    }
+
+    #[test]
+    fn empty_usage_accepts_any_glyphs() {
+        let mut usage = Usage::new(20);
+
+        assert!(usage.insert(&glyphs(&[1, 2, 3, 4], 100)));
+
+        usage.clear();
+
+        assert!(usage.insert(&glyphs(&[5], 100)));
+    }
+}
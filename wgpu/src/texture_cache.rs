@@ -0,0 +1,316 @@
+//! Keep the contents of [`Cached`] widgets drawn in offscreen textures.
+//!
+//! [`Cached`]: ../widget/cached/type.Cached.html
+use crate::Primitive;
+use iced_native::{MouseCursor, Rectangle};
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Debug, Default)]
+pub struct Cache {
+    outputs: HashMap<u64, Output>,
+    textures: HashMap<u64, Texture>,
+}
+
+#[derive(Debug)]
+struct Output {
+    hash: u64,
+    bounds: Rectangle,
+    content: Arc<Primitive>,
+    mouse_cursor: MouseCursor,
+    is_used: bool,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the output drawn for the given `id`, as long as it was drawn
+    /// with the same `hash` and `bounds`.
+    pub fn output(
+        &mut self,
+        id: u64,
+        hash: u64,
+        bounds: Rectangle,
+    ) -> Option<(Arc<Primitive>, MouseCursor)> {
+        let output = self.outputs.get_mut(&id)?;
+
+        if output.hash != hash || output.bounds != bounds {
+            return None;
+        }
+
+        output.is_used = true;
+
+        Some((output.content.clone(), output.mouse_cursor))
+    }
+
+    pub fn insert_output(
+        &mut self,
+        id: u64,
+        hash: u64,
+        bounds: Rectangle,
+        content: Arc<Primitive>,
+        mouse_cursor: MouseCursor,
+    ) {
+        let _ = self.outputs.insert(
+            id,
+            Output {
+                hash,
+                bounds,
+                content,
+                mouse_cursor,
+                is_used: true,
+            },
+        );
+    }
+
+    /// Returns the texture drawn for the given `id`, as long as it was drawn
+    /// with the same `hash`, `bounds`, and `scale_factor`.
+    pub fn texture(
+        &mut self,
+        id: u64,
+        hash: u64,
+        bounds: Rectangle,
+        scale_factor: f32,
+    ) -> Option<&Texture> {
+        if let Some(output) = self.outputs.get_mut(&id) {
+            output.is_used = true;
+        }
+
+        let texture = self.textures.get_mut(&id)?;
+
+        if texture.hash != hash
+            || texture.bounds != bounds
+            || texture.scale_factor != scale_factor
+        {
+            return None;
+        }
+
+        texture.is_used = true;
+
+        Some(texture)
+    }
+
+    /// Returns the last texture drawn for the given `id`, if any.
+    pub fn get(&self, id: u64) -> Option<&Texture> {
+        self.textures.get(&id)
+    }
+
+    pub fn insert_texture(&mut self, id: u64, texture: Texture) {
+        let _ = self.textures.insert(id, texture);
+    }
+
+    pub fn remove_texture(&mut self, id: u64) {
+        let _ = self.textures.remove(&id);
+    }
+
+    /// Drops the outputs and textures that have not been used since the last
+    /// call.
+    pub fn trim(&mut self) {
+        self.outputs.retain(|_, output| {
+            let is_used = output.is_used;
+            output.is_used = false;
+            is_used
+        });
+
+        self.textures.retain(|_, texture| {
+            let is_used = texture.is_used;
+            texture.is_used = false;
+            is_used
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct Texture {
+    hash: u64,
+    bounds: Rectangle,
+    scale_factor: f32,
+    region: Rectangle<u32>,
+    view: wgpu::TextureView,
+    bind_group: Rc<wgpu::BindGroup>,
+    _texture: wgpu::Texture,
+    is_used: bool,
+}
+
+impl Texture {
+    /// Creates an empty texture that covers the region of the given `bounds`.
+    ///
+    /// Returns `None` if the region is empty.
+    pub fn new(
+        device: &wgpu::Device,
+        texture_layout: &wgpu::BindGroupLayout,
+        hash: u64,
+        bounds: Rectangle,
+        scale_factor: f32,
+    ) -> Option<Texture> {
+        let region = region(bounds);
+        let size = region * scale_factor;
+
+        if size.width == 0 || size.height == 0 {
+            return None;
+        }
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            size: wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth: 1,
+            },
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::SAMPLED,
+        });
+
+        let view = texture.create_default_view();
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: texture_layout,
+            bindings: &[wgpu::Binding {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Some(Texture {
+            hash,
+            bounds,
+            scale_factor,
+            region,
+            view,
+            bind_group: Rc::new(bind_group),
+            _texture: texture,
+            is_used: true,
+        })
+    }
+
+    /// Returns the region covered by the [`Texture`], in logical pixels.
+    ///
+    /// [`Texture`]: struct.Texture.html
+    pub fn region(&self) -> Rectangle<u32> {
+        self.region
+    }
+
+    /// Returns the size of the [`Texture`], in physical pixels.
+    ///
+    /// [`Texture`]: struct.Texture.html
+    pub fn size(&self) -> (u32, u32) {
+        let size = self.region * self.scale_factor;
+
+        (size.width, size.height)
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    pub fn bind_group(&self) -> Rc<wgpu::BindGroup> {
+        self.bind_group.clone()
+    }
+}
+
+/// Returns the whole logical pixels covered by the given `bounds`.
+///
+/// Snapping the texture to the pixel grid lets it be drawn back without any
+/// resampling.
+fn region(bounds: Rectangle) -> Rectangle<u32> {
+    let left = bounds.x.max(0.0).floor();
+    let top = bounds.y.max(0.0).floor();
+    let right = (bounds.x + bounds.width).ceil();
+    let bottom = (bounds.y + bounds.height).ceil();
+
+    Rectangle {
+        x: left as u32,
+        y: top as u32,
+        width: (right - left).max(0.0) as u32,
+        height: (bottom - top).max(0.0) as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.5,
+        y: 20.0,
+        width: 100.0,
+        height: 40.25,
+    };
+
+    fn output(cache: &mut Cache, hash: u64, bounds: Rectangle) -> bool {
+        cache.output(0, hash, bounds).is_some()
+    }
+
+    #[test]
+    fn region_covers_the_bounds_in_whole_pixels() {
+        assert_eq!(
+            region(BOUNDS),
+            Rectangle {
+                x: 10,
+                y: 20,
+                width: 101,
+                height: 41,
+            }
+        );
+    }
+
+    #[test]
+    fn region_is_clamped_to_the_origin() {
+        assert_eq!(
+            region(Rectangle { x: -20.0, ..BOUNDS }),
+            Rectangle {
+                x: 0,
+                y: 20,
+                width: 80,
+                height: 41,
+            }
+        );
+    }
+
+    #[test]
+    fn output_is_reused_while_hash_and_bounds_match() {
+        let mut cache = Cache::new();
+
+        assert!(!output(&mut cache, 1, BOUNDS));
+
+        cache.insert_output(
+            0,
+            1,
+            BOUNDS,
+            Arc::new(Primitive::None),
+            MouseCursor::Idle,
+        );
+
+        assert!(output(&mut cache, 1, BOUNDS));
+        assert!(!output(&mut cache, 2, BOUNDS));
+        assert!(!output(&mut cache, 1, Rectangle { x: 0.0, ..BOUNDS }));
+    }
+
+    #[test]
+    fn unused_outputs_are_trimmed() {
+        let mut cache = Cache::new();
+
+        cache.insert_output(
+            0,
+            1,
+            BOUNDS,
+            Arc::new(Primitive::None),
+            MouseCursor::Idle,
+        );
+
+        cache.trim();
+        assert!(output(&mut cache, 1, BOUNDS));
+
+        cache.trim();
+        cache.trim();
+        assert!(!output(&mut cache, 1, BOUNDS));
+    }
+}
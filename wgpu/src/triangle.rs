@@ -1,7 +1,7 @@
 //! Draw meshes of triangles.
 use crate::Transformation;
-use iced_native::Rectangle;
-use std::{mem, sync::Arc};
+use iced_native::{BorderRadius, Color, Point, Rectangle};
+use std::{convert::TryFrom, mem, sync::Arc};
 
 #[derive(Debug)]
 pub(crate) struct Pipeline {
@@ -237,3 +237,597 @@ pub struct Mesh2D {
     /// Therefore, this list should always have a length that is a multiple of 3.
     pub indices: Vec<u16>,
 }
+
+impl Mesh2D {
+    /// Creates the meshes filling the given bounds, with rounded corners,
+    /// with a linear gradient.
+    ///
+    /// The `start` and `end` points are relative to the bounds and the stops
+    /// must be sorted by their offset.
+    pub(crate) fn linear_gradient(
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        start: Point,
+        end: Point,
+        stops: &[(f32, Color)],
+    ) -> Vec<Mesh2D> {
+        let start = Point::new(
+            bounds.x + start.x * bounds.width,
+            bounds.y + start.y * bounds.height,
+        );
+
+        let direction = [
+            bounds.x + end.x * bounds.width - start.x,
+            bounds.y + end.y * bounds.height - start.y,
+        ];
+
+        let length_squared =
+            direction[0] * direction[0] + direction[1] * direction[1];
+
+        // The position of a point along the gradient
+        let offset = |point: [f32; 2]| {
+            if length_squared > f32::EPSILON {
+                ((point[0] - start.x) * direction[0]
+                    + (point[1] - start.y) * direction[1])
+                    / length_squared
+            } else {
+                1.0
+            }
+        };
+
+        let mut meshes = Meshes::default();
+
+        // Split the shape at every stop, so the colors only need to be
+        // interpolated linearly inside each part
+        let mut remaining = rounded_rectangle(bounds, border_radius);
+
+        for &(stop, _) in stops {
+            let (before, after) =
+                split(&remaining, |point| offset(point) - stop);
+
+            meshes.push_polygon(&before, |point| {
+                gradient_color(stops, offset(point))
+            });
+
+            remaining = after;
+        }
+
+        meshes.push_polygon(&remaining, |point| {
+            gradient_color(stops, offset(point))
+        });
+
+        meshes.0
+    }
+
+    /// Creates the meshes drawing a dashed border inside the given bounds,
+    /// following their rounded corners.
+    ///
+    /// The dashes run along the whole outline, clockwise from the top-left
+    /// corner.
+    pub(crate) fn dashed_border(
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        width: f32,
+        color: Color,
+        dash: f32,
+        gap: f32,
+    ) -> Vec<Mesh2D> {
+        let outline = Outline::new(bounds, border_radius, width);
+        let color = color.into_linear();
+
+        let mut meshes = Meshes::default();
+
+        let mut start = 0.0;
+
+        while start < outline.length {
+            let end = (start + dash).min(outline.length);
+
+            meshes.push_strip(&outline.stroke(start, end, width), color);
+
+            start += dash + gap;
+        }
+
+        meshes.0
+    }
+
+    /// Creates the meshes drawing a dotted border inside the given bounds,
+    /// following their rounded corners.
+    ///
+    /// The dots are as big as the `width` of the border and are spread
+    /// evenly along the outline.
+    pub(crate) fn dotted_border(
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        width: f32,
+        color: Color,
+    ) -> Vec<Mesh2D> {
+        let outline = Outline::new(bounds, border_radius, width);
+        let color = color.into_linear();
+
+        let mut meshes = Meshes::default();
+
+        let dots = (outline.length / (2.0 * width)).floor().max(1.0);
+        let step = outline.length / dots;
+
+        for i in 0..dots as usize {
+            let [x, y] = outline.point(i as f32 * step);
+
+            let dot: Vec<_> = (0..CORNER_SEGMENTS * 2)
+                .map(|segment| {
+                    let angle = std::f32::consts::PI * segment as f32
+                        / CORNER_SEGMENTS as f32;
+
+                    [
+                        x + width / 2.0 * angle.cos(),
+                        y + width / 2.0 * angle.sin(),
+                    ]
+                })
+                .collect();
+
+            meshes.push_polygon(&dot, |_| color);
+        }
+
+        meshes.0
+    }
+}
+
+/// The amount of vertices that a [`Mesh2D`] can index.
+///
+/// [`Mesh2D`]: struct.Mesh2D.html
+const MAX_VERTICES: usize = u16::MAX as usize + 1;
+
+/// A list of meshes that starts a new [`Mesh2D`] whenever the last one runs
+/// out of vertex indices.
+///
+/// [`Mesh2D`]: struct.Mesh2D.html
+#[derive(Debug, Default)]
+struct Meshes(Vec<Mesh2D>);
+
+impl Meshes {
+    /// Returns a mesh with room for the given amount of vertices, along with
+    /// the index of the first one.
+    ///
+    /// Returns `None` if the vertices do not fit in any mesh.
+    fn reserve(&mut self, vertices: usize) -> Option<(&mut Mesh2D, u16)> {
+        if vertices > MAX_VERTICES {
+            return None;
+        }
+
+        let is_full = match self.0.last() {
+            Some(mesh) => mesh.vertices.len() + vertices > MAX_VERTICES,
+            None => true,
+        };
+
+        if is_full {
+            self.0.push(Mesh2D {
+                vertices: Vec::new(),
+                indices: Vec::new(),
+            });
+        }
+
+        let mesh = self.0.last_mut()?;
+        let base = u16::try_from(mesh.vertices.len()).ok()?;
+
+        Some((mesh, base))
+    }
+
+    /// Pushes a strip of quads between pairs of outer and inner points.
+    fn push_strip(&mut self, strip: &[([f32; 2], [f32; 2])], color: [f32; 4]) {
+        let quads = match u16::try_from(strip.len().saturating_sub(1)) {
+            Ok(quads) if quads > 0 => quads,
+            _ => return,
+        };
+
+        let (mesh, base) = match self.reserve(strip.len() * 2) {
+            Some(reserved) => reserved,
+            None => return,
+        };
+
+        for &(outer, inner) in strip {
+            mesh.vertices.push(Vertex2D {
+                position: outer,
+                color,
+            });
+
+            mesh.vertices.push(Vertex2D {
+                position: inner,
+                color,
+            });
+        }
+
+        // The reserved room guarantees that none of these indices overflow
+        for i in 0..quads {
+            let (outer, inner) = (base + 2 * i, base + 2 * i + 1);
+
+            mesh.indices.extend_from_slice(&[
+                outer,
+                inner,
+                outer + 2,
+                inner,
+                inner + 2,
+                outer + 2,
+            ]);
+        }
+    }
+
+    fn push_polygon(
+        &mut self,
+        polygon: &[[f32; 2]],
+        color: impl Fn([f32; 2]) -> [f32; 4],
+    ) {
+        let last = match u16::try_from(polygon.len().saturating_sub(1)) {
+            Ok(last) if last > 1 => last,
+            _ => return,
+        };
+
+        let (mesh, base) = match self.reserve(polygon.len()) {
+            Some(reserved) => reserved,
+            None => return,
+        };
+
+        mesh.vertices
+            .extend(polygon.iter().map(|&position| Vertex2D {
+                position,
+                color: color(position),
+            }));
+
+        for i in 1..last {
+            mesh.indices
+                .extend_from_slice(&[base, base + i, base + i + 1]);
+        }
+    }
+}
+
+const CORNER_SEGMENTS: usize = 8;
+
+/// Returns the vertices of the convex outline of a rounded rectangle, in
+/// clockwise order.
+fn rounded_rectangle(
+    bounds: Rectangle,
+    border_radius: BorderRadius,
+) -> Vec<[f32; 2]> {
+    let max_radius = (bounds.width / 2.0).min(bounds.height / 2.0).max(0.0);
+    let [top_left, top_right, bottom_right, bottom_left] =
+        border_radius.corners();
+
+    let left = bounds.x;
+    let top = bounds.y;
+    let right = bounds.x + bounds.width;
+    let bottom = bounds.y + bounds.height;
+
+    // The center of the arc of every corner, relative to the corner itself
+    let corners = [
+        (left, top, top_left, 1.0, 1.0),
+        (right, top, top_right, -1.0, 1.0),
+        (right, bottom, bottom_right, -1.0, -1.0),
+        (left, bottom, bottom_left, 1.0, -1.0),
+    ];
+
+    let mut vertices = Vec::new();
+
+    for (i, &(x, y, radius, dx, dy)) in corners.iter().enumerate() {
+        let radius = radius.min(max_radius).max(0.0);
+
+        if radius < 0.5 {
+            vertices.push([x, y]);
+            continue;
+        }
+
+        let (center_x, center_y) = (x + dx * radius, y + dy * radius);
+
+        vertices.extend((0..=CORNER_SEGMENTS).map(|segment| {
+            let angle = std::f32::consts::FRAC_PI_2
+                * (i as f32 + 2.0 + segment as f32 / CORNER_SEGMENTS as f32);
+
+            [
+                center_x + radius * angle.cos(),
+                center_y + radius * angle.sin(),
+            ]
+        }));
+    }
+
+    vertices
+}
+
+/// The closed line running through the middle of a border.
+struct Outline {
+    points: Vec<[f32; 2]>,
+    normals: Vec<[f32; 2]>,
+    miters: Vec<[f32; 2]>,
+    offsets: Vec<f32>,
+    length: f32,
+}
+
+impl Outline {
+    fn new(bounds: Rectangle, border_radius: BorderRadius, width: f32) -> Self {
+        let half = width / 2.0;
+        let inset = |radius: f32| (radius - half).max(0.0);
+
+        let mut points = rounded_rectangle(
+            Rectangle {
+                x: bounds.x + half,
+                y: bounds.y + half,
+                width: (bounds.width - width).max(0.0),
+                height: (bounds.height - width).max(0.0),
+            },
+            BorderRadius {
+                top_left: inset(border_radius.top_left),
+                top_right: inset(border_radius.top_right),
+                bottom_right: inset(border_radius.bottom_right),
+                bottom_left: inset(border_radius.bottom_left),
+            },
+        );
+
+        points.dedup_by(|a, b| distance(*a, *b) < f32::EPSILON);
+
+        if points.len() > 1
+            && distance(points[0], points[points.len() - 1]) < f32::EPSILON
+        {
+            let _ = points.pop();
+        }
+
+        let count = points.len();
+
+        // The normal of every segment, pointing outwards
+        let normals: Vec<_> = (0..count)
+            .map(|i| {
+                let [x, y] = points[i];
+                let [next_x, next_y] = points[(i + 1) % count];
+                let length = distance(points[i], points[(i + 1) % count]);
+
+                if length < f32::EPSILON {
+                    [0.0, 0.0]
+                } else {
+                    [(next_y - y) / length, (x - next_x) / length]
+                }
+            })
+            .collect();
+
+        // The offset of the outer side of the border at every point, which
+        // keeps its width constant where two segments meet
+        let miters = (0..count)
+            .map(|i| {
+                let previous = normals[(i + count - 1) % count];
+                let next = normals[i];
+                let sum = [previous[0] + next[0], previous[1] + next[1]];
+                let length = (sum[0] * sum[0] + sum[1] * sum[1]).sqrt();
+
+                if length < f32::EPSILON {
+                    return [next[0] * half, next[1] * half];
+                }
+
+                let miter = [sum[0] / length, sum[1] / length];
+                let scale = half / (miter[0] * next[0] + miter[1] * next[1]);
+
+                [miter[0] * scale, miter[1] * scale]
+            })
+            .collect();
+
+        let mut offsets = Vec::with_capacity(count);
+        let mut length = 0.0;
+
+        for i in 0..count {
+            offsets.push(length);
+            length += distance(points[i], points[(i + 1) % count]);
+        }
+
+        Outline {
+            points,
+            normals,
+            miters,
+            offsets,
+            length,
+        }
+    }
+
+    /// Returns the point at the given distance from the start of the outline.
+    fn point(&self, offset: f32) -> [f32; 2] {
+        let i = self
+            .offsets
+            .iter()
+            .rposition(|&start| start <= offset)
+            .unwrap_or(0);
+
+        self.point_on(i, offset)
+    }
+
+    /// Returns the point at the given distance from the start of the outline,
+    /// on the given segment.
+    fn point_on(&self, i: usize, offset: f32) -> [f32; 2] {
+        let [x, y] = self.points[i];
+        let [next_x, next_y] = self.points[(i + 1) % self.points.len()];
+        let length = distance([x, y], [next_x, next_y]);
+
+        let t = if length < f32::EPSILON {
+            0.0
+        } else {
+            ((offset - self.offsets[i]) / length).clamp(0.0, 1.0)
+        };
+
+        [x + (next_x - x) * t, y + (next_y - y) * t]
+    }
+
+    /// Returns the outer and inner points of a stroke of the given width
+    /// running along the outline between the given distances.
+    fn stroke(
+        &self,
+        start: f32,
+        end: f32,
+        width: f32,
+    ) -> Vec<([f32; 2], [f32; 2])> {
+        if self.points.is_empty() || end <= start {
+            return Vec::new();
+        }
+
+        let half = width / 2.0;
+        let side = |[x, y]: [f32; 2], [dx, dy]: [f32; 2]| {
+            ([x + dx, y + dy], [x - dx, y - dy])
+        };
+
+        // The segments where the stroke starts and ends
+        let first = self
+            .offsets
+            .iter()
+            .rposition(|&offset| offset <= start)
+            .unwrap_or(0);
+
+        let last = self
+            .offsets
+            .iter()
+            .rposition(|&offset| offset < end)
+            .unwrap_or(0);
+
+        let [dx, dy] = self.normals[first];
+
+        let mut strip =
+            vec![side(self.point_on(first, start), [dx * half, dy * half])];
+
+        for i in first + 1..=last {
+            strip.push(side(self.points[i], self.miters[i]));
+        }
+
+        let [dx, dy] = self.normals[last];
+
+        strip.push(side(self.point_on(last, end), [dx * half, dy * half]));
+
+        strip
+    }
+}
+
+fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt()
+}
+
+/// Splits a convex polygon in the parts where the given function is negative
+/// and positive.
+fn split(
+    polygon: &[[f32; 2]],
+    f: impl Fn([f32; 2]) -> f32,
+) -> (Vec<[f32; 2]>, Vec<[f32; 2]>) {
+    let mut negative = Vec::new();
+    let mut positive = Vec::new();
+
+    for (i, &current) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let (a, b) = (f(current), f(next));
+
+        if a <= 0.0 {
+            negative.push(current);
+        }
+
+        if a >= 0.0 {
+            positive.push(current);
+        }
+
+        if (a < 0.0 && b > 0.0) || (a > 0.0 && b < 0.0) {
+            let t = a / (a - b);
+            let intersection = [
+                current[0] + (next[0] - current[0]) * t,
+                current[1] + (next[1] - current[1]) * t,
+            ];
+
+            negative.push(intersection);
+            positive.push(intersection);
+        }
+    }
+
+    (negative, positive)
+}
+
+/// Returns the color of a gradient with the given stops at some offset, in
+/// __linear__ RGBA.
+fn gradient_color(stops: &[(f32, Color)], offset: f32) -> [f32; 4] {
+    let last = stops.len() - 1;
+
+    if offset <= stops[0].0 {
+        return stops[0].1.into_linear();
+    }
+
+    if offset >= stops[last].0 {
+        return stops[last].1.into_linear();
+    }
+
+    let i = stops
+        .iter()
+        .position(|&(stop, _)| stop > offset)
+        .unwrap_or(last);
+
+    let (from, a) = (stops[i - 1].0, stops[i - 1].1.into_linear());
+    let (to, b) = (stops[i].0, stops[i].1.into_linear());
+    let t = (offset - from) / (to - from);
+
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_indices_are_valid(meshes: &[Mesh2D]) {
+        for mesh in meshes {
+            assert!(mesh.vertices.len() <= MAX_VERTICES);
+            assert_eq!(mesh.indices.len() % 3, 0);
+            assert!(mesh
+                .indices
+                .iter()
+                .all(|&index| usize::from(index) < mesh.vertices.len()));
+        }
+    }
+
+    #[test]
+    fn long_dotted_border_is_split_in_many_meshes() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 5000.0,
+            height: 5000.0,
+        };
+
+        let meshes = Mesh2D::dotted_border(
+            bounds,
+            BorderRadius::from(0.0),
+            1.0,
+            Color::BLACK,
+        );
+
+        assert!(meshes.len() > 1);
+        assert_indices_are_valid(&meshes);
+    }
+
+    #[test]
+    fn short_dashed_border_fits_in_a_single_mesh() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 50.0,
+        };
+
+        let meshes = Mesh2D::dashed_border(
+            bounds,
+            BorderRadius::from(8.0),
+            2.0,
+            Color::BLACK,
+            4.0,
+            2.0,
+        );
+
+        assert_eq!(meshes.len(), 1);
+        assert_indices_are_valid(&meshes);
+    }
+
+    #[test]
+    fn degenerate_shapes_are_ignored() {
+        let mut meshes = Meshes::default();
+
+        meshes.push_strip(&[([0.0, 0.0], [1.0, 1.0])], [0.0; 4]);
+        meshes.push_polygon(&[[0.0, 0.0], [1.0, 1.0]], |_| [0.0; 4]);
+
+        assert!(meshes.0.is_empty());
+    }
+}
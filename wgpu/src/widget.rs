@@ -7,28 +7,54 @@
 //! ```
 //! use iced_wgpu::{button, Button};
 //! ```
+pub mod animated;
+pub mod badge;
 pub mod button;
+pub mod cached;
+#[cfg(feature = "canvas")]
+pub mod canvas;
 pub mod checkbox;
 pub mod container;
+pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+pub mod title_bar;
+pub mod tooltip;
 
+#[doc(no_inline)]
+pub use animated::Animated;
+#[doc(no_inline)]
+pub use badge::Badge;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use cached::Cached;
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use canvas::Canvas;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use pick_list::PickList;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rule::Rule;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use title_bar::TitleBar;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
@@ -0,0 +1,16 @@
+//! Animate the position changes of some content.
+//!
+//! An [`Animated`] widget has some local [`State`].
+//!
+//! [`Animated`]: type.Animated.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::animated::State;
+
+/// A widget that smoothly moves its contents when their position changes
+/// between frames.
+///
+/// This is an alias of an `iced_native` animated widget with an
+/// `iced_wgpu::Renderer`.
+pub type Animated<'a, Message> = iced_native::Animated<'a, Message, Renderer>;
@@ -0,0 +1,9 @@
+//! Display a small count on top of some content.
+use crate::Renderer;
+
+pub use iced_style::badge::{Style, StyleSheet};
+
+/// A widget that draws a small badge with a count on top of some content.
+///
+/// This is an alias of an `iced_native` badge with an `iced_wgpu::Renderer`.
+pub type Badge<'a, Message> = iced_native::Badge<'a, Message, Renderer>;
@@ -7,7 +7,7 @@
 use crate::Renderer;
 
 pub use iced_native::button::State;
-pub use iced_style::button::{Style, StyleSheet};
+pub use iced_style::button::{Status, Style, StyleSheet};
 
 /// A widget that produces a message when clicked.
 ///
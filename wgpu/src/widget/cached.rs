@@ -0,0 +1,16 @@
+//! Reuse the drawn output of expensive, mostly static content.
+//!
+//! A [`Cached`] widget has some local [`State`].
+//!
+//! [`Cached`]: type.Cached.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::cached::State;
+
+/// A widget that draws its contents once into a texture and reuses it until
+/// their layout or bounds change.
+///
+/// This is an alias of an `iced_native` cached widget with an
+/// `iced_wgpu::Renderer`.
+pub type Cached<'a, Message> = iced_native::Cached<'a, Message, Renderer>;
@@ -0,0 +1,284 @@
+//! Draw 2D graphics for your users.
+//!
+//! A [`Canvas`] draws the [`Geometry`] produced by a [`Program`]. The
+//! [`Geometry`] is tessellated into triangles once and kept in a [`Cache`]
+//! until the bounds of the [`Canvas`] or the state of its [`Program`] change.
+//!
+//! [`Canvas`]: struct.Canvas.html
+//! [`Geometry`]: struct.Geometry.html
+//! [`Program`]: trait.Program.html
+//! [`Cache`]: struct.Cache.html
+use crate::{Defaults, Primitive, Renderer};
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, MouseCursor, Point, Rectangle,
+    Size, Vector, Widget,
+};
+use std::hash::{Hash, Hasher as _};
+
+mod cache;
+mod fill;
+mod frame;
+mod geometry;
+mod path;
+mod program;
+mod stroke;
+
+pub use cache::Cache;
+pub use fill::Fill;
+pub use frame::Frame;
+pub use geometry::Geometry;
+pub use path::{Builder, Path};
+pub use program::Program;
+pub use stroke::{LineCap, LineJoin, Stroke};
+
+/// A widget capable of drawing 2D graphics.
+///
+/// # Example
+/// ```
+/// # use iced_wgpu::canvas::{Cache, Canvas, Frame, Geometry, Path, Program};
+/// # use iced_native::{Color, Rectangle};
+/// #
+/// struct Circle {
+///     radius: f32,
+/// }
+///
+/// impl Program for Circle {
+///     fn draw(&self, bounds: Rectangle) -> Vec<Geometry> {
+///         let mut frame = Frame::new(bounds);
+///         let circle = Path::circle(frame.center(), self.radius);
+///
+///         frame.fill(&circle, Color::BLACK);
+///
+///         vec![frame.into_geometry()]
+///     }
+/// }
+///
+/// let cache = Cache::new();
+/// let circle = Circle { radius: 50.0 };
+///
+/// let canvas = Canvas::new(&cache, &circle);
+/// ```
+///
+/// [`Canvas`]: struct.Canvas.html
+#[derive(Debug)]
+pub struct Canvas<'a, P: Program> {
+    cache: &'a Cache,
+    program: P,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, P: Program> Canvas<'a, P> {
+    const DEFAULT_SIZE: u16 = 100;
+
+    /// Creates a new [`Canvas`] that draws the given [`Program`], keeping
+    /// its [`Geometry`] in the given [`Cache`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Program`]: trait.Program.html
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Cache`]: struct.Cache.html
+    pub fn new(cache: &'a Cache, program: P) -> Self {
+        Canvas {
+            cache,
+            program,
+            width: Length::Units(Self::DEFAULT_SIZE),
+            height: Length::Units(Self::DEFAULT_SIZE),
+        }
+    }
+
+    /// Sets the width of the [`Canvas`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Canvas`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<'a, P: Program> Canvas<'a, P> {
+    /// Returns the [`Geometry`] of the [`Program`] in the given bounds,
+    /// reusing the cached one when neither the bounds nor the state of the
+    /// [`Program`] changed.
+    fn geometry(&self, bounds: Rectangle) -> Vec<Geometry> {
+        let mut hasher = Hasher::default();
+
+        for value in &[bounds.x, bounds.y, bounds.width, bounds.height] {
+            value.to_bits().hash(&mut hasher);
+        }
+
+        self.program.hash_state(&mut hasher);
+
+        self.cache
+            .draw(hasher.finish(), || self.program.draw(bounds))
+    }
+}
+
+impl<'a, Message, P: Program> Widget<Message, Renderer> for Canvas<'a, P> {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        _renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> (Primitive, MouseCursor) {
+        let bounds = layout.bounds();
+        let geometry = self.geometry(bounds);
+
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group {
+                    primitives: geometry
+                        .into_iter()
+                        .map(Geometry::into_primitive)
+                        .collect(),
+                }),
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, Message, P> From<Canvas<'a, P>> for Element<'a, Message, Renderer>
+where
+    Message: 'static,
+    P: Program + 'a,
+{
+    fn from(canvas: Canvas<'a, P>) -> Element<'a, Message, Renderer> {
+        Element::new(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Color;
+    use std::cell::Cell;
+
+    /// A circle that counts how many times it is drawn.
+    #[derive(Default)]
+    struct Circle {
+        radius: f32,
+        draws: Cell<usize>,
+    }
+
+    impl Program for Circle {
+        fn draw(&self, bounds: Rectangle) -> Vec<Geometry> {
+            self.draws.set(self.draws.get() + 1);
+
+            let mut frame = Frame::new(bounds);
+            frame
+                .fill(&Path::circle(frame.center(), self.radius), Color::BLACK);
+
+            vec![frame.into_geometry()]
+        }
+
+        fn hash_state(&self, state: &mut Hasher) {
+            self.radius.to_bits().hash(state);
+        }
+    }
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn identical_inputs_reuse_the_cached_geometry() {
+        let cache = Cache::new();
+        let circle = Circle {
+            radius: 10.0,
+            ..Circle::default()
+        };
+        let canvas = Canvas::new(&cache, &circle);
+
+        let _ = canvas.geometry(BOUNDS);
+        let _ = canvas.geometry(BOUNDS);
+
+        assert_eq!(circle.draws.get(), 1);
+    }
+
+    #[test]
+    fn changed_bounds_invalidate_the_cached_geometry() {
+        let cache = Cache::new();
+        let circle = Circle::default();
+        let canvas = Canvas::new(&cache, &circle);
+
+        let _ = canvas.geometry(BOUNDS);
+        let _ = canvas.geometry(Rectangle {
+            width: 50.0,
+            ..BOUNDS
+        });
+
+        assert_eq!(circle.draws.get(), 2);
+    }
+
+    #[test]
+    fn changed_state_invalidates_the_cached_geometry() {
+        let cache = Cache::new();
+        let small = Circle {
+            radius: 10.0,
+            ..Circle::default()
+        };
+        let large = Circle {
+            radius: 20.0,
+            ..Circle::default()
+        };
+
+        let _ = Canvas::new(&cache, &small).geometry(BOUNDS);
+        let _ = Canvas::new(&cache, &large).geometry(BOUNDS);
+        let _ = Canvas::new(&cache, &large).geometry(BOUNDS);
+
+        assert_eq!(small.draws.get(), 1);
+        assert_eq!(large.draws.get(), 1);
+    }
+
+    #[test]
+    fn clearing_the_cache_draws_the_geometry_again() {
+        let mut cache = Cache::new();
+        let circle = Circle::default();
+
+        let _ = Canvas::new(&cache, &circle).geometry(BOUNDS);
+        cache.clear();
+        let _ = Canvas::new(&cache, &circle).geometry(BOUNDS);
+
+        assert_eq!(circle.draws.get(), 2);
+    }
+}
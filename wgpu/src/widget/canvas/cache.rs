@@ -0,0 +1,58 @@
+use crate::canvas::Geometry;
+
+use std::cell::RefCell;
+
+/// A cache of the [`Geometry`] of a [`Canvas`].
+///
+/// Keep it in your application state, so the [`Geometry`] of a [`Canvas`]
+/// is only tessellated again when it changes.
+///
+/// [`Geometry`]: struct.Geometry.html
+/// [`Canvas`]: struct.Canvas.html
+#[derive(Debug, Default)]
+pub struct Cache {
+    state: RefCell<Option<(u64, Vec<Geometry>)>>,
+}
+
+impl Cache {
+    /// Creates a new empty [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn new() -> Self {
+        Cache::default()
+    }
+
+    /// Clears the [`Cache`], forcing the [`Geometry`] of its [`Canvas`] to
+    /// be drawn again.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn clear(&mut self) {
+        *self.state.borrow_mut() = None;
+    }
+
+    /// Returns the cached [`Geometry`] if it was drawn with the given key,
+    /// or draws and caches new [`Geometry`] otherwise.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub(super) fn draw(
+        &self,
+        key: u64,
+        draw: impl FnOnce() -> Vec<Geometry>,
+    ) -> Vec<Geometry> {
+        let mut state = self.state.borrow_mut();
+
+        match &*state {
+            Some((cached_key, geometry)) if *cached_key == key => {
+                geometry.clone()
+            }
+            _ => {
+                let geometry = draw();
+                *state = Some((key, geometry.clone()));
+
+                geometry
+            }
+        }
+    }
+}
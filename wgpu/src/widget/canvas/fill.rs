@@ -0,0 +1,22 @@
+use iced_native::Color;
+
+/// The style used to fill a [`Path`].
+///
+/// [`Path`]: struct.Path.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fill {
+    /// A solid color
+    Color(Color),
+}
+
+impl Default for Fill {
+    fn default() -> Fill {
+        Fill::Color(Color::BLACK)
+    }
+}
+
+impl From<Color> for Fill {
+    fn from(color: Color) -> Fill {
+        Fill::Color(color)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    canvas::{Fill, Geometry, Path, Stroke},
+    triangle::{Mesh2D, Vertex2D},
+};
+
+use iced_native::{Point, Rectangle, Size};
+use lyon::tessellation::{
+    BuffersBuilder, FillAttributes, FillOptions, FillTessellator,
+    StrokeAttributes, StrokeOptions, StrokeTessellator, VertexBuffers,
+};
+
+/// The frame of a [`Canvas`], where paths are filled and stroked.
+///
+/// The coordinates of a [`Frame`] are relative to the top-left corner of its
+/// bounds. Every [`Path`] is tessellated into triangles as soon as it is
+/// drawn.
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`Frame`]: struct.Frame.html
+/// [`Path`]: struct.Path.html
+#[allow(missing_debug_implementations)]
+pub struct Frame {
+    bounds: Rectangle,
+    buffers: VertexBuffers<Vertex2D, u16>,
+}
+
+impl Frame {
+    /// Creates a new empty [`Frame`] covering the given bounds.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn new(bounds: Rectangle) -> Frame {
+        Frame {
+            bounds,
+            buffers: VertexBuffers::new(),
+        }
+    }
+
+    /// Returns the width of the [`Frame`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn width(&self) -> f32 {
+        self.bounds.width
+    }
+
+    /// Returns the height of the [`Frame`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn height(&self) -> f32 {
+        self.bounds.height
+    }
+
+    /// Returns the size of the [`Frame`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn size(&self) -> Size {
+        Size::new(self.bounds.width, self.bounds.height)
+    }
+
+    /// Returns the center of the [`Frame`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn center(&self) -> Point {
+        Point::new(self.bounds.width / 2.0, self.bounds.height / 2.0)
+    }
+
+    /// Fills the given [`Path`] with the given [`Fill`].
+    ///
+    /// A [`Path`] that cannot be tessellated is not drawn.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Fill`]: enum.Fill.html
+    pub fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let Fill::Color(color) = fill.into();
+
+        let (x, y) = (self.bounds.x, self.bounds.y);
+        let color = color.into_linear();
+
+        let _ = FillTessellator::new().tessellate_path(
+            path.raw(),
+            &FillOptions::default(),
+            &mut BuffersBuilder::new(
+                &mut self.buffers,
+                |position: lyon::math::Point, _: FillAttributes<'_>| Vertex2D {
+                    position: [position.x + x, position.y + y],
+                    color,
+                },
+            ),
+        );
+    }
+
+    /// Draws the outline of the given [`Path`] with the given [`Stroke`].
+    ///
+    /// A [`Path`] that cannot be tessellated is not drawn.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Stroke`]: struct.Stroke.html
+    pub fn stroke(&mut self, path: &Path, stroke: impl Into<Stroke>) {
+        let stroke = stroke.into();
+
+        let (x, y) = (self.bounds.x, self.bounds.y);
+        let color = stroke.color.into_linear();
+
+        let options = StrokeOptions::default()
+            .with_line_width(stroke.width)
+            .with_line_cap(stroke.line_cap.into())
+            .with_line_join(stroke.line_join.into());
+
+        let _ = StrokeTessellator::new().tessellate(
+            path.raw(),
+            &options,
+            &mut BuffersBuilder::new(
+                &mut self.buffers,
+                |position: lyon::math::Point, _: StrokeAttributes| Vertex2D {
+                    position: [position.x + x, position.y + y],
+                    color,
+                },
+            ),
+        );
+    }
+
+    /// Turns the [`Frame`] into [`Geometry`] that can be drawn by a
+    /// [`Canvas`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn into_geometry(self) -> Geometry {
+        Geometry::new(Mesh2D {
+            vertices: self.buffers.vertices,
+            indices: self.buffers.indices,
+        })
+    }
+}
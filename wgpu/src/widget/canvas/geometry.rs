@@ -0,0 +1,27 @@
+use crate::{triangle::Mesh2D, Primitive};
+
+use std::sync::Arc;
+
+/// A group of tessellated paths, ready to be drawn by a [`Canvas`].
+///
+/// You can produce some [`Geometry`] with a [`Frame`]. Cloning it is cheap.
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`Geometry`]: struct.Geometry.html
+/// [`Frame`]: struct.Frame.html
+#[derive(Debug, Clone)]
+pub struct Geometry(Arc<Mesh2D>);
+
+impl Geometry {
+    pub(super) fn new(mesh: Mesh2D) -> Geometry {
+        Geometry(Arc::new(mesh))
+    }
+
+    /// Turns the [`Geometry`] into a [`Primitive`].
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Primitive`]: ../enum.Primitive.html
+    pub fn into_primitive(self) -> Primitive {
+        Primitive::Mesh2D(self.0)
+    }
+}
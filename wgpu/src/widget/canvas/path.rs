@@ -0,0 +1,191 @@
+use iced_native::{Point, Size};
+
+/// A shape made of lines and curves, which can be filled or stroked by a
+/// [`Frame`].
+///
+/// The coordinates of a [`Path`] are relative to the top-left corner of the
+/// [`Frame`] drawing it.
+///
+/// [`Frame`]: struct.Frame.html
+/// [`Path`]: struct.Path.html
+#[derive(Debug, Clone)]
+pub struct Path {
+    raw: lyon::path::Path,
+}
+
+impl Path {
+    /// Creates a new [`Path`] with the provided closure.
+    ///
+    /// Use the [`Builder`] to configure your [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Builder`]: struct.Builder.html
+    pub fn new(f: impl FnOnce(&mut Builder)) -> Path {
+        let mut builder = Builder::new();
+
+        f(&mut builder);
+
+        builder.build()
+    }
+
+    /// Creates a new [`Path`] made of a single line between two points.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn line(from: Point, to: Point) -> Path {
+        Path::new(|builder| {
+            builder.move_to(from);
+            builder.line_to(to);
+        })
+    }
+
+    /// Creates a new [`Path`] made of a rectangle with the given top-left
+    /// corner and size.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn rectangle(top_left: Point, size: Size) -> Path {
+        Path::new(|builder| builder.rectangle(top_left, size))
+    }
+
+    /// Creates a new [`Path`] made of a circle with the given center and
+    /// radius.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn circle(center: Point, radius: f32) -> Path {
+        Path::new(|builder| builder.circle(center, radius))
+    }
+
+    pub(super) fn raw(&self) -> &lyon::path::Path {
+        &self.raw
+    }
+}
+
+/// A [`Path`] builder.
+///
+/// Once a [`Path`] is built, it can be filled or stroked by a [`Frame`].
+///
+/// [`Path`]: struct.Path.html
+/// [`Frame`]: struct.Frame.html
+#[allow(missing_debug_implementations)]
+pub struct Builder {
+    raw: lyon::path::Builder,
+    is_open: bool,
+}
+
+impl Builder {
+    fn new() -> Builder {
+        Builder {
+            raw: lyon::path::Path::builder(),
+            is_open: false,
+        }
+    }
+
+    /// Starts a new sub-path at the given point.
+    pub fn move_to(&mut self, point: Point) {
+        self.raw.move_to(lyon::math::Point::new(point.x, point.y));
+        self.is_open = true;
+    }
+
+    /// Adds a straight line from the current point to the given one.
+    pub fn line_to(&mut self, point: Point) {
+        if self.is_open {
+            self.raw.line_to(lyon::math::Point::new(point.x, point.y));
+        } else {
+            self.move_to(point);
+        }
+    }
+
+    /// Adds a quadratic Bézier curve from the current point to the given
+    /// one, with the given control point.
+    pub fn quadratic_curve_to(&mut self, control: Point, to: Point) {
+        if !self.is_open {
+            self.move_to(control);
+        }
+
+        self.raw.quadratic_bezier_to(
+            lyon::math::Point::new(control.x, control.y),
+            lyon::math::Point::new(to.x, to.y),
+        );
+    }
+
+    /// Adds a cubic Bézier curve from the current point to the given one,
+    /// with the given control points.
+    pub fn bezier_curve_to(
+        &mut self,
+        control_a: Point,
+        control_b: Point,
+        to: Point,
+    ) {
+        if !self.is_open {
+            self.move_to(control_a);
+        }
+
+        self.raw.cubic_bezier_to(
+            lyon::math::Point::new(control_a.x, control_a.y),
+            lyon::math::Point::new(control_b.x, control_b.y),
+            lyon::math::Point::new(to.x, to.y),
+        );
+    }
+
+    /// Adds a circular arc with the given center and radius, going clockwise
+    /// from the start angle to the end angle, in radians.
+    ///
+    /// A straight line is added from the current point to the start of the
+    /// arc, if there is one.
+    pub fn arc(
+        &mut self,
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+    ) {
+        let arc = lyon::geom::Arc {
+            center: lyon::math::Point::new(center.x, center.y),
+            radii: lyon::math::Vector::new(radius, radius),
+            start_angle: lyon::math::Angle::radians(start_angle),
+            sweep_angle: lyon::math::Angle::radians(end_angle - start_angle),
+            x_rotation: lyon::math::Angle::radians(0.0),
+        };
+
+        let start = arc.sample(0.0);
+        self.line_to(Point::new(start.x, start.y));
+
+        arc.for_each_quadratic_bezier(&mut |curve| {
+            self.raw.quadratic_bezier_to(curve.ctrl, curve.to);
+        });
+    }
+
+    /// Adds a rectangle with the given top-left corner and size as a new
+    /// closed sub-path.
+    pub fn rectangle(&mut self, top_left: Point, size: Size) {
+        self.move_to(top_left);
+        self.line_to(Point::new(top_left.x + size.width, top_left.y));
+        self.line_to(Point::new(
+            top_left.x + size.width,
+            top_left.y + size.height,
+        ));
+        self.line_to(Point::new(top_left.x, top_left.y + size.height));
+        self.close();
+    }
+
+    /// Adds a circle with the given center and radius as a new closed
+    /// sub-path.
+    pub fn circle(&mut self, center: Point, radius: f32) {
+        self.is_open = false;
+        self.arc(center, radius, 0.0, 2.0 * std::f32::consts::PI);
+        self.close();
+    }
+
+    /// Closes the current sub-path with a straight line to its start.
+    pub fn close(&mut self) {
+        if self.is_open {
+            self.raw.close();
+            self.is_open = false;
+        }
+    }
+
+    fn build(self) -> Path {
+        Path {
+            raw: self.raw.build(),
+        }
+    }
+}
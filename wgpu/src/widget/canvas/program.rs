@@ -0,0 +1,48 @@
+use crate::canvas::Geometry;
+
+use iced_native::{Hasher, Rectangle};
+
+/// The state and logic of a [`Canvas`].
+///
+/// A [`Program`] draws some [`Geometry`] inside the bounds of a [`Canvas`].
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`Program`]: trait.Program.html
+/// [`Geometry`]: struct.Geometry.html
+pub trait Program {
+    /// Draws the [`Geometry`] of the [`Program`] inside the given bounds.
+    ///
+    /// It is only called when the cached [`Geometry`] of the [`Canvas`] is
+    /// outdated.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Program`]: trait.Program.html
+    /// [`Canvas`]: struct.Canvas.html
+    fn draw(&self, bounds: Rectangle) -> Vec<Geometry>;
+
+    /// Hashes the state of the [`Program`] that affects its [`Geometry`].
+    ///
+    /// The cached [`Geometry`] of a [`Canvas`] is drawn again whenever this
+    /// hash or the bounds of the [`Canvas`] change. By default, nothing is
+    /// hashed and the [`Geometry`] only depends on the bounds. In that case,
+    /// you can use [`Cache::clear`] to draw it again.
+    ///
+    /// [`Program`]: trait.Program.html
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Cache::clear`]: struct.Cache.html#method.clear
+    fn hash_state(&self, _state: &mut Hasher) {}
+}
+
+impl<T> Program for &T
+where
+    T: Program,
+{
+    fn draw(&self, bounds: Rectangle) -> Vec<Geometry> {
+        T::draw(self, bounds)
+    }
+
+    fn hash_state(&self, state: &mut Hasher) {
+        T::hash_state(self, state)
+    }
+}
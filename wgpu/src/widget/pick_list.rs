@@ -0,0 +1,18 @@
+//! Let your users choose a single value from a dropdown list of options.
+//!
+//! A [`PickList`] has some local [`State`].
+//!
+//! [`PickList`]: type.PickList.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::pick_list::State;
+pub use iced_style::pick_list::{Menu, Style, StyleSheet};
+
+/// A widget that shows the selected option and lets the user pick another
+/// one from a dropdown list.
+///
+/// This is an alias of an `iced_native` pick list with an
+/// `iced_wgpu::Renderer`.
+pub type PickList<'a, T, Message> =
+    iced_native::PickList<'a, T, Message, Renderer>;
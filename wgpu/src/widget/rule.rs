@@ -0,0 +1,9 @@
+//! Separate content with a horizontal or vertical line.
+use crate::Renderer;
+
+pub use iced_style::rule::{Style, StyleSheet};
+
+/// A horizontal or vertical line that separates content.
+///
+/// This is an alias of an `iced_native` rule with an `iced_wgpu::Renderer`.
+pub type Rule = iced_native::Rule<Renderer>;
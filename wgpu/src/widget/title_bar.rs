@@ -0,0 +1,8 @@
+//! Drag the window of your application using your own title bar.
+use crate::Renderer;
+
+/// A region of the user interface that can be dragged to move the window.
+///
+/// This is an alias of an `iced_native` title bar with an
+/// `iced_wgpu::Renderer`.
+pub type TitleBar<'a, Message> = iced_native::TitleBar<'a, Message, Renderer>;
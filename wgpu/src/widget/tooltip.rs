@@ -0,0 +1,16 @@
+//! Display a floating label when hovering over some content.
+//!
+//! A [`Tooltip`] has some local [`State`].
+//!
+//! [`Tooltip`]: type.Tooltip.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::tooltip::{Position, State};
+pub use iced_style::tooltip::{Style, StyleSheet};
+
+/// A widget that draws a floating label near its content after the cursor
+/// has hovered over it for a while.
+///
+/// This is an alias of an `iced_native` tooltip with an `iced_wgpu::Renderer`.
+pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Renderer>;
@@ -1,6 +1,8 @@
-use crate::{window::SwapChain, Renderer, Settings, Target};
+use crate::{
+    settings::PowerPreference, window::SwapChain, Renderer, Settings, Target,
+};
 
-use iced_native::MouseCursor;
+use iced_native::{window::Error, MouseCursor};
 use raw_window_handle::HasRawWindowHandle;
 
 /// A window graphics backend for iced powered by `wgpu`.
@@ -16,12 +18,9 @@ impl iced_native::window::Backend for Backend {
     type Surface = wgpu::Surface;
     type SwapChain = SwapChain;
 
-    fn new(settings: Self::Settings) -> (Backend, Renderer) {
-        let adapter = wgpu::Adapter::request(&wgpu::RequestAdapterOptions {
-            power_preference: wgpu::PowerPreference::Default,
-            backends: wgpu::BackendBit::all(),
-        })
-        .expect("Request adapter");
+    fn new(settings: Self::Settings) -> Result<(Backend, Renderer), Error> {
+        let adapter =
+            request_adapter(&settings).ok_or(Error::AdapterNotFound)?;
 
         let (mut device, queue) =
             adapter.request_device(&wgpu::DeviceDescriptor {
@@ -33,7 +32,7 @@ impl iced_native::window::Backend for Backend {
 
         let renderer = Renderer::new(&mut device, settings);
 
-        (Backend { device, queue }, renderer)
+        Ok((Backend { device, queue }, renderer))
     }
 
     fn create_surface<W: HasRawWindowHandle>(
@@ -98,4 +97,86 @@ impl iced_native::window::Backend for Backend {
 
         mouse_cursor
     }
+
+    fn set_waker(
+        &mut self,
+        renderer: &mut Renderer,
+        waker: Box<dyn Fn() + Send + Sync>,
+    ) {
+        renderer.set_waker(waker);
+    }
+}
+
+fn request_adapter(settings: &Settings) -> Option<wgpu::Adapter> {
+    let power_preference = settings.power_preference.into();
+
+    let request = |power_preference, backends| {
+        wgpu::Adapter::request(&wgpu::RequestAdapterOptions {
+            power_preference,
+            backends,
+        })
+    };
+
+    let adapter = if settings.force_fallback_adapter {
+        None
+    } else {
+        settings
+            .backend
+            .and_then(|backend| {
+                let adapter = request(power_preference, backend.into());
+
+                if adapter.is_none() {
+                    log::warn!(
+                        "No adapter found for backend {:?}, falling back to \
+                        default selection",
+                        backend
+                    );
+                }
+
+                adapter
+            })
+            .or_else(|| request(power_preference, wgpu::BackendBit::PRIMARY))
+            .or_else(|| {
+                // An integrated GPU may work when the preferred one does not
+                if settings.power_preference == PowerPreference::LowPower {
+                    None
+                } else {
+                    request(
+                        wgpu::PowerPreference::LowPower,
+                        wgpu::BackendBit::PRIMARY,
+                    )
+                }
+            })
+    };
+
+    match adapter {
+        Some(adapter) => {
+            log::info!(
+                "Selected adapter {:?} (power preference: {:?}, backend: {:?})",
+                adapter,
+                settings.power_preference,
+                settings.backend
+            );
+
+            Some(adapter)
+        }
+        None => {
+            // Secondary backends (OpenGL and DirectX 11) may still work
+            // without a GPU driver if the system provides a software
+            // rasterizer, like Mesa's llvmpipe or Microsoft WARP
+            let adapter = request(
+                wgpu::PowerPreference::LowPower,
+                wgpu::BackendBit::SECONDARY,
+            )
+            .or_else(|| {
+                request(wgpu::PowerPreference::Default, wgpu::BackendBit::all())
+            });
+
+            if let Some(adapter) = &adapter {
+                log::warn!("Selected fallback adapter {:?}", adapter);
+            }
+
+            adapter
+        }
+    }
 }
@@ -1,9 +1,14 @@
 use crate::{
-    conversion, size::Size, window, Cache, Clipboard, Command, Debug, Element,
-    Executor, Mode, MouseCursor, Proxy, Runtime, Settings, Subscription,
-    UserInterface,
+    conversion,
+    input::{keyboard, mouse},
+    size::Size,
+    window, Cache, Clipboard, Command, Debug, Element, Event, Executor, Mode,
+    MouseCursor, Proxy, Recorder, Recording, Runtime, Settings, Subscription,
+    UserEvent, UserInterface,
 };
 
+use std::{collections::VecDeque, time::Instant};
+
 /// An interactive, native cross-platform application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -45,6 +50,9 @@ pub trait Application: Sized {
     /// This title can be dynamic! The runtime will automatically update the
     /// title of your application when necessary.
     ///
+    /// The runtime queries the title after every update, and it only changes
+    /// the title of the window when the returned title is different.
+    ///
     /// [`Application`]: trait.Application.html
     fn title(&self) -> String;
 
@@ -90,6 +98,91 @@ pub trait Application: Sized {
         Mode::Windowed
     }
 
+    /// Returns the scale factor of the [`Application`].
+    ///
+    /// It multiplies the scale factor of the window given by the operating
+    /// system, which can be used to zoom the whole user interface. The
+    /// runtime lays out and draws the user interface again when it changes.
+    ///
+    /// By default, it returns `1.0`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn scale_factor(&self) -> f64 {
+        1.0
+    }
+
+    /// Returns an [`Action`] to perform on the window of the [`Application`],
+    /// if any.
+    ///
+    /// The runtime calls this method after every update. Together with
+    /// [`Settings`] without window decorations and a [`TitleBar`], it can be
+    /// used to implement custom window decorations.
+    ///
+    /// By default, no action is performed.
+    ///
+    /// [`Action`]: window/enum.Action.html
+    /// [`Application`]: trait.Application.html
+    /// [`Settings`]: settings/struct.Settings.html
+    /// [`TitleBar`]: widget/title_bar/struct.TitleBar.html
+    fn window_action(&mut self) -> Option<window::Action> {
+        None
+    }
+
+    /// Decides what happens when the window of the [`Application`] is
+    /// requested to close, like when its close button is pressed.
+    ///
+    /// Returning [`CloseBehavior::Cancel`] keeps the application running,
+    /// which is useful to ask for confirmation when there are unsaved
+    /// changes. The request is also delivered as a
+    /// [`window::Event::CloseRequested`], and every following request is
+    /// handled the same way.
+    ///
+    /// By default, the window is closed.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`CloseBehavior::Cancel`]: window/enum.CloseBehavior.html#variant.Cancel
+    /// [`window::Event::CloseRequested`]: window/enum.Event.html#variant.CloseRequested
+    fn on_close(&mut self) -> window::CloseBehavior {
+        window::CloseBehavior::Close
+    }
+
+    /// Returns whether the runtime should record the events of the
+    /// [`Application`].
+    ///
+    /// If `true`, the resulting [`Recording`] will be given to
+    /// [`recorded`](#method.recorded) when the window is closed.
+    ///
+    /// By default, events are not recorded.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Recording`]: struct.Recording.html
+    fn record_events(&self) -> bool {
+        false
+    }
+
+    /// Handles the [`Recording`] of the events of the [`Application`], once
+    /// its window is closed.
+    ///
+    /// This is only called if [`record_events`](#method.record_events)
+    /// returns `true`.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    /// [`Application`]: trait.Application.html
+    fn recorded(&mut self, _recording: Recording) {}
+
+    /// Returns a [`Recording`] to replay once the [`Application`] starts.
+    ///
+    /// The events of the [`Recording`] will be fed to the [`Application`] as
+    /// if they were produced by the user, preserving their original timing.
+    ///
+    /// By default, nothing is replayed.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    /// [`Application`]: trait.Application.html
+    fn replay(&mut self) -> Option<Recording> {
+        None
+    }
+
     /// Runs the [`Application`].
     ///
     /// This method will take control of the current thread and __will NOT
@@ -97,6 +190,11 @@ pub trait Application: Sized {
     ///
     /// It should probably be that last thing you call in your `main` function.
     ///
+    /// # Panics
+    ///
+    /// It panics if the graphics backend cannot be created, like when no
+    /// graphics adapter can be found.
+    ///
     /// [`Application`]: trait.Application.html
     fn run(
         settings: Settings,
@@ -108,22 +206,30 @@ pub trait Application: Sized {
         use winit::{
             event::{self, WindowEvent},
             event_loop::{ControlFlow, EventLoop},
-            window::WindowBuilder,
         };
 
         let mut debug = Debug::new();
 
         debug.startup_started();
         let event_loop = EventLoop::with_user_event();
+        let proxy = Proxy::new(event_loop.create_proxy());
         let mut external_messages = Vec::new();
 
+        // Create the backend first, so no window is opened if it fails
+        let (mut backend, mut renderer) = Self::Backend::new(backend_settings)
+            .unwrap_or_else(|error| {
+                panic!("Failed to create graphics backend: {}", error)
+            });
+        backend.set_waker(&mut renderer, proxy.waker());
+
         let mut runtime = {
             let executor = Self::Executor::new().expect("Create executor");
 
-            Runtime::new(executor, Proxy::new(event_loop.create_proxy()))
+            Runtime::new(executor, proxy.clone())
         };
 
-        let (mut application, init_command) = runtime.enter(|| Self::new());
+        let (mut application, mut init_command) = runtime.enter(|| Self::new());
+        external_messages.extend(init_command.take_messages());
         runtime.spawn(init_command);
 
         let subscription = application.subscription();
@@ -131,40 +237,32 @@ pub trait Application: Sized {
 
         let mut title = application.title();
         let mut mode = application.mode();
+        let mut scale_factor = application.scale_factor();
 
-        let window = {
-            let mut window_builder = WindowBuilder::new();
-
-            let (width, height) = settings.window.size;
-
-            window_builder = window_builder
-                .with_title(&title)
-                .with_inner_size(winit::dpi::LogicalSize { width, height })
-                .with_resizable(settings.window.resizable)
-                .with_decorations(settings.window.decorations)
-                .with_fullscreen(conversion::fullscreen(
-                    event_loop.primary_monitor(),
-                    mode,
-                ));
-
-            #[cfg(target_os = "windows")]
-            {
-                use winit::platform::windows::WindowBuilderExtWindows;
-
-                if let Some(parent) = settings.window.platform_specific.parent {
-                    window_builder = window_builder.with_parent_window(parent);
-                }
-            }
-
-            window_builder.build(&event_loop).expect("Open window")
+        let mut recorder = if application.record_events() {
+            Some(Recorder::new())
+        } else {
+            None
         };
-
-        let mut size = Size::new(window.inner_size(), window.scale_factor());
+        let mut replay = application.replay().map(Recording::replay);
+
+        let window = settings
+            .window
+            .builder(&title)
+            .with_fullscreen(conversion::fullscreen(
+                event_loop.primary_monitor(),
+                mode,
+            ))
+            .build(&event_loop)
+            .expect("Open window");
+
+        let mut size = Size::new(
+            window.inner_size(),
+            window.scale_factor() * scale_factor,
+        );
         let mut resized = false;
 
         let clipboard = Clipboard::new(&window);
-        let (mut backend, mut renderer) = Self::Backend::new(backend_settings);
-
         let surface = backend.create_surface(&window);
 
         let mut swap_chain = {
@@ -177,7 +275,7 @@ pub trait Application: Sized {
             )
         };
 
-        let user_interface = build_user_interface(
+        let mut user_interface = build_user_interface(
             &mut application,
             Cache::default(),
             &mut renderer,
@@ -189,17 +287,27 @@ pub trait Application: Sized {
         let mut primitive = user_interface.draw(&mut renderer);
         debug.draw_finished();
 
+        let mut next_redraw = user_interface.redraw_at();
         let mut cache = Some(user_interface.into_cache());
         let mut events = Vec::new();
         let mut mouse_cursor = MouseCursor::OutOfBounds;
-        let mut modifiers = winit::event::ModifiersState::default();
+        let mut modifiers = conversion::Modifiers::default();
+        let mut is_maximized = false;
+        let mut is_focused = false;
+        let mut click_counter = mouse::ClickCounter::new();
         debug.startup_finished();
 
         window.request_redraw();
 
         event_loop.run(move |event, _, control_flow| match event {
             event::Event::MainEventsCleared => {
-                if events.is_empty() && external_messages.is_empty() {
+                let is_redraw_due =
+                    next_redraw.map_or(false, |at| at <= Instant::now());
+
+                if events.is_empty()
+                    && external_messages.is_empty()
+                    && !is_redraw_due
+                {
                     return;
                 }
 
@@ -237,49 +345,94 @@ pub trait Application: Sized {
                     primitive = user_interface.draw(&mut renderer);
                     debug.draw_finished();
 
+                    next_redraw = user_interface.redraw_at();
+
+                    // Let subscriptions know when a widget gains or loses focus
+                    let focused = user_interface.is_focused();
+
+                    if focused != is_focused {
+                        runtime.broadcast(Event::Keyboard(
+                            keyboard::Event::FocusChanged(focused),
+                        ));
+
+                        is_focused = focused;
+                    }
+
                     cache = Some(user_interface.into_cache());
                 } else {
                     // When there are messages, we are forced to rebuild twice
                     // for now :^)
                     let temp_cache = user_interface.into_cache();
+                    let mut messages = VecDeque::from(messages);
 
-                    for message in messages {
+                    while let Some(message) = messages.pop_front() {
                         log::debug!("Updating");
 
                         debug.log_message(&message);
 
                         debug.update_started();
-                        let command =
+                        let mut command =
                             runtime.enter(|| application.update(message));
+                        messages.extend(command.take_messages());
                         runtime.spawn(command);
                         debug.update_finished();
+
+                        match application.window_action() {
+                            Some(window::Action::Drag) => {
+                                // The platform moves the window until the
+                                // mouse button is released
+                                let _ = window.drag_window();
+                            }
+                            Some(window::Action::Minimize) => {
+                                window.set_minimized(true);
+                            }
+                            Some(window::Action::ToggleMaximize) => {
+                                // `winit` cannot tell whether the platform
+                                // maximized the window, so we only track our
+                                // own requests
+                                is_maximized = !is_maximized;
+                                window.set_maximized(is_maximized);
+                            }
+                            Some(window::Action::Close) => {
+                                if let Some(recorder) = recorder.take() {
+                                    application.recorded(recorder.finish());
+                                }
+
+                                *control_flow = ControlFlow::Exit;
+                            }
+                            None => {}
+                        }
                     }
 
                     let subscription = application.subscription();
                     runtime.track(subscription);
 
                     // Update window title
-                    let new_title = application.title();
-
-                    if title != new_title {
-                        window.set_title(&new_title);
-
-                        title = new_title;
+                    if replace_if_changed(&mut title, application.title()) {
+                        window.set_title(&title);
                     }
 
                     // Update window mode
-                    let new_mode = application.mode();
-
-                    if mode != new_mode {
+                    if replace_if_changed(&mut mode, application.mode()) {
                         window.set_fullscreen(conversion::fullscreen(
                             window.current_monitor(),
-                            new_mode,
+                            mode,
                         ));
+                    }
+
+                    // Update scale factor
+                    let new_scale_factor = application.scale_factor();
 
-                        mode = new_mode;
+                    if (scale_factor - new_scale_factor).abs() > f64::EPSILON {
+                        size = Size::new(
+                            size.physical(),
+                            window.scale_factor() * new_scale_factor,
+                        );
+
+                        scale_factor = new_scale_factor;
                     }
 
-                    let user_interface = build_user_interface(
+                    let mut user_interface = build_user_interface(
                         &mut application,
                         temp_cache,
                         &mut renderer,
@@ -291,14 +444,33 @@ pub trait Application: Sized {
                     primitive = user_interface.draw(&mut renderer);
                     debug.draw_finished();
 
+                    next_redraw = user_interface.redraw_at();
+
+                    // Let subscriptions know when a widget gains or loses focus
+                    let focused = user_interface.is_focused();
+
+                    if focused != is_focused {
+                        runtime.broadcast(Event::Keyboard(
+                            keyboard::Event::FocusChanged(focused),
+                        ));
+
+                        is_focused = focused;
+                    }
+
                     cache = Some(user_interface.into_cache());
                 }
 
                 window.request_redraw();
             }
-            event::Event::UserEvent(message) => {
+            event::Event::UserEvent(UserEvent::Message(message)) => {
                 external_messages.push(message);
             }
+            event::Event::UserEvent(UserEvent::Redraw) => {
+                // Rebuild the user interface too, as the work done in the
+                // background may change what the view shows, like the status
+                // of an SVG
+                next_redraw = Some(Instant::now());
+            }
             event::Event::RedrawRequested(_) => {
                 debug.render_started();
 
@@ -324,6 +496,10 @@ pub trait Application: Sized {
 
                 debug.render_finished();
 
+                if let Some(event) = rendered_frame(size.physical()) {
+                    runtime.broadcast(event);
+                }
+
                 if new_mouse_cursor != mouse_cursor {
                     window.set_cursor_icon(conversion::mouse_cursor(
                         new_mouse_cursor,
@@ -341,11 +517,22 @@ pub trait Application: Sized {
             } => {
                 match window_event {
                     WindowEvent::Resized(new_size) => {
-                        size = Size::new(new_size, window.scale_factor());
+                        size = Size::new(
+                            new_size,
+                            window.scale_factor() * scale_factor,
+                        );
                         resized = true;
                     }
                     WindowEvent::CloseRequested => {
-                        *control_flow = ControlFlow::Exit;
+                        if application.on_close()
+                            == window::CloseBehavior::Close
+                        {
+                            if let Some(recorder) = recorder.take() {
+                                application.recorded(recorder.finish());
+                            }
+
+                            *control_flow = ControlFlow::Exit;
+                        }
                     }
                     #[cfg(feature = "debug")]
                     WindowEvent::KeyboardInput {
@@ -361,22 +548,55 @@ pub trait Application: Sized {
                     _ => {}
                 }
 
+                conversion::update_modifiers(&mut modifiers, &window_event);
+
                 if let Some(event) = conversion::window_event(
                     window_event,
                     size.scale_factor(),
-                    modifiers,
+                    modifiers.state(),
                 ) {
-                    events.push(event);
+                    let click = match &event {
+                        Event::Mouse(mouse_event) => {
+                            click_counter.update(mouse_event).map(Event::Mouse)
+                        }
+                        _ => None,
+                    };
+
+                    for event in std::iter::once(event).chain(click) {
+                        if let Some(recorder) = &mut recorder {
+                            recorder.record(event.clone());
+                        }
+
+                        events.push(event);
+                    }
                 }
             }
-            event::Event::DeviceEvent {
-                event: event::DeviceEvent::ModifiersChanged(new_modifiers),
-                ..
-            } => {
-                modifiers = new_modifiers;
-            }
             _ => {
-                *control_flow = ControlFlow::Wait;
+                let replay_deadline = match &mut replay {
+                    Some(current) => {
+                        events.extend(current.poll());
+
+                        let deadline = current.next_deadline();
+
+                        if deadline.is_none() {
+                            replay = None;
+                        }
+
+                        deadline
+                    }
+                    None => None,
+                };
+
+                // Wake up for the next replayed event or for the next redraw
+                // requested by a widget, whichever comes first
+                *control_flow = match replay_deadline
+                    .into_iter()
+                    .chain(next_redraw)
+                    .min()
+                {
+                    Some(deadline) => ControlFlow::WaitUntil(deadline),
+                    None => ControlFlow::Wait,
+                };
             }
         })
     }
@@ -407,3 +627,73 @@ fn build_user_interface<'a, A: Application>(
 
     user_interface
 }
+
+/// Replaces a value with a new one, returning whether they were different.
+///
+/// The window is only updated when its settings change, which avoids
+/// redundant calls to the operating system.
+fn replace_if_changed<T: PartialEq>(value: &mut T, new_value: T) -> bool {
+    if *value == new_value {
+        false
+    } else {
+        *value = new_value;
+        true
+    }
+}
+
+/// Returns the event that lets subscriptions know a frame was rendered in a
+/// window of the given size.
+///
+/// There is none while the window is minimized and nothing is visible.
+fn rendered_frame(
+    physical_size: winit::dpi::PhysicalSize<u32>,
+) -> Option<Event> {
+    if physical_size.width > 0 && physical_size.height > 0 {
+        Some(Event::Window(
+            window::Event::RedrawRequested(Instant::now()),
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_title_replaces_the_tracked_title() {
+        let mut title = String::from("00:00:00 - Stopwatch - Iced");
+
+        assert!(replace_if_changed(
+            &mut title,
+            String::from("00:00:01 - Stopwatch - Iced")
+        ));
+        assert_eq!(title, "00:00:01 - Stopwatch - Iced");
+    }
+
+    #[test]
+    fn unchanged_title_is_not_applied() {
+        let mut title = String::from("Stopwatch");
+
+        assert!(!replace_if_changed(&mut title, String::from("Stopwatch")));
+        assert_eq!(title, "Stopwatch");
+    }
+
+    #[test]
+    fn visible_window_produces_a_frame() {
+        let physical_size = winit::dpi::PhysicalSize::new(800, 600);
+
+        assert!(match rendered_frame(physical_size) {
+            Some(Event::Window(window::Event::RedrawRequested(_))) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn minimized_window_produces_no_frames() {
+        let physical_size = winit::dpi::PhysicalSize::new(0, 0);
+
+        assert!(rendered_frame(physical_size).is_none());
+    }
+}
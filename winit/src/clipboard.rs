@@ -1,19 +1,27 @@
+use std::cell::RefCell;
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 #[allow(missing_debug_implementations)]
-pub struct Clipboard(window_clipboard::Clipboard);
+pub struct Clipboard(RefCell<window_clipboard::Clipboard>);
 
 impl Clipboard {
     /// Creates a new [`Clipboard`] for the given window.
     ///
     /// [`Clipboard`]: struct.Clipboard.html
     pub fn new(window: &winit::window::Window) -> Option<Clipboard> {
-        window_clipboard::Clipboard::new(window).map(Clipboard).ok()
+        window_clipboard::Clipboard::connect(window)
+            .map(|clipboard| Clipboard(RefCell::new(clipboard)))
+            .ok()
     }
 }
 
 impl iced_native::Clipboard for Clipboard {
     fn content(&self) -> Option<String> {
-        self.0.read().ok()
+        self.0.borrow().read().ok()
+    }
+
+    fn set_content(&self, content: String) -> bool {
+        self.0.borrow_mut().write(content).is_ok()
     }
 }
@@ -35,10 +35,17 @@ pub fn window_event(
                 y: position.y as f32,
             }))
         }
+        WindowEvent::CursorEntered { .. } => {
+            Some(Event::Mouse(mouse::Event::CursorEntered))
+        }
+        WindowEvent::CursorLeft { .. } => {
+            Some(Event::Mouse(mouse::Event::CursorLeft))
+        }
         WindowEvent::MouseInput { button, state, .. } => {
             Some(Event::Mouse(mouse::Event::Input {
                 button: mouse_button(button),
                 state: button_state(state),
+                modifiers: modifiers_state(modifiers),
             }))
         }
         WindowEvent::MouseWheel { delta, .. } => match delta {
@@ -75,6 +82,14 @@ pub fn window_event(
             state: button_state(state),
             modifiers: modifiers_state(modifiers),
         })),
+        WindowEvent::Focused(focused) => Some(Event::Window(if focused {
+            window::Event::Focused
+        } else {
+            window::Event::Unfocused
+        })),
+        WindowEvent::CloseRequested => {
+            Some(Event::Window(window::Event::CloseRequested))
+        }
         WindowEvent::HoveredFile(path) => {
             Some(Event::Window(window::Event::FileHovered(path)))
         }
@@ -92,7 +107,7 @@ pub fn window_event(
 ///
 /// [`Mode`]:
 pub fn fullscreen(
-    monitor: winit::monitor::MonitorHandle,
+    monitor: Option<winit::monitor::MonitorHandle>,
     mode: Mode,
 ) -> Option<winit::window::Fullscreen> {
     match mode {
@@ -159,6 +174,94 @@ pub fn modifiers_state(
     }
 }
 
+/// The modifier keys held down, as reported by [`winit`].
+///
+/// The left and right keys of each modifier are tracked separately, so
+/// releasing one of them keeps the modifier active while the other is held.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifiers {
+    left: winit::event::ModifiersState,
+    right: winit::event::ModifiersState,
+}
+
+impl Modifiers {
+    /// Returns the `ModifiersState` of the held modifier keys.
+    pub fn state(&self) -> winit::event::ModifiersState {
+        self.left | self.right
+    }
+}
+
+/// Updates some [`Modifiers`] with a window event from [`winit`].
+///
+/// [`winit`] reports modifier changes in a separate event, which may arrive
+/// after the window events they affect. Applying the press or release of a
+/// modifier key right away keeps the modifiers of the following events
+/// accurate.
+///
+/// [`Modifiers`]: struct.Modifiers.html
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn update_modifiers(
+    modifiers: &mut Modifiers,
+    event: &winit::event::WindowEvent<'_>,
+) {
+    use winit::event::{
+        ElementState, KeyboardInput, ModifiersState, VirtualKeyCode,
+        WindowEvent,
+    };
+
+    match event {
+        WindowEvent::KeyboardInput {
+            input:
+                KeyboardInput {
+                    virtual_keycode: Some(virtual_keycode),
+                    state,
+                    ..
+                },
+            ..
+        } => {
+            let (side, modifier) = match virtual_keycode {
+                VirtualKeyCode::LShift => {
+                    (&mut modifiers.left, ModifiersState::SHIFT)
+                }
+                VirtualKeyCode::RShift => {
+                    (&mut modifiers.right, ModifiersState::SHIFT)
+                }
+                VirtualKeyCode::LControl => {
+                    (&mut modifiers.left, ModifiersState::CTRL)
+                }
+                VirtualKeyCode::RControl => {
+                    (&mut modifiers.right, ModifiersState::CTRL)
+                }
+                VirtualKeyCode::LAlt => {
+                    (&mut modifiers.left, ModifiersState::ALT)
+                }
+                VirtualKeyCode::RAlt => {
+                    (&mut modifiers.right, ModifiersState::ALT)
+                }
+                VirtualKeyCode::LWin => {
+                    (&mut modifiers.left, ModifiersState::LOGO)
+                }
+                VirtualKeyCode::RWin => {
+                    (&mut modifiers.right, ModifiersState::LOGO)
+                }
+                _ => return,
+            };
+
+            side.set(modifier, *state == ElementState::Pressed);
+        }
+        WindowEvent::ModifiersChanged(new_modifiers) => {
+            // The platform does not tell the sides apart, so any modifier
+            // that is not held on either side is assumed to be on the left
+            modifiers.left &= *new_modifiers;
+            modifiers.right &= *new_modifiers;
+            modifiers.left |= *new_modifiers - modifiers.right;
+        }
+        _ => {}
+    }
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced_native`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -342,3 +445,192 @@ pub(crate) fn is_private_use_character(c: char) -> bool {
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::event::{DeviceId, MouseScrollDelta, TouchPhase, WindowEvent};
+
+    #[allow(unsafe_code)]
+    fn device_id() -> DeviceId {
+        // The dummy device is only compared against itself
+        unsafe { DeviceId::dummy() }
+    }
+
+    fn convert(event: WindowEvent<'_>) -> Option<Event> {
+        window_event(event, 1.0, winit::event::ModifiersState::empty())
+    }
+
+    #[allow(deprecated)]
+    fn wheel(delta: MouseScrollDelta) -> WindowEvent<'static> {
+        WindowEvent::MouseWheel {
+            device_id: device_id(),
+            delta,
+            phase: TouchPhase::Moved,
+            modifiers: winit::event::ModifiersState::empty(),
+        }
+    }
+
+    #[test]
+    fn line_delta_is_scrolled_by_lines() {
+        let event = convert(wheel(MouseScrollDelta::LineDelta(1.0, -2.0)));
+
+        assert_eq!(
+            event,
+            Some(Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 1.0, y: -2.0 },
+            }))
+        );
+    }
+
+    #[test]
+    fn pixel_delta_is_scrolled_by_pixels() {
+        let event = convert(wheel(MouseScrollDelta::PixelDelta(
+            winit::dpi::PhysicalPosition::new(3.0, -4.0),
+        )));
+
+        assert_eq!(
+            event,
+            Some(Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 3.0, y: -4.0 },
+            }))
+        );
+    }
+
+    #[test]
+    fn cursor_leaving_the_window_is_translated() {
+        let event = convert(WindowEvent::CursorLeft {
+            device_id: device_id(),
+        });
+
+        assert_eq!(event, Some(Event::Mouse(mouse::Event::CursorLeft)));
+    }
+
+    #[test]
+    fn cursor_entering_the_window_is_translated() {
+        let event = convert(WindowEvent::CursorEntered {
+            device_id: device_id(),
+        });
+
+        assert_eq!(event, Some(Event::Mouse(mouse::Event::CursorEntered)));
+    }
+
+    #[allow(deprecated)]
+    fn mouse_press() -> WindowEvent<'static> {
+        WindowEvent::MouseInput {
+            device_id: device_id(),
+            state: winit::event::ElementState::Pressed,
+            button: winit::event::MouseButton::Left,
+            modifiers: winit::event::ModifiersState::empty(),
+        }
+    }
+
+    #[allow(deprecated)]
+    fn key_input(
+        virtual_keycode: winit::event::VirtualKeyCode,
+        state: winit::event::ElementState,
+    ) -> WindowEvent<'static> {
+        WindowEvent::KeyboardInput {
+            device_id: device_id(),
+            input: winit::event::KeyboardInput {
+                scancode: 0,
+                state,
+                virtual_keycode: Some(virtual_keycode),
+                modifiers: winit::event::ModifiersState::empty(),
+            },
+            is_synthetic: false,
+        }
+    }
+
+    #[test]
+    fn shift_click_reports_shift() {
+        let event = window_event(
+            mouse_press(),
+            1.0,
+            winit::event::ModifiersState::SHIFT,
+        );
+
+        assert_eq!(
+            event,
+            Some(Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+                modifiers: ModifiersState {
+                    shift: true,
+                    ..ModifiersState::default()
+                },
+            }))
+        );
+    }
+
+    #[test]
+    fn modifier_press_applies_before_the_modifiers_change() {
+        use winit::event::{ElementState, VirtualKeyCode};
+
+        let mut modifiers = Modifiers::default();
+
+        update_modifiers(
+            &mut modifiers,
+            &key_input(VirtualKeyCode::LShift, ElementState::Pressed),
+        );
+
+        assert_eq!(modifiers.state(), winit::event::ModifiersState::SHIFT);
+
+        update_modifiers(
+            &mut modifiers,
+            &key_input(VirtualKeyCode::LShift, ElementState::Released),
+        );
+
+        assert_eq!(modifiers.state(), winit::event::ModifiersState::empty());
+    }
+
+    #[test]
+    fn modifier_is_held_while_either_side_is_pressed() {
+        use winit::event::{ElementState, VirtualKeyCode};
+
+        let mut modifiers = Modifiers::default();
+
+        update_modifiers(
+            &mut modifiers,
+            &key_input(VirtualKeyCode::LControl, ElementState::Pressed),
+        );
+        update_modifiers(
+            &mut modifiers,
+            &key_input(VirtualKeyCode::RControl, ElementState::Pressed),
+        );
+        update_modifiers(
+            &mut modifiers,
+            &key_input(VirtualKeyCode::LControl, ElementState::Released),
+        );
+
+        assert_eq!(modifiers.state(), winit::event::ModifiersState::CTRL);
+    }
+
+    #[test]
+    fn modifiers_change_releases_missing_modifiers() {
+        use winit::event::{ElementState, VirtualKeyCode};
+
+        let mut modifiers = Modifiers::default();
+
+        update_modifiers(
+            &mut modifiers,
+            &key_input(VirtualKeyCode::RAlt, ElementState::Pressed),
+        );
+        update_modifiers(
+            &mut modifiers,
+            &WindowEvent::ModifiersChanged(winit::event::ModifiersState::LOGO),
+        );
+
+        assert_eq!(modifiers.state(), winit::event::ModifiersState::LOGO);
+    }
+
+    #[test]
+    fn every_close_request_is_delivered() {
+        for _ in 0..2 {
+            assert_eq!(
+                convert(WindowEvent::CloseRequested),
+                Some(Event::Window(window::Event::CloseRequested))
+            );
+        }
+    }
+}
@@ -18,7 +18,9 @@
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
-#![forbid(unsafe_code)]
+// Tests need `unsafe` to build the device of a window event
+#![cfg_attr(not(test), forbid(unsafe_code))]
+#![cfg_attr(test, deny(unsafe_code))]
 #![forbid(rust_2018_idioms)]
 
 #[doc(no_inline)]
@@ -49,4 +51,4 @@ pub use mode::Mode;
 pub use settings::Settings;
 
 use debug::Debug;
-use proxy::Proxy;
+use proxy::{Proxy, UserEvent};
@@ -3,10 +3,20 @@ use iced_native::futures::{
     task::{Context, Poll},
     Sink,
 };
-use std::pin::Pin;
+use std::{pin::Pin, sync::Mutex};
+
+/// An event sent to the event loop from outside of it.
+#[derive(Debug)]
+pub enum UserEvent<Message> {
+    /// A message produced by a command or a subscription.
+    Message(Message),
+
+    /// A request to draw the last output again.
+    Redraw,
+}
 
 pub struct Proxy<Message: 'static> {
-    raw: winit::event_loop::EventLoopProxy<Message>,
+    raw: winit::event_loop::EventLoopProxy<UserEvent<Message>>,
 }
 
 impl<Message: 'static> Clone for Proxy<Message> {
@@ -18,11 +28,26 @@ impl<Message: 'static> Clone for Proxy<Message> {
 }
 
 impl<Message: 'static> Proxy<Message> {
-    pub fn new(raw: winit::event_loop::EventLoopProxy<Message>) -> Self {
+    pub fn new(
+        raw: winit::event_loop::EventLoopProxy<UserEvent<Message>>,
+    ) -> Self {
         Self { raw }
     }
 }
 
+impl<Message: 'static + Send> Proxy<Message> {
+    /// Returns a function that requests a redraw from any thread.
+    pub fn waker(&self) -> Box<dyn Fn() + Send + Sync> {
+        let raw = Mutex::new(self.raw.clone());
+
+        Box::new(move || {
+            if let Ok(raw) = raw.lock() {
+                let _ = raw.send_event(UserEvent::Redraw);
+            }
+        })
+    }
+}
+
 impl<Message: 'static> Sink<Message> for Proxy<Message> {
     type Error = mpsc::SendError;
 
@@ -37,7 +62,7 @@ impl<Message: 'static> Sink<Message> for Proxy<Message> {
         self: Pin<&mut Self>,
         message: Message,
     ) -> Result<(), Self::Error> {
-        let _ = self.raw.send_event(message);
+        let _ = self.raw.send_event(UserEvent::Message(message));
 
         Ok(())
     }
@@ -9,7 +9,7 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 /// The settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Settings {
     /// The [`Window`] settings
     ///
@@ -26,28 +26,243 @@ impl Default for Settings {
 }
 
 /// The window settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Window {
     /// The size of the window.
+    ///
+    /// Dimensions smaller than [`Window::MIN_SIZE`] are raised to it.
+    ///
+    /// [`Window::MIN_SIZE`]: struct.Window.html#associatedconstant.MIN_SIZE
     pub size: (u32, u32),
 
+    /// The minimum size of the window, if any.
+    pub min_size: Option<(u32, u32)>,
+
+    /// The maximum size of the window, if any.
+    pub max_size: Option<(u32, u32)>,
+
     /// Whether the window should be resizable or not.
     pub resizable: bool,
 
     /// Whether the window should have a border, a title bar, etc.
     pub decorations: bool,
 
+    /// The icon of the window, if any.
+    pub icon: Option<winit::window::Icon>,
+
     /// Platform specific settings.
     pub platform_specific: platform::PlatformSpecific,
 }
 
+impl Window {
+    /// The minimum size of a window.
+    pub const MIN_SIZE: (u32, u32) = (1, 1);
+
+    /// Returns the initial size of the window, making sure it is at least
+    /// [`Window::MIN_SIZE`] and within the [`size_limits`].
+    ///
+    /// [`Window::MIN_SIZE`]: struct.Window.html#associatedconstant.MIN_SIZE
+    /// [`size_limits`]: struct.Window.html#method.size_limits
+    pub fn inner_size(&self) -> (u32, u32) {
+        let (width, height) = self.size;
+
+        if width < Self::MIN_SIZE.0 || height < Self::MIN_SIZE.1 {
+            log::warn!(
+                "Invalid window size {}x{}, using at least {}x{} instead",
+                width,
+                height,
+                Self::MIN_SIZE.0,
+                Self::MIN_SIZE.1
+            );
+        }
+
+        let (min_size, max_size) = self.size_limits();
+        let (width, height) =
+            at_least((width, height), min_size.unwrap_or(Self::MIN_SIZE));
+
+        match max_size {
+            Some((max_width, max_height)) => {
+                (width.min(max_width), height.min(max_height))
+            }
+            None => (width, height),
+        }
+    }
+
+    /// Creates a `WindowBuilder` with the [`Window`] settings and the given
+    /// title.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn builder(&self, title: &str) -> winit::window::WindowBuilder {
+        let mut window_builder = winit::window::WindowBuilder::new();
+
+        let (width, height) = self.inner_size();
+        let (min_size, max_size) = self.size_limits();
+
+        if let Some((width, height)) = min_size {
+            window_builder = window_builder
+                .with_min_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+
+        if let Some((width, height)) = max_size {
+            window_builder = window_builder
+                .with_max_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+
+        window_builder = window_builder
+            .with_title(title)
+            .with_inner_size(winit::dpi::LogicalSize { width, height })
+            .with_resizable(self.resizable)
+            .with_decorations(self.decorations)
+            .with_window_icon(self.icon.clone());
+
+        #[cfg(target_os = "windows")]
+        {
+            use winit::platform::windows::WindowBuilderExtWindows;
+
+            if let Some(parent) = self.platform_specific.parent {
+                window_builder = window_builder.with_parent_window(parent);
+            }
+        }
+
+        window_builder
+    }
+
+    /// Returns the minimum and maximum sizes of the window, if any.
+    ///
+    /// Both sizes are at least [`Window::MIN_SIZE`]. A maximum size smaller
+    /// than the minimum size is raised to it.
+    ///
+    /// [`Window::MIN_SIZE`]: struct.Window.html#associatedconstant.MIN_SIZE
+    pub fn size_limits(&self) -> (Option<(u32, u32)>, Option<(u32, u32)>) {
+        let min_size = self.min_size.map(|size| at_least(size, Self::MIN_SIZE));
+        let max_size = self.max_size.map(|size| at_least(size, Self::MIN_SIZE));
+
+        let max_size = match (min_size, max_size) {
+            (Some(min_size), Some(max_size))
+                if max_size.0 < min_size.0 || max_size.1 < min_size.1 =>
+            {
+                log::warn!(
+                    "Maximum window size {:?} is smaller than the minimum \
+                     size {:?}, raising it",
+                    max_size,
+                    min_size
+                );
+
+                Some(at_least(max_size, min_size))
+            }
+            (_, max_size) => max_size,
+        };
+
+        (min_size, max_size)
+    }
+}
+
 impl Default for Window {
     fn default() -> Window {
         Window {
             size: (1024, 768),
+            min_size: None,
+            max_size: None,
             resizable: true,
             decorations: true,
+            icon: None,
             platform_specific: Default::default(),
         }
     }
 }
+
+fn at_least(
+    (width, height): (u32, u32),
+    (min_width, min_height): (u32, u32),
+) -> (u32, u32) {
+    (width.max(min_width), height.max(min_height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::dpi::{LogicalSize, Size};
+
+    fn logical(width: u32, height: u32) -> Option<Size> {
+        Some(LogicalSize { width, height }.into())
+    }
+
+    #[test]
+    fn settings_are_passed_to_the_window_builder() {
+        let window = Window {
+            size: (800, 600),
+            resizable: false,
+            decorations: false,
+            ..Window::default()
+        };
+
+        let attributes = window.builder("Stopwatch").window;
+
+        assert_eq!(attributes.title, "Stopwatch");
+        assert_eq!(attributes.inner_size, logical(800, 600));
+        assert!(!attributes.resizable);
+        assert!(!attributes.decorations);
+    }
+
+    #[test]
+    fn zero_size_is_raised_to_the_minimum() {
+        let window = Window {
+            size: (0, 600),
+            ..Window::default()
+        };
+
+        assert_eq!(window.inner_size(), (1, 600));
+        assert_eq!(
+            window.builder("").window.inner_size,
+            logical(Window::MIN_SIZE.0, 600)
+        );
+    }
+
+    #[test]
+    fn size_limits_are_passed_to_the_window_builder() {
+        let window = Window {
+            min_size: Some((200, 100)),
+            max_size: Some((1600, 1200)),
+            ..Window::default()
+        };
+
+        let attributes = window.builder("").window;
+
+        assert_eq!(attributes.min_inner_size, logical(200, 100));
+        assert_eq!(attributes.max_inner_size, logical(1600, 1200));
+    }
+
+    #[test]
+    fn maximum_size_smaller_than_the_minimum_is_raised() {
+        let window = Window {
+            min_size: Some((400, 300)),
+            max_size: Some((300, 500)),
+            ..Window::default()
+        };
+
+        assert_eq!(window.size_limits(), (Some((400, 300)), Some((400, 500))));
+    }
+
+    #[test]
+    fn size_is_clamped_to_the_limits() {
+        let window = Window {
+            size: (100, 2000),
+            min_size: Some((400, 300)),
+            max_size: Some((800, 600)),
+            ..Window::default()
+        };
+
+        assert_eq!(window.inner_size(), (400, 600));
+    }
+
+    #[test]
+    fn limits_are_at_least_the_minimum_window_size() {
+        let window = Window {
+            min_size: Some((0, 0)),
+            max_size: Some((0, 10)),
+            ..Window::default()
+        };
+
+        assert_eq!(window.size_limits(), (Some((1, 1)), Some((1, 10))));
+    }
+}
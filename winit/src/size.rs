@@ -28,3 +28,20 @@ impl Size {
         self.scale_factor
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::dpi::{LogicalSize, PhysicalSize};
+
+    #[test]
+    fn application_scale_factor_zooms_the_logical_size() {
+        let os_scale_factor = 1.5;
+        let size =
+            Size::new(PhysicalSize::new(900, 600), os_scale_factor * 2.0);
+
+        assert_eq!(size.scale_factor(), 3.0);
+        assert_eq!(size.logical(), LogicalSize::new(300.0, 200.0));
+        assert_eq!(size.physical(), PhysicalSize::new(900, 600));
+    }
+}